@@ -1,13 +1,24 @@
 use crate::app_state::StateError::BuildError;
 use crate::client::{Database, DatabaseBuilder};
+use application::event::{EventDispatcher, LoggingEventHandler, PublishEvents};
+use application::service::budget_service::BudgetServiceImpl;
 use application::service::category_service::CategoryServiceImpl;
+use application::service::dashboard_service::DashboardServiceImpl;
+use application::service::ledger_service::LedgerServiceImpl;
 use application::service::payment_method_service::PaymentMethodServiceImpl;
 use application::service::subscribe_service::SubscribeServiceImpl;
-use application::service::{CategoryService, PaymentMethodService, SubscribeService};
+use application::service::user_service::UserServiceImpl;
+use application::service::{
+    BudgetService, CategoryService, DashboardService, LedgerService, PaymentMethodService, SubscribeService,
+    UserService,
+};
+use infrastructure::repository_impl::budget_repository_impl::BudgetRepositoryImpl;
 use infrastructure::repository_impl::category_repository_impl::CategoryRepositoryImpl;
+use infrastructure::repository_impl::ledger_repository_impl::LedgerRepositoryImpl;
 use infrastructure::repository_impl::payment_repository_impl::PaymentRepositoryImpl;
 use infrastructure::repository_impl::subscribe_repository_impl::SubscribeRepositoryImpl;
-use std::sync::Arc;
+use infrastructure::repository_impl::user_repository_impl::UserRepositoryImpl;
+use std::sync::{Arc, OnceLock};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -19,6 +30,24 @@ pub enum StateError {
 pub type DynPaymentService = Arc<dyn PaymentMethodService + Send + Sync>;
 pub type DynSubscribeService = Arc<dyn SubscribeService + Send + Sync>;
 pub type DynCategoryService = Arc<dyn CategoryService + Send + Sync>;
+pub type DynLedgerService = Arc<dyn LedgerService + Send + Sync>;
+pub type DynBudgetService = Arc<dyn BudgetService + Send + Sync>;
+pub type DynUserService = Arc<dyn UserService + Send + Sync>;
+pub type DynDashboardService = Arc<dyn DashboardService + Send + Sync>;
+
+/// プロセス内で共有するドメインイベントの配信先を取得する
+///
+/// 初回呼び出し時にログ出力のハンドラを登録した [EventDispatcher] を作成する
+pub fn event_dispatcher() -> Arc<EventDispatcher> {
+    static DISPATCHER: OnceLock<Arc<EventDispatcher>> = OnceLock::new();
+    DISPATCHER
+        .get_or_init(|| {
+            let dispatcher = EventDispatcher::new();
+            dispatcher.subscribe(Arc::new(LoggingEventHandler));
+            Arc::new(dispatcher)
+        })
+        .clone()
+}
 
 #[derive(Clone)]
 pub struct PaymentMethodState {
@@ -26,15 +55,17 @@ pub struct PaymentMethodState {
 }
 
 impl PaymentMethodState {
-    pub async fn new(table: &str) -> Result<Self, StateError> {
+    pub async fn new(table: &str, subscribe_table: &str) -> Result<Self, StateError> {
         let build = Database::build(None).await;
         let client = match build {
-            Ok(b) => b,
+            Ok(b) => b.client(),
             Err(e) => return Err(BuildError(e.to_string())),
         };
 
-        let repository = PaymentRepositoryImpl::new(client.client(), table);
-        let service = PaymentMethodServiceImpl::new(repository);
+        let repository = PaymentRepositoryImpl::new(client.clone(), table);
+        let subscribe_repository = SubscribeRepositoryImpl::new(client, subscribe_table);
+        let service =
+            PaymentMethodServiceImpl::new(repository, subscribe_repository).with_event_publisher(event_dispatcher());
 
         Ok(Self { state: Arc::new(service) })
     }
@@ -46,15 +77,24 @@ pub struct SubscribeState {
 }
 
 impl SubscribeState {
-    pub async fn new(table: &str) -> Result<Self, StateError> {
+    pub async fn new(
+        table: &str,
+        category_table: &str,
+        payment_table: &str,
+        user_table: &str,
+    ) -> Result<Self, StateError> {
         let build = Database::build(None).await;
         let client = match build {
-            Ok(b) => b,
+            Ok(b) => b.client(),
             Err(e) => return Err(BuildError(e.to_string())),
         };
 
-        let repository = SubscribeRepositoryImpl::new(client.client(), table);
-        let service = SubscribeServiceImpl::new(repository);
+        let repository = SubscribeRepositoryImpl::new(client.clone(), table);
+        let category_repository = CategoryRepositoryImpl::new(client.clone(), category_table, table);
+        let payment_repository = PaymentRepositoryImpl::new(client.clone(), payment_table);
+        let user_repository = UserRepositoryImpl::new(client, user_table);
+        let service = SubscribeServiceImpl::new(repository, category_repository, payment_repository, user_repository)
+            .with_event_publisher(event_dispatcher());
 
         Ok(Self { state: Arc::new(service) })
     }
@@ -66,15 +106,104 @@ pub struct CategoryState {
 }
 
 impl CategoryState {
+    pub async fn new(table: &str, subscribe_table: &str, user_table: &str) -> Result<Self, StateError> {
+        let build = Database::build(None).await;
+        let client = match build {
+            Ok(b) => b.client(),
+            Err(e) => return Err(BuildError(e.to_string())),
+        };
+
+        let repository = CategoryRepositoryImpl::new(client.clone(), table, subscribe_table);
+        let subscribe_repository = SubscribeRepositoryImpl::new(client.clone(), subscribe_table);
+        let user_repository = UserRepositoryImpl::new(client, user_table);
+        let service = CategoryServiceImpl::new(repository, subscribe_repository, user_repository)
+            .with_event_publisher(event_dispatcher());
+
+        Ok(Self { state: Arc::new(service) })
+    }
+}
+
+#[derive(Clone)]
+pub struct LedgerState {
+    pub state: DynLedgerService,
+}
+
+impl LedgerState {
+    pub async fn new(subscribe_table: &str, ledger_table: &str) -> Result<Self, StateError> {
+        let build = Database::build(None).await;
+        let client = match build {
+            Ok(b) => b.client(),
+            Err(e) => return Err(BuildError(e.to_string())),
+        };
+
+        let subscribe_repository = SubscribeRepositoryImpl::new(client.clone(), subscribe_table);
+        let ledger_repository = LedgerRepositoryImpl::new(client, ledger_table);
+        let service =
+            LedgerServiceImpl::new(subscribe_repository, ledger_repository).with_event_publisher(event_dispatcher());
+
+        Ok(Self { state: Arc::new(service) })
+    }
+}
+
+#[derive(Clone)]
+pub struct BudgetState {
+    pub state: DynBudgetService,
+}
+
+impl BudgetState {
+    pub async fn new(table: &str, subscribe_table: &str, category_table: &str) -> Result<Self, StateError> {
+        let build = Database::build(None).await;
+        let client = match build {
+            Ok(b) => b.client(),
+            Err(e) => return Err(BuildError(e.to_string())),
+        };
+
+        let repository = BudgetRepositoryImpl::new(client.clone(), table);
+        let subscribe_repository = SubscribeRepositoryImpl::new(client.clone(), subscribe_table);
+        let category_repository = CategoryRepositoryImpl::new(client, category_table, subscribe_table);
+        let service = BudgetServiceImpl::new(repository, subscribe_repository, category_repository)
+            .with_event_publisher(event_dispatcher());
+
+        Ok(Self { state: Arc::new(service) })
+    }
+}
+
+#[derive(Clone)]
+pub struct UserState {
+    pub state: DynUserService,
+}
+
+impl UserState {
     pub async fn new(table: &str) -> Result<Self, StateError> {
         let build = Database::build(None).await;
         let client = match build {
-            Ok(b) => b,
+            Ok(b) => b.client(),
+            Err(e) => return Err(BuildError(e.to_string())),
+        };
+
+        let repository = UserRepositoryImpl::new(client, table);
+        let service = UserServiceImpl::new(repository).with_event_publisher(event_dispatcher());
+
+        Ok(Self { state: Arc::new(service) })
+    }
+}
+
+#[derive(Clone)]
+pub struct DashboardState {
+    pub state: DynDashboardService,
+}
+
+impl DashboardState {
+    pub async fn new(subscribe_table: &str, category_table: &str) -> Result<Self, StateError> {
+        let build = Database::build(None).await;
+        let client = match build {
+            Ok(b) => b.client(),
             Err(e) => return Err(BuildError(e.to_string())),
         };
 
-        let repository = CategoryRepositoryImpl::new(client.client(), table);
-        let service = CategoryServiceImpl::new(repository);
+        let subscribe_repository = SubscribeRepositoryImpl::new(client.clone(), subscribe_table);
+        let category_repository = CategoryRepositoryImpl::new(client, category_table, subscribe_table);
+        let service = DashboardServiceImpl::new(subscribe_repository, category_repository);
 
         Ok(Self { state: Arc::new(service) })
     }
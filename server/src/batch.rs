@@ -0,0 +1,102 @@
+use crate::app_state::{CategoryState, PaymentMethodState, SubscribeState};
+use crate::client::{Database, DatabaseBuilder};
+use crate::{AwsSettings, SettingsError};
+use infrastructure::repository_impl::subscribe_repository_impl::SubscribeRepositoryImpl;
+
+/// ゴミ箱に移動したデータを保持する既定の日数
+const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+/// 支払予定日を過ぎたACTIVEのサブスクの次回支払予定日を全ユーザー分繰り越す
+///
+/// # 戻り値
+/// - [usize] 更新したサブスクの件数
+///
+/// # エラー
+/// - [SettingsError] 設定の読み込み、またはバッチ処理に失敗した場合
+pub async fn roll_forward_payment_date() -> Result<usize, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let state = SubscribeState::new(&aws.subscribe, &aws.category, &aws.payment, &aws.user)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    state.state.roll_forward_payment_date_all().await.map_err(|e| SettingsError::BatchError(e.to_string()))
+}
+
+/// トライアル終了日を迎えたTRIALのサブスクを全ユーザー分有料契約に切り替える
+///
+/// # 戻り値
+/// - [usize] 切り替えたサブスクの件数
+///
+/// # エラー
+/// - [SettingsError] 設定の読み込み、またはバッチ処理に失敗した場合
+pub async fn convert_expired_trials() -> Result<usize, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let state = SubscribeState::new(&aws.subscribe, &aws.category, &aws.payment, &aws.user)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    state.state.convert_expired_trials_all().await.map_err(|e| SettingsError::BatchError(e.to_string()))
+}
+
+/// サブスクテーブルの金額を請求額での保存形式に移行する(一度だけ実行する移行処理)
+///
+/// # 戻り値
+/// - [usize] 移行したサブスクの件数
+///
+/// # エラー
+/// - [SettingsError] 設定の読み込み、または移行処理に失敗した場合
+pub async fn migrate_subscribe_amount() -> Result<usize, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let client = Database::build(None).await.map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    let repository = SubscribeRepositoryImpl::new(client.client(), &aws.subscribe);
+    repository.migrate_billed_amount().await.map_err(|e| SettingsError::BatchError(e.to_string()))
+}
+
+/// ゴミ箱に移動したデータを保持する日数を環境変数 `TRASH_RETENTION_DAYS` から読み込む
+///
+/// # エラー
+/// - [SettingsError::InvalidLoadConfig] 値が日数として解釈できない場合
+fn trash_retention_days() -> Result<u32, SettingsError> {
+    match std::env::var("TRASH_RETENTION_DAYS") {
+        Ok(v) => v.parse().map_err(|_| SettingsError::InvalidLoadConfig("TRASH_RETENTION_DAYS".to_string())),
+        Err(_) => Ok(DEFAULT_TRASH_RETENTION_DAYS),
+    }
+}
+
+/// 保持期間を過ぎてゴミ箱に残っているサブスク・カテゴリ・支払い方法を全ユーザー分完全に削除する
+///
+/// # 戻り値
+/// - [usize] 削除したデータの件数
+///
+/// # エラー
+/// - [SettingsError] 設定の読み込み、またはバッチ処理に失敗した場合
+pub async fn purge_trash() -> Result<usize, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let retention_days = trash_retention_days()?;
+
+    let subscribe = SubscribeState::new(&aws.subscribe, &aws.category, &aws.payment, &aws.user)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    let category = CategoryState::new(&aws.category, &aws.subscribe, &aws.user)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    let payment = PaymentMethodState::new(&aws.payment, &aws.subscribe)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+
+    let subscribes = subscribe
+        .state
+        .purge_deleted_subscribes(retention_days)
+        .await
+        .map_err(|e| SettingsError::BatchError(e.to_string()))?;
+    let categories = category
+        .state
+        .purge_deleted_categories(retention_days)
+        .await
+        .map_err(|e| SettingsError::BatchError(e.to_string()))?;
+    let payments = payment
+        .state
+        .purge_deleted_payment_methods(retention_days)
+        .await
+        .map_err(|e| SettingsError::BatchError(e.to_string()))?;
+
+    Ok(subscribes + categories + payments)
+}
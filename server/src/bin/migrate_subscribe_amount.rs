@@ -0,0 +1,18 @@
+use dotenv::dotenv;
+use server::{batch::migrate_subscribe_amount, set_up_tracing_subscriber};
+use tracing::{error, event, Level};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenv().ok();
+    set_up_tracing_subscriber();
+
+    event!(Level::INFO, "Subscribe amount migration started");
+    let count = migrate_subscribe_amount().await.map_err(|e| {
+        error!("{}", e);
+        e
+    })?;
+    event!(Level::INFO, "Subscribe amount migration finished. migrated: {0}", count);
+
+    Ok(())
+}
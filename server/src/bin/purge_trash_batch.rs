@@ -0,0 +1,18 @@
+use dotenv::dotenv;
+use server::{batch::purge_trash, set_up_tracing_subscriber};
+use tracing::{error, event, Level};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenv().ok();
+    set_up_tracing_subscriber();
+
+    event!(Level::INFO, "Purge trash batch started");
+    let count = purge_trash().await.map_err(|e| {
+        error!("{}", e);
+        e
+    })?;
+    event!(Level::INFO, "Purge trash batch finished. purged: {0}", count);
+
+    Ok(())
+}
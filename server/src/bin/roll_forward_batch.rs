@@ -0,0 +1,27 @@
+use dotenv::dotenv;
+use server::{
+    batch::{convert_expired_trials, roll_forward_payment_date},
+    set_up_tracing_subscriber,
+};
+use tracing::{error, event, Level};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenv().ok();
+    set_up_tracing_subscriber();
+
+    event!(Level::INFO, "Roll forward batch started");
+    // 有料契約に切り替えたサブスクも繰り越しの対象にするため先に実行する
+    let converted = convert_expired_trials().await.map_err(|e| {
+        error!("{}", e);
+        e
+    })?;
+    event!(Level::INFO, "Expired trials converted. converted: {0}", converted);
+    let count = roll_forward_payment_date().await.map_err(|e| {
+        error!("{}", e);
+        e
+    })?;
+    event!(Level::INFO, "Roll forward batch finished. updated: {0}", count);
+
+    Ok(())
+}
@@ -1,7 +1,11 @@
+pub mod budget_controller;
 pub mod category_controller;
+pub mod dashboard_controller;
+pub mod ledger_controller;
 pub mod params;
 pub mod payment_method_controller;
 pub mod subscribe_controller;
+pub mod user_controller;
 
 use application::error::ApplicationError;
 
@@ -9,7 +13,7 @@ pub struct ApplicationErrorWrapper(ApplicationError);
 
 impl std::fmt::Display for ApplicationErrorWrapper {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.to_string())
+        write!(f, "{}", self.0)
     }
 }
 
@@ -19,6 +23,9 @@ impl axum::response::IntoResponse for ApplicationErrorWrapper {
             ApplicationErrorWrapper(ApplicationError::PaymentMethodError(..)) => {
                 (axum::http::StatusCode::INTERNAL_SERVER_ERROR, self.to_string())
             }
+            ApplicationErrorWrapper(ApplicationError::Conflict(..)) => {
+                (axum::http::StatusCode::CONFLICT, self.to_string())
+            }
             _ => (axum::http::StatusCode::INTERNAL_SERVER_ERROR, self.to_string()),
         };
         let res = serde_json::json!({
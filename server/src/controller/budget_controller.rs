@@ -0,0 +1,94 @@
+use crate::app_state::BudgetState;
+use application::dtos::budget_dto::BudgetDto;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use serde_json::json;
+
+use super::params::budget_params::{FindAllParam, FindByIdParams};
+use super::ApplicationErrorWrapper;
+
+pub async fn create_budget(
+    Extension(module): Extension<BudgetState>,
+    Json(payload): Json<BudgetDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.create_budget(payload).await;
+    let response = json!({
+        "message": "budget created",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_budget_all(
+    Extension(module): Extension<BudgetState>,
+    Query(FindAllParam { user_id }): Query<FindAllParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_budget_all(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_budget_by_id(
+    Extension(module): Extension<BudgetState>,
+    Query(FindByIdParams { user_id, budget_id }): Query<FindByIdParams>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_budget_by_id(&budget_id, &user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn update_budget(
+    Extension(module): Extension<BudgetState>,
+    Json(payload): Json<BudgetDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.update_budget(payload).await;
+    let response = json!({
+        "message": "budget updated",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn delete_budget(
+    Extension(module): Extension<BudgetState>,
+    Query(FindByIdParams { user_id, budget_id }): Query<FindByIdParams>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.delete_budget(&budget_id, &user_id).await;
+    let response = json!({
+        "message": "budget deleted",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(()) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn evaluate_budgets(
+    Extension(module): Extension<BudgetState>,
+    Query(FindAllParam { user_id }): Query<FindAllParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.evaluate_budgets(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
@@ -1,11 +1,11 @@
-use application::dtos::category_dto::CategoryDto;
+use application::dtos::category_dto::{CategoryDto, CategoryOrderDto};
 use axum::{extract::Query, http::StatusCode, response::IntoResponse, Extension, Json};
 use serde_json::json;
 
 use crate::app_state::CategoryState;
 
 use super::{
-    params::category_params::{FindAllParam, FindByIdParams},
+    params::category_params::{FindAllParam, FindByIdParams, ReassignDeleteParams, SeedParams},
     ApplicationErrorWrapper,
 };
 
@@ -27,9 +27,9 @@ pub async fn create_category(
 
 pub async fn find_category_all(
     Extension(module): Extension<CategoryState>,
-    Query(FindAllParam { user_id }): Query<FindAllParam>,
+    Query(FindAllParam { user_id, sort }): Query<FindAllParam>,
 ) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
-    let result = module.state.find_category_all(&user_id).await;
+    let result = module.state.find_category_all(&user_id, sort).await;
 
     match result {
         Ok(v) => Ok((StatusCode::OK, Json(v))),
@@ -80,3 +80,86 @@ pub async fn delete_category(
         Err(e) => Err(ApplicationErrorWrapper(e)),
     }
 }
+
+pub async fn delete_category_with_reassignment(
+    Extension(module): Extension<CategoryState>,
+    Query(ReassignDeleteParams { user_id, category_id, target_category_id }): Query<ReassignDeleteParams>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result =
+        module.state.delete_category_with_reassignment(&user_id, &category_id, target_category_id.as_deref()).await;
+
+    match result {
+        Ok(moved) => {
+            let response = json!({
+                "message": "category deleted",
+                "moved": moved,
+                "status code": StatusCode::OK.as_u16()
+            });
+            Ok((StatusCode::OK, Json(response)))
+        }
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn reorder_categories(
+    Extension(module): Extension<CategoryState>,
+    Json(payload): Json<CategoryOrderDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.reorder_categories(payload).await;
+    let response = json!({
+        "message": "categories reordered",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn seed_default_categories(
+    Extension(module): Extension<CategoryState>,
+    Query(SeedParams { user_id }): Query<SeedParams>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.seed_default_categories(&user_id).await;
+
+    match result {
+        Ok(created) => {
+            let response = json!({
+                "message": "default categories created",
+                "created": created,
+                "status code": StatusCode::OK.as_u16()
+            });
+            Ok((StatusCode::OK, Json(response)))
+        }
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_deleted_categories(
+    Extension(module): Extension<CategoryState>,
+    Query(FindAllParam { user_id, .. }): Query<FindAllParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_deleted_categories(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn restore_category(
+    Extension(module): Extension<CategoryState>,
+    Query(FindByIdParams { user_id, category_id }): Query<FindByIdParams>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.restore_category(&user_id, &category_id).await;
+    let response = json!({
+        "message": "category restored",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
@@ -0,0 +1,68 @@
+use crate::app_state::DashboardState;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+
+use super::params::dashboard_params::{CalendarParam, FindParam, VarianceParam};
+use super::ApplicationErrorWrapper;
+
+pub async fn find_dashboard_summary(
+    Extension(module): Extension<DashboardState>,
+    Query(FindParam { user_id }): Query<FindParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_summary(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_category_breakdown(
+    Extension(module): Extension<DashboardState>,
+    Query(FindParam { user_id }): Query<FindParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_category_breakdown(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_monthly_variance(
+    Extension(module): Extension<DashboardState>,
+    Query(VarianceParam { user_id, from, to }): Query<VarianceParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_monthly_variance(&user_id, &from, &to).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_forecast(
+    Extension(module): Extension<DashboardState>,
+    Query(FindParam { user_id }): Query<FindParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_forecast(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_payment_calendar(
+    Extension(module): Extension<DashboardState>,
+    Query(CalendarParam { user_id, from, to }): Query<CalendarParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_payment_calendar(&user_id, &from, &to).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
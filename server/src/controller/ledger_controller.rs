@@ -0,0 +1,42 @@
+use application::dtos::ledger_dto::{ConfirmPaymentDto, DisputePaymentDto};
+use axum::{extract::Query, http::StatusCode, response::IntoResponse, Extension, Json};
+
+use crate::app_state::LedgerState;
+
+use super::{params::ledger_params::FindPaymentsParam, ApplicationErrorWrapper};
+
+pub async fn confirm_payment(
+    Extension(module): Extension<LedgerState>,
+    Json(payload): Json<ConfirmPaymentDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.confirm_payment(payload).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn dispute_payment(
+    Extension(module): Extension<LedgerState>,
+    Json(payload): Json<DisputePaymentDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.dispute_payment(payload).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_payments(
+    Extension(module): Extension<LedgerState>,
+    Query(FindPaymentsParam { user_id, subscribe_id }): Query<FindPaymentsParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_payments(&user_id, subscribe_id.as_deref()).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
@@ -1,3 +1,7 @@
+pub mod budget_params;
 pub mod category_params;
+pub mod dashboard_params;
+pub mod ledger_params;
 pub mod payment_method_params;
 pub mod subscribe_params;
+pub mod user_params;
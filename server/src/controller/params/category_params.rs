@@ -1,8 +1,11 @@
+use application::dtos::list_order::ListOrder;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct FindAllParam {
     pub user_id: String,
+    /// 並び順 (newest, oldest, recently_updated)。未指定の場合は登録順
+    pub sort: Option<ListOrder>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -10,3 +13,16 @@ pub struct FindByIdParams {
     pub user_id: String,
     pub category_id: String,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct ReassignDeleteParams {
+    pub user_id: String,
+    pub category_id: String,
+    /// サブスクの付け替え先のカテゴリID (未指定の場合は「未分類」カテゴリ)
+    pub target_category_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeedParams {
+    pub user_id: String,
+}
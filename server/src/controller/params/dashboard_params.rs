@@ -0,0 +1,24 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct FindParam {
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct VarianceParam {
+    pub user_id: String,
+    /// 期間の開始月 (YYYY-MM)
+    pub from: String,
+    /// 期間の終了月 (YYYY-MM)
+    pub to: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarParam {
+    pub user_id: String,
+    /// 期間の開始日 (YYYY-MM-DD)
+    pub from: String,
+    /// 期間の終了日 (YYYY-MM-DD)
+    pub to: String,
+}
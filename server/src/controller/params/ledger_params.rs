@@ -0,0 +1,8 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct FindPaymentsParam {
+    pub user_id: String,
+    /// 指定した場合はそのサブスクの支払記録のみ取得する
+    pub subscribe_id: Option<String>,
+}
@@ -1,8 +1,11 @@
+use application::dtos::list_order::ListOrder;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct FindAllParam {
     pub user_id: String,
+    /// 並び順 (newest, oldest, recently_updated)。未指定の場合は登録順
+    pub sort: Option<ListOrder>,
 }
 
 #[derive(Debug, Deserialize)]
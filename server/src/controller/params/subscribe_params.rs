@@ -1,8 +1,13 @@
+use application::dtos::list_order::ListOrder;
 use serde::Deserialize;
 
 #[derive(Debug, Deserialize)]
 pub struct FindAllParam {
     pub user_id: String,
+    /// 指定した場合はこのタグが付いたサブスクのみを取得する
+    pub tag: Option<String>,
+    /// 並び順 (newest, oldest, recently_updated)。未指定の場合は登録順
+    pub sort: Option<ListOrder>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -10,3 +15,29 @@ pub struct FindByIdParams {
     pub user_id: String,
     pub subscribe_id: String,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct UserParam {
+    pub user_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TrialEndingParam {
+    pub user_id: String,
+    /// 何日以内に終了するトライアルを取得するか (未指定の場合は7日)
+    pub days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PriceIncreaseParam {
+    pub user_id: String,
+    /// 何日前以降の値上げを取得するか (未指定の場合は365日)
+    pub days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CancellationClosingParam {
+    pub user_id: String,
+    /// 何日以内に解約の最終日を迎えるサブスクを取得するか (未指定の場合は14日)
+    pub days: Option<u32>,
+}
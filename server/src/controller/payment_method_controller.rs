@@ -27,9 +27,9 @@ pub async fn create_payment_method(
 
 pub async fn find_payment_method_all(
     Extension(module): Extension<PaymentMethodState>,
-    Query(FindAllParam { user_id }): Query<FindAllParam>,
+    Query(FindAllParam { user_id, sort }): Query<FindAllParam>,
 ) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
-    let result = module.state.find_payment_method_all(&user_id).await;
+    let result = module.state.find_payment_method_all(&user_id, sort).await;
 
     match result {
         Ok(v) => Ok((StatusCode::OK, Json(v))),
@@ -81,3 +81,32 @@ pub async fn delete_payment_method(
         Err(e) => Err(ApplicationErrorWrapper(e)),
     }
 }
+
+pub async fn find_deleted_payment_methods(
+    Extension(module): Extension<PaymentMethodState>,
+    Query(FindAllParam { user_id, .. }): Query<FindAllParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_deleted_payment_methods(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn restore_payment_method(
+    Extension(module): Extension<PaymentMethodState>,
+    Query(FindByIdParams { user_id, payment_method_id }): Query<FindByIdParams>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.restore_payment_method(&payment_method_id, &user_id).await;
+
+    let response = json!({
+        "message": "payment method restored",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(()) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
@@ -1,14 +1,25 @@
 use application::dtos::subscribe_dto::SubscribeDto;
-use axum::{extract::Query, http::StatusCode, response::IntoResponse, Extension, Json};
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
 use serde_json::json;
 
 use crate::app_state::SubscribeState;
 
 use super::{
-    params::subscribe_params::{FindAllParam, FindByIdParams},
+    params::subscribe_params::{
+        CancellationClosingParam, FindAllParam, FindByIdParams, PriceIncreaseParam, TrialEndingParam, UserParam,
+    },
     ApplicationErrorWrapper,
 };
 
+const DEFAULT_TRIAL_ENDING_DAYS: u32 = 7;
+const DEFAULT_PRICE_INCREASE_DAYS: u32 = 365;
+const DEFAULT_CANCELLATION_CLOSING_DAYS: u32 = 14;
+
 pub async fn create_subscribe(
     Extension(module): Extension<SubscribeState>,
     Json(payload): Json<SubscribeDto>,
@@ -27,9 +38,9 @@ pub async fn create_subscribe(
 
 pub async fn find_subscribe_all(
     Extension(module): Extension<SubscribeState>,
-    Query(FindAllParam { user_id }): Query<FindAllParam>,
+    Query(FindAllParam { user_id, tag, sort }): Query<FindAllParam>,
 ) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
-    let result = module.state.find_subscribe_all(&user_id).await;
+    let result = module.state.find_subscribe_all(&user_id, tag.as_deref(), sort).await;
 
     match result {
         Ok(v) => Ok((StatusCode::OK, Json(v))),
@@ -80,3 +91,230 @@ pub async fn delete_subscribe(
         Err(e) => Err(ApplicationErrorWrapper(e)),
     }
 }
+
+pub async fn pause_subscribe(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.pause_subscribe(&user_id, &subscribe_id).await;
+    let response = json!({
+        "message": "subscribe paused",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn resume_subscribe(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.resume_subscribe(&user_id, &subscribe_id).await;
+    let response = json!({
+        "message": "subscribe resumed",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn cancel_subscribe(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.cancel_subscribe(&user_id, &subscribe_id).await;
+    let response = json!({
+        "message": "subscribe cancelled",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn reactivate_subscribe(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.reactivate_subscribe(&user_id, &subscribe_id).await;
+    let response = json!({
+        "message": "subscribe reactivated",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn roll_forward_payment_date(
+    Extension(module): Extension<SubscribeState>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.roll_forward_payment_date(&user_id).await;
+
+    match result {
+        Ok(count) => {
+            let response = json!({
+                "message": "next payment date rolled forward",
+                "updated": count,
+                "status code": StatusCode::OK.as_u16()
+            });
+            Ok((StatusCode::OK, Json(response)))
+        }
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_trials_ending(
+    Extension(module): Extension<SubscribeState>,
+    Query(TrialEndingParam { user_id, days }): Query<TrialEndingParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_trials_ending_within(&user_id, days.unwrap_or(DEFAULT_TRIAL_ENDING_DAYS)).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_cancellation_windows_closing(
+    Extension(module): Extension<SubscribeState>,
+    Query(CancellationClosingParam { user_id, days }): Query<CancellationClosingParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module
+        .state
+        .find_cancellation_windows_closing(&user_id, days.unwrap_or(DEFAULT_CANCELLATION_CLOSING_DAYS))
+        .await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_price_history(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_price_history(&user_id, &subscribe_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_recent_price_increases(
+    Extension(module): Extension<SubscribeState>,
+    Query(PriceIncreaseParam { user_id, days }): Query<PriceIncreaseParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_recent_price_increases(&user_id, days.unwrap_or(DEFAULT_PRICE_INCREASE_DAYS)).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_cost_split(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_cost_split(&user_id, &subscribe_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn add_tag(
+    Extension(module): Extension<SubscribeState>,
+    Path((subscribe_id, tag)): Path<(String, String)>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.add_tag(&user_id, &subscribe_id, &tag).await;
+    let response = json!({
+        "message": "tag added",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn remove_tag(
+    Extension(module): Extension<SubscribeState>,
+    Path((subscribe_id, tag)): Path<(String, String)>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.remove_tag(&user_id, &subscribe_id, &tag).await;
+    let response = json!({
+        "message": "tag removed",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_tag_counts(
+    Extension(module): Extension<SubscribeState>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_tag_counts(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_deleted_subscribes(
+    Extension(module): Extension<SubscribeState>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_deleted_subscribes(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn restore_subscribe(
+    Extension(module): Extension<SubscribeState>,
+    Path(subscribe_id): Path<String>,
+    Query(UserParam { user_id }): Query<UserParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.restore_subscribe(&user_id, &subscribe_id).await;
+    let response = json!({
+        "message": "subscribe restored",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
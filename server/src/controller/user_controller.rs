@@ -0,0 +1,66 @@
+use crate::app_state::UserState;
+use application::dtos::user_dto::UserDto;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use serde_json::json;
+
+use super::params::user_params::FindParam;
+use super::ApplicationErrorWrapper;
+
+pub async fn create_user(
+    Extension(module): Extension<UserState>,
+    Json(payload): Json<UserDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.create_user(payload).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn find_user(
+    Extension(module): Extension<UserState>,
+    Query(FindParam { user_id }): Query<FindParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.find_user(&user_id).await;
+
+    match result {
+        Ok(v) => Ok((StatusCode::OK, Json(v))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn update_user(
+    Extension(module): Extension<UserState>,
+    Json(payload): Json<UserDto>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.update_user(payload).await;
+    let response = json!({
+        "message": "user updated",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(_) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
+
+pub async fn delete_user(
+    Extension(module): Extension<UserState>,
+    Query(FindParam { user_id }): Query<FindParam>,
+) -> Result<impl IntoResponse, ApplicationErrorWrapper> {
+    let result = module.state.delete_user(&user_id).await;
+    let response = json!({
+        "message": "user deleted",
+        "status code": StatusCode::OK.as_u16()
+    });
+
+    match result {
+        Ok(()) => Ok((StatusCode::OK, Json(response))),
+        Err(e) => Err(ApplicationErrorWrapper(e)),
+    }
+}
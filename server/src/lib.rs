@@ -1,21 +1,36 @@
 pub mod app_state;
+pub mod batch;
 pub mod client;
 pub mod controller;
 pub mod middlewares;
 
-use app_state::{CategoryState, PaymentMethodState, SubscribeState};
+use app_state::{
+    BudgetState, CategoryState, DashboardState, LedgerState, PaymentMethodState, SubscribeState, UserState,
+};
 use axum::routing::{delete, get, post, put};
 use axum::{Extension, Router};
+use controller::budget_controller::{
+    create_budget, delete_budget, evaluate_budgets, find_budget_all, find_budget_by_id, update_budget,
+};
 use controller::category_controller::{
-    create_category, delete_category, find_category_all, find_category_by_id, update_category,
+    create_category, delete_category, delete_category_with_reassignment, find_category_all, find_category_by_id,
+    find_deleted_categories, reorder_categories, restore_category, seed_default_categories, update_category,
+};
+use controller::dashboard_controller::{
+    find_category_breakdown, find_dashboard_summary, find_forecast, find_monthly_variance, find_payment_calendar,
 };
+use controller::ledger_controller::{confirm_payment, dispute_payment, find_payments};
 use controller::payment_method_controller::{
-    create_payment_method, delete_payment_method, find_payment_method_all, find_payment_method_by_id,
-    update_payment_method,
+    create_payment_method, delete_payment_method, find_deleted_payment_methods, find_payment_method_all,
+    find_payment_method_by_id, restore_payment_method, update_payment_method,
 };
 use controller::subscribe_controller::{
-    create_subscribe, delete_subscribe, find_subscribe_all, find_subscribe_by_id, update_subscribe,
+    add_tag, cancel_subscribe, create_subscribe, delete_subscribe, find_cancellation_windows_closing, find_cost_split,
+    find_deleted_subscribes, find_price_history, find_recent_price_increases, find_subscribe_all, find_subscribe_by_id,
+    find_tag_counts, find_trials_ending, pause_subscribe, reactivate_subscribe, remove_tag, restore_subscribe,
+    resume_subscribe, roll_forward_payment_date, update_subscribe,
 };
+use controller::user_controller::{create_user, delete_user, find_user, update_user};
 use middlewares::logging_middleware::logging_middleware;
 use thiserror::Error;
 use tracing::error;
@@ -34,6 +49,9 @@ pub struct AwsSettings {
     payment: String,
     subscribe: String,
     category: String,
+    user: String,
+    ledger: Option<String>,
+    budget: Option<String>,
 }
 
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -43,6 +61,9 @@ pub enum SettingsError {
 
     #[error("State build error: {0}")]
     StateBuildError(String),
+
+    #[error("Batch execution error: {0}")]
+    BatchError(String),
 }
 
 impl ApiSettings {
@@ -62,8 +83,34 @@ impl AwsSettings {
             .map_err(|_| SettingsError::InvalidLoadConfig("SUBSCRIBE_TABLE".to_string()))?;
         let category = std::env::var("CATEGORY_TABLE")
             .map_err(|_| SettingsError::InvalidLoadConfig("CATEGORY_TABLE".to_string()))?;
+        // サブスクとカテゴリのルーターも起動時に参照するため、ユーザーのテーブルは必須とする
+        let user =
+            std::env::var("USER_TABLE").map_err(|_| SettingsError::InvalidLoadConfig("USER_TABLE".to_string()))?;
+        // 機能ごとに追加したテーブルは、未設定でも他のルーターを起動できるよう使用時に検証する
+        let ledger = std::env::var("LEDGER_TABLE").ok();
+        let budget = std::env::var("BUDGET_TABLE").ok();
+
+        Ok(Self { payment, subscribe, category, user, ledger, budget })
+    }
+
+    /// 支払台帳のテーブル名を取得する
+    ///
+    /// # エラー
+    /// - [SettingsError::InvalidLoadConfig] `LEDGER_TABLE` が未設定の場合
+    fn ledger(&self) -> Result<&str, SettingsError> {
+        Self::required(&self.ledger, "LEDGER_TABLE")
+    }
+
+    /// 予算のテーブル名を取得する
+    ///
+    /// # エラー
+    /// - [SettingsError::InvalidLoadConfig] `BUDGET_TABLE` が未設定の場合
+    fn budget(&self) -> Result<&str, SettingsError> {
+        Self::required(&self.budget, "BUDGET_TABLE")
+    }
 
-        Ok(Self { payment, subscribe, category })
+    fn required<'a>(value: &'a Option<String>, key: &str) -> Result<&'a str, SettingsError> {
+        value.as_deref().ok_or_else(|| SettingsError::InvalidLoadConfig(key.to_string()))
     }
 }
 
@@ -73,49 +120,134 @@ pub fn set_up_tracing_subscriber() {
 
     tracing_subscriber::registry()
         .with(
-            tracing_subscriber::fmt::layer().json().with_target(true).with_ansi(false).with_filter(filter).with_filter(
-                filter::filter_fn(|metadata| if metadata.target().contains(CREDENTIALS) { false } else { true }),
-            ),
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_target(true)
+                .with_ansi(false)
+                .with_filter(filter)
+                .with_filter(filter::filter_fn(|metadata| !metadata.target().contains(CREDENTIALS))),
         )
         .init();
 }
 
 pub async fn create_payment_router() -> Result<Router, SettingsError> {
     let aws = AwsSettings::build()?;
-    let state =
-        PaymentMethodState::new(&aws.payment).await.map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    let state = PaymentMethodState::new(&aws.payment, &aws.subscribe)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
     Ok(Router::new()
         .route("/create", post(create_payment_method))
         .route("/", get(find_payment_method_all))
         .route("/id", get(find_payment_method_by_id))
         .route("/update", put(update_payment_method))
         .route("/delete", delete(delete_payment_method))
+        .route("/trash", get(find_deleted_payment_methods))
+        .route("/restore", put(restore_payment_method))
         .route_layer(axum::middleware::from_fn(logging_middleware))
         .layer(Extension(state)))
 }
 
 pub async fn create_subscribe_router() -> Result<Router, SettingsError> {
     let aws = AwsSettings::build()?;
-    let state = SubscribeState::new(&aws.subscribe).await.map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    let state = SubscribeState::new(&aws.subscribe, &aws.category, &aws.payment, &aws.user)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
     Ok(Router::new()
         .route("/create", post(create_subscribe))
         .route("/", get(find_subscribe_all))
         .route("/id", get(find_subscribe_by_id))
         .route("/update", put(update_subscribe))
         .route("/delete", delete(delete_subscribe))
+        .route("/:id/pause", put(pause_subscribe))
+        .route("/:id/resume", put(resume_subscribe))
+        .route("/:id/cancel", put(cancel_subscribe))
+        .route("/:id/reactivate", put(reactivate_subscribe))
+        .route("/roll-forward", put(roll_forward_payment_date))
+        .route("/trials/ending", get(find_trials_ending))
+        .route("/cancellation/closing", get(find_cancellation_windows_closing))
+        .route("/:id/price-history", get(find_price_history))
+        .route("/price-increases", get(find_recent_price_increases))
+        .route("/:id/split", get(find_cost_split))
+        .route("/:id/tags/:tag", put(add_tag).delete(remove_tag))
+        .route("/tags", get(find_tag_counts))
+        .route("/trash", get(find_deleted_subscribes))
+        .route("/:id/restore", put(restore_subscribe))
         .route_layer(axum::middleware::from_fn(logging_middleware))
         .layer(Extension(state)))
 }
 
 pub async fn create_category_router() -> Result<Router, SettingsError> {
     let aws = AwsSettings::build()?;
-    let state = CategoryState::new(&aws.category).await.map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    let state = CategoryState::new(&aws.category, &aws.subscribe, &aws.user)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
     Ok(Router::new()
         .route("/create", post(create_category))
         .route("/", get(find_category_all))
         .route("/id", get(find_category_by_id))
         .route("/update", put(update_category))
         .route("/delete", delete(delete_category))
+        .route("/delete/reassign", delete(delete_category_with_reassignment))
+        .route("/reorder", put(reorder_categories))
+        .route("/seed", post(seed_default_categories))
+        .route("/trash", get(find_deleted_categories))
+        .route("/restore", put(restore_category))
+        .route_layer(axum::middleware::from_fn(logging_middleware))
+        .layer(Extension(state)))
+}
+
+pub async fn create_ledger_router() -> Result<Router, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let state = LedgerState::new(&aws.subscribe, aws.ledger()?)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    Ok(Router::new()
+        .route("/confirm", post(confirm_payment))
+        .route("/dispute", post(dispute_payment))
+        .route("/", get(find_payments))
+        .route_layer(axum::middleware::from_fn(logging_middleware))
+        .layer(Extension(state)))
+}
+
+pub async fn create_budget_router() -> Result<Router, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let state = BudgetState::new(aws.budget()?, &aws.subscribe, &aws.category)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    Ok(Router::new()
+        .route("/create", post(create_budget))
+        .route("/", get(find_budget_all))
+        .route("/id", get(find_budget_by_id))
+        .route("/update", put(update_budget))
+        .route("/delete", delete(delete_budget))
+        .route("/evaluate", get(evaluate_budgets))
+        .route_layer(axum::middleware::from_fn(logging_middleware))
+        .layer(Extension(state)))
+}
+
+pub async fn create_user_router() -> Result<Router, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let state = UserState::new(&aws.user).await.map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    Ok(Router::new()
+        .route("/create", post(create_user))
+        .route("/", get(find_user))
+        .route("/update", put(update_user))
+        .route("/delete", delete(delete_user))
+        .route_layer(axum::middleware::from_fn(logging_middleware))
+        .layer(Extension(state)))
+}
+
+pub async fn create_dashboard_router() -> Result<Router, SettingsError> {
+    let aws = AwsSettings::build()?;
+    let state = DashboardState::new(&aws.subscribe, &aws.category)
+        .await
+        .map_err(|e| SettingsError::StateBuildError(e.to_string()))?;
+    Ok(Router::new()
+        .route("/summary", get(find_dashboard_summary))
+        .route("/categories", get(find_category_breakdown))
+        .route("/variance", get(find_monthly_variance))
+        .route("/forecast", get(find_forecast))
+        .route("/calendar", get(find_payment_calendar))
         .route_layer(axum::middleware::from_fn(logging_middleware))
         .layer(Extension(state)))
 }
@@ -172,6 +304,9 @@ mod tests {
         std::env::set_var("PAYMENT_TABLE", "payment");
         std::env::set_var("CATEGORY_TABLE", "category");
         std::env::set_var("SUBSCRIBE_TABLE", "subscribe");
+        std::env::set_var("LEDGER_TABLE", "ledger");
+        std::env::set_var("BUDGET_TABLE", "budget");
+        std::env::set_var("USER_TABLE", "user");
         let result = AwsSettings::build();
 
         assert!(result.is_ok());
@@ -188,12 +323,30 @@ mod tests {
         assert_eq!(SettingsError::InvalidLoadConfig("PAYMENT_TABLE".to_string()), result.unwrap_err())
     }
 
+    #[test]
+    fn aws_settings_feature_table_failed() {
+        let aws = AwsSettings {
+            payment: "payment".to_string(),
+            subscribe: "subscribe".to_string(),
+            category: "category".to_string(),
+            user: "user".to_string(),
+            ledger: None,
+            budget: Some("budget".to_string()),
+        };
+
+        assert_eq!(SettingsError::InvalidLoadConfig("LEDGER_TABLE".to_string()), aws.ledger().unwrap_err());
+        assert_eq!(Ok("budget"), aws.budget());
+    }
+
     #[tokio::test]
     async fn test_create_payment_router() {
         clear_env();
         std::env::set_var("PAYMENT_TABLE", "payment");
         std::env::set_var("CATEGORY_TABLE", "category");
         std::env::set_var("SUBSCRIBE_TABLE", "subscribe");
+        std::env::set_var("LEDGER_TABLE", "ledger");
+        std::env::set_var("BUDGET_TABLE", "budget");
+        std::env::set_var("USER_TABLE", "user");
         let result = create_payment_router().await;
         println!("{:?}", result);
         assert!(result.is_ok())
@@ -1,6 +1,7 @@
 use dotenv::dotenv;
 use server::{
-    create_category_router, create_payment_router, create_subscribe_router, set_up_tracing_subscriber, ApiSettings,
+    create_budget_router, create_category_router, create_dashboard_router, create_ledger_router, create_payment_router,
+    create_subscribe_router, create_user_router, set_up_tracing_subscriber, ApiSettings,
 };
 use std::net::{Ipv4Addr, SocketAddrV4};
 use std::str::FromStr;
@@ -14,11 +15,19 @@ async fn main() -> anyhow::Result<()> {
     let payment_routes = create_payment_router().await?;
     let subscribe_routes = create_subscribe_router().await?;
     let category_routes = create_category_router().await?;
+    let ledger_routes = create_ledger_router().await?;
+    let budget_routes = create_budget_router().await?;
+    let user_routes = create_user_router().await?;
+    let dashboard_routes = create_dashboard_router().await?;
 
     let api_routes = axum::Router::new()
         .nest("/api/v1/payment", payment_routes)
         .nest("/api/v1/subscribe", subscribe_routes)
-        .nest("/api/v1/category", category_routes);
+        .nest("/api/v1/category", category_routes)
+        .nest("/api/v1/ledger", ledger_routes)
+        .nest("/api/v1/budget", budget_routes)
+        .nest("/api/v1/user", user_routes)
+        .nest("/api/v1/dashboard", dashboard_routes);
     let api = ApiSettings::build().map_err(|e| {
         error!("{}", e);
         e
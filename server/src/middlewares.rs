@@ -1,20 +1,16 @@
-use std::usize;
-
 use axum::body::{to_bytes, Body, Bytes};
 
 pub mod logging_middleware;
 
-pub fn process_response(
-    body: Body,
-) -> std::pin::Pin<
-    Box<dyn std::future::Future<Output = Result<(Bytes, serde_json::Value), Box<dyn std::error::Error>>> + Send>,
-> {
-    let result = Box::pin(async move {
+/// レスポンスボディを読み取った結果。読み取ったバイト列とJSONとして解釈した値
+type ProcessedResponse = Result<(Bytes, serde_json::Value), Box<dyn std::error::Error>>;
+
+pub fn process_response(body: Body) -> std::pin::Pin<Box<dyn std::future::Future<Output = ProcessedResponse> + Send>> {
+    Box::pin(async move {
         let bytes = to_bytes(body, usize::MAX).await?;
         let body_string = String::from_utf8(bytes.to_vec())?;
-        let res_json = serde_json::from_str(&body_string)?;
+        let res_json: serde_json::Value = serde_json::from_str(&body_string)?;
 
         Ok((bytes, res_json))
-    });
-    result
+    })
 }
@@ -1,5 +1,3 @@
-use std::usize;
-
 use axum::{
     body::{to_bytes, Body},
     extract::Request,
@@ -1,6 +1,14 @@
+pub mod budget_dto;
 pub mod category_dto;
+pub mod cost_split_dto;
+pub mod dashboard_dto;
+pub mod ledger_dto;
+pub mod list_order;
 pub mod payment_method_dto;
+pub mod price_change_dto;
 pub mod subscribe_dto;
+pub mod tag_dto;
+pub mod user_dto;
 /// DTOとドメインモデル間の相互変換を行うトレイト
 ///
 /// # 型パラメータ
@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use domain::budget::{budget_status::BudgetStatus, Budget};
+use rust_decimal::Decimal;
+
+use crate::error::{self, ApplicationError};
+
+/// 予算を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BudgetDto {
+    /// 新規作成時は空文字
+    #[serde(default)]
+    budget_id: String,
+    user_id: String,
+    /// 対象のカテゴリID。未指定の場合は全体の予算
+    #[serde(default)]
+    category_id: Option<String>,
+    /// 月の上限額
+    limit: String,
+    currency: String,
+    /// 作成日時 (参照専用)
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    /// 更新日時 (参照専用)
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl BudgetDto {
+    pub fn new(
+        budget_id: String,
+        user_id: String,
+        category_id: Option<String>,
+        limit: String,
+        currency: String,
+    ) -> Self {
+        Self { budget_id, user_id, category_id, limit, currency, created_at: None, updated_at: None }
+    }
+
+    pub fn budget_id(&self) -> &str {
+        &self.budget_id
+    }
+
+    pub fn category_id(&self) -> &Option<String> {
+        &self.category_id
+    }
+
+    pub fn limit(&self) -> &str {
+        &self.limit
+    }
+}
+
+impl super::DTO<BudgetDto, Budget, ApplicationError> for BudgetDto {
+    fn map_to_domain_model(v: BudgetDto) -> Result<Budget, ApplicationError> {
+        use domain::budget::budget_id::BudgetId;
+        use domain::category::category_id::CategoryId;
+        use domain::user::user_id::UserId;
+        use domain::value_object::{amount::Amount, currency::Currency, money::Money};
+
+        let budget_id = match &v.budget_id {
+            s if s.is_empty() => BudgetId::new(),
+            s => BudgetId::from_str(s).map_err(error::to_aggregate_id_error)?,
+        };
+        let user_id = UserId::from_str(&v.user_id).map_err(error::to_aggregate_id_error)?;
+        let category_id = match &v.category_id {
+            Some(c) => Some(CategoryId::from_str(c).map_err(error::to_aggregate_id_error)?),
+            None => None,
+        };
+        let amount = Amount::from_str(&v.limit).map_err(error::to_budget_error)?;
+        let currency = Currency::from_str(&v.currency).map_err(error::to_budget_error)?;
+        let limit = Money::new(amount, currency).map_err(error::to_budget_error)?;
+
+        // 作成日時・更新日時はクライアントの値を用いず、サービス層で記録する
+        Ok(Budget::from(budget_id, user_id, category_id, limit, Utc::now(), None))
+    }
+
+    fn map_to_dto(v: &Budget) -> BudgetDto {
+        BudgetDto {
+            budget_id: v.budget_id().to_string(),
+            user_id: v.user_id().to_string(),
+            category_id: v.category_id().as_ref().map(|c| c.to_string()),
+            limit: v.limit().amount().to_string(),
+            currency: v.limit().currency().to_string(),
+            created_at: Some(*v.created_at()),
+            updated_at: *v.updated_at(),
+        }
+    }
+}
+
+/// 予算に対する支出の評価結果を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct BudgetEvaluationDto {
+    budget_id: String,
+    /// 対象のカテゴリID。全体の予算の場合はNULL
+    category_id: Option<String>,
+    limit: String,
+    currency: String,
+    /// ACTIVEなサブスクの月額換算の負担額の合計
+    spent: String,
+    /// 上限額までの残り (超過している場合は負の値)
+    remaining: String,
+    /// 上限額に対する使用率 (%、小数点以下1桁)
+    usage_percent: String,
+    /// UNDER, NEAR, OVER
+    status: String,
+    /// 通貨が上限額と異なるため集計に含めなかったサブスクの件数
+    excluded_count: usize,
+}
+
+impl BudgetEvaluationDto {
+    /// 予算と月額換算の支出額から評価結果を作成する
+    ///
+    /// # 引数
+    /// * `budget` - [Budget] 評価した予算
+    /// * `spent` - [Decimal] 月額換算の支出額
+    /// * `excluded_count` - 通貨が異なるため集計に含めなかったサブスクの件数
+    ///
+    /// # 戻り値
+    /// - [BudgetEvaluationDto] 評価結果
+    pub fn new(budget: &Budget, spent: Decimal, excluded_count: usize) -> Self {
+        let limit = *budget.limit().amount().value();
+        let usage_percent = (spent * Decimal::from(100) / limit).round_dp(1);
+        Self {
+            budget_id: budget.budget_id().to_string(),
+            category_id: budget.category_id().as_ref().map(|c| c.to_string()),
+            limit: limit.to_string(),
+            currency: budget.limit().currency().to_string(),
+            spent: spent.to_string(),
+            remaining: (limit - spent).to_string(),
+            usage_percent: usage_percent.to_string(),
+            status: BudgetStatus::evaluate(spent, limit).to_string(),
+            excluded_count,
+        }
+    }
+
+    pub fn budget_id(&self) -> &str {
+        &self.budget_id
+    }
+
+    pub fn spent(&self) -> &str {
+        &self.spent
+    }
+
+    pub fn remaining(&self) -> &str {
+        &self.remaining
+    }
+
+    pub fn usage_percent(&self) -> &str {
+        &self.usage_percent
+    }
+
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub fn excluded_count(&self) -> usize {
+        self.excluded_count
+    }
+}
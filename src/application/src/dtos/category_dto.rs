@@ -1,7 +1,14 @@
 use std::str::FromStr;
 
+use chrono::{DateTime, Utc};
 use domain::{
-    category::{category_error::CategoryError, category_id::CategoryId, category_name::CategoryName, Category},
+    category::{
+        category_appearance::{CategoryColor, CategoryIcon},
+        category_error::CategoryError,
+        category_id::CategoryId,
+        category_name::CategoryName,
+        Category,
+    },
     user::user_id::UserId,
 };
 
@@ -14,11 +21,39 @@ pub struct CategoryDto {
     category_id: String,
     user_id: String,
     category_name: String,
+    /// 表示色 (`#RRGGBB` 形式)
+    #[serde(default)]
+    color: Option<String>,
+    /// アイコンのキー
+    #[serde(default)]
+    icon: Option<String>,
+    /// 並び順 (参照専用。並び替えは専用のエンドポイントで行う)
+    #[serde(default)]
+    sort_order: Option<u32>,
+    /// 作成日時 (参照専用)
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    /// 更新日時 (参照専用)
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+    /// ゴミ箱に移動した日時 (参照専用)
+    #[serde(default)]
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl CategoryDto {
     pub fn new(category_id: String, user_id: String, category_name: String) -> Self {
-        Self { category_id, user_id, category_name }
+        Self {
+            category_id,
+            user_id,
+            category_name,
+            color: None,
+            icon: None,
+            sort_order: None,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
+        }
     }
 
     pub fn builder() -> CategoryDtoBuilder {
@@ -31,6 +66,12 @@ pub struct CategoryDtoBuilder {
     category_id: Option<String>,
     user_id: Option<String>,
     category_name: Option<String>,
+    color: Option<String>,
+    icon: Option<String>,
+    sort_order: Option<u32>,
+    created_at: Option<DateTime<Utc>>,
+    updated_at: Option<DateTime<Utc>>,
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl CategoryDtoBuilder {
@@ -49,6 +90,36 @@ impl CategoryDtoBuilder {
         self
     }
 
+    pub fn color(mut self, color: Option<String>) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn icon(mut self, icon: Option<String>) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: Option<u32>) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    pub fn created_at(mut self, created_at: Option<DateTime<Utc>>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    pub fn updated_at(mut self, updated_at: Option<DateTime<Utc>>) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn deleted_at(mut self, deleted_at: Option<DateTime<Utc>>) -> Self {
+        self.deleted_at = deleted_at;
+        self
+    }
+
     pub fn build(self) -> Result<CategoryDto, CategoryError> {
         Ok(CategoryDto {
             category_id: self.category_id.ok_or_else(|| CategoryError::MissingField("category_id".to_string()))?,
@@ -56,22 +127,61 @@ impl CategoryDtoBuilder {
             category_name: self
                 .category_name
                 .ok_or_else(|| CategoryError::MissingField("category_name".to_string()))?,
+            color: self.color,
+            icon: self.icon,
+            sort_order: self.sort_order,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted_at: self.deleted_at,
         })
     }
 }
 
+/// カテゴリの並び替えリクエストを表すDTO
+///
+/// `category_ids` にはユーザーの全カテゴリのIDを表示したい順に並べて指定する
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CategoryOrderDto {
+    user_id: String,
+    category_ids: Vec<String>,
+}
+
+impl CategoryOrderDto {
+    pub fn new(user_id: String, category_ids: Vec<String>) -> Self {
+        Self { user_id, category_ids }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn category_ids(&self) -> &[String] {
+        &self.category_ids
+    }
+}
+
 impl DTO<CategoryDto, Category, ApplicationError> for CategoryDto {
     fn map_to_domain_model(v: CategoryDto) -> Result<Category, ApplicationError> {
         let category_id = match v.category_id {
             s if s.is_empty() => CategoryId::new(),
-            _ => CategoryId::from_str(v.category_id.as_str()).map_err(|e| to_aggregate_id_error(e))?,
+            _ => CategoryId::from_str(v.category_id.as_str()).map_err(to_aggregate_id_error)?,
         };
 
-        let user_id = UserId::from_str(v.user_id.as_str()).map_err(|e| to_aggregate_id_error(e))?;
+        let user_id = UserId::from_str(v.user_id.as_str()).map_err(to_aggregate_id_error)?;
+
+        let category_name = CategoryName::from_str(v.category_name.as_str()).map_err(to_category_error)?;
 
-        let category_name = CategoryName::from_str(v.category_name.as_str()).map_err(|e| to_category_error(e))?;
+        let color = match &v.color {
+            Some(c) => Some(CategoryColor::from_str(c).map_err(to_category_error)?),
+            None => None,
+        };
+        let icon = match &v.icon {
+            Some(i) => Some(CategoryIcon::from_str(i).map_err(to_category_error)?),
+            None => None,
+        };
 
-        Ok(Category::from(category_id, user_id, category_name))
+        // 並び順・作成日時・更新日時はクライアントの値を用いず、サービス層で記録する
+        Ok(Category::from(category_id, user_id, category_name, color, icon, 0, Utc::now(), None, None))
     }
 
     fn map_to_dto(v: &Category) -> CategoryDto {
@@ -79,6 +189,12 @@ impl DTO<CategoryDto, Category, ApplicationError> for CategoryDto {
             .category_id(v.category_id().to_string())
             .user_id(v.user_id().to_string())
             .category_name(v.category_name().to_string())
+            .color(v.color().as_ref().map(|c| c.to_string()))
+            .icon(v.icon().as_ref().map(|i| i.to_string()))
+            .sort_order(Some(v.sort_order()))
+            .created_at(Some(*v.created_at()))
+            .updated_at(*v.updated_at())
+            .deleted_at(*v.deleted_at())
             .build();
         builder.unwrap()
     }
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use crate::error::{self, ApplicationError};
+use domain::subscribe::cost_split::{MemberShare, Share, SplitMember};
+use domain::subscribe::subscribe_error::SubscribeError;
+use domain::subscribe::Subscribe;
+use domain::user::user_id::UserId;
+use domain::value_object::{amount::Amount, currency::Currency, money::Money};
+use rust_decimal::Decimal;
+
+/// 費用を分担するメンバー1人分の設定を表すDTO
+///
+/// `user_id`と`name`、`amount`と`percentage`はそれぞれどちらか一方のみを指定する
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SplitMemberDto {
+    /// アプリを利用している他のユーザーのID
+    #[serde(default)]
+    user_id: Option<String>,
+    /// アプリを利用していない人の名前
+    #[serde(default)]
+    name: Option<String>,
+    /// 1回の請求あたりの固定の負担額
+    #[serde(default)]
+    amount: Option<String>,
+    /// 請求額に対する負担割合(%)
+    #[serde(default)]
+    percentage: Option<String>,
+}
+
+impl SplitMemberDto {
+    pub fn new(
+        user_id: Option<String>,
+        name: Option<String>,
+        amount: Option<String>,
+        percentage: Option<String>,
+    ) -> Self {
+        Self { user_id, name, amount, percentage }
+    }
+
+    /// ドメインモデルからDTOを生成する
+    ///
+    /// # 引数
+    /// * `v` - [MemberShare] メンバーの負担分
+    ///
+    /// # 戻り値
+    /// - [SplitMemberDto] 生成されたDTO
+    pub fn map_to_dto(v: &MemberShare) -> Self {
+        let (user_id, name) = match v.member() {
+            SplitMember::User(user_id) => (Some(user_id.to_string()), None),
+            SplitMember::Person(name) => (None, Some(name.to_owned())),
+        };
+        let (amount, percentage) = match v.share() {
+            Share::Fixed(money) => (Some(money.amount().to_string()), None),
+            Share::Percentage(p) => (None, Some(p.to_string())),
+        };
+        Self::new(user_id, name, amount, percentage)
+    }
+
+    /// DTOをドメインモデルに変換する
+    ///
+    /// # 引数
+    /// * `currency` - [Currency] 固定額に用いるサブスクの通貨
+    ///
+    /// # 戻り値
+    /// - [MemberShare] メンバーの負担分
+    ///
+    /// # エラー
+    /// - [ApplicationError::SubscribeError] 指定が不足・重複している、または値が不正な場合
+    pub fn map_to_domain_model(self, currency: Currency) -> Result<MemberShare, ApplicationError> {
+        let member = match (self.user_id, self.name) {
+            (Some(user_id), None) => {
+                SplitMember::User(UserId::from_str(&user_id).map_err(error::to_aggregate_id_error)?)
+            }
+            (None, Some(name)) => SplitMember::Person(name),
+            _ => {
+                return Err(error::to_subscribe_error(SubscribeError::InvalidCostSplit(
+                    "either user_id or name must be specified".to_string(),
+                )))
+            }
+        };
+        let share = match (self.amount, self.percentage) {
+            (Some(amount), None) => {
+                let amount = Amount::from_str(&amount).map_err(error::to_subscribe_error)?;
+                Share::Fixed(Money::new(amount, currency).map_err(error::to_subscribe_error)?)
+            }
+            (None, Some(percentage)) => Share::Percentage(Decimal::from_str(&percentage).map_err(|_| {
+                error::to_subscribe_error(SubscribeError::InvalidCostSplit(format!("invalid percentage: {percentage}")))
+            })?),
+            _ => {
+                return Err(error::to_subscribe_error(SubscribeError::InvalidCostSplit(
+                    "either amount or percentage must be specified".to_string(),
+                )))
+            }
+        };
+        MemberShare::new(member, share).map_err(error::to_subscribe_error)
+    }
+}
+
+/// メンバー1人分の1回の請求あたりの負担額を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MemberOwedDto {
+    #[serde(flatten)]
+    member: SplitMemberDto,
+    /// 1回の請求あたりの負担額
+    owed: String,
+}
+
+impl MemberOwedDto {
+    pub fn member(&self) -> &SplitMemberDto {
+        &self.member
+    }
+
+    pub fn owed(&self) -> &str {
+        &self.owed
+    }
+}
+
+/// サブスクの請求額を各メンバーがいくら負担するかを表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CostSplitDto {
+    subscribe_id: String,
+    name: String,
+    amount: String,
+    currency: String,
+    payment_cycle: String,
+    /// 所有者自身の1回の請求あたりの負担額
+    own_amount: String,
+    members: Vec<MemberOwedDto>,
+}
+
+impl CostSplitDto {
+    /// サブスクからDTOを生成する
+    ///
+    /// # 引数
+    /// * `v` - [Subscribe] 対象のサブスク
+    ///
+    /// # 戻り値
+    /// - [CostSplitDto] 生成されたDTO
+    pub fn map_to_dto(v: &Subscribe) -> Self {
+        Self {
+            subscribe_id: v.subscribe_id().to_string(),
+            name: v.name().to_string(),
+            amount: v.amount().to_string(),
+            currency: v.currency().to_string(),
+            payment_cycle: v.payment_cycle().to_string(),
+            own_amount: v.own_amount().to_string(),
+            members: v
+                .owed_per_member()
+                .into_iter()
+                .map(|(m, owed)| MemberOwedDto { member: SplitMemberDto::map_to_dto(m), owed: owed.to_string() })
+                .collect(),
+        }
+    }
+
+    pub fn own_amount(&self) -> &str {
+        &self.own_amount
+    }
+
+    pub fn members(&self) -> &Vec<MemberOwedDto> {
+        &self.members
+    }
+}
@@ -0,0 +1,589 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::subscribe::{scheduled_payment::ScheduledPayment, subscribe_status::SubscribeStatus, Subscribe};
+use domain::value_object::currency::Currency;
+use rust_decimal::Decimal;
+
+/// 通貨ごとの支出の合計を表すDTO
+///
+/// 為替換算は行わないため、通貨が異なるサブスクは別々に集計する
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CurrencyTotalDto {
+    currency: String,
+    /// 月額換算の負担額の合計
+    monthly_total: String,
+    /// 年額換算の負担額の合計
+    yearly_total: String,
+    /// 集計したサブスクの件数
+    subscribe_count: usize,
+}
+
+impl CurrencyTotalDto {
+    pub fn new(currency: &Currency, monthly_total: Decimal, yearly_total: Decimal, subscribe_count: usize) -> Self {
+        Self {
+            currency: currency.to_string(),
+            monthly_total: monthly_total.to_string(),
+            yearly_total: yearly_total.to_string(),
+            subscribe_count,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn monthly_total(&self) -> &str {
+        &self.monthly_total
+    }
+
+    pub fn yearly_total(&self) -> &str {
+        &self.yearly_total
+    }
+
+    pub fn subscribe_count(&self) -> usize {
+        self.subscribe_count
+    }
+}
+
+/// ステータスごとのサブスクの件数を表すDTO
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StatusCountDto {
+    trial: usize,
+    active: usize,
+    paused: usize,
+    cancelled: usize,
+}
+
+impl StatusCountDto {
+    /// サブスクのステータスごとの件数を数える
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    ///
+    /// # 戻り値
+    /// - [StatusCountDto] ステータスごとの件数
+    pub fn count(subscribes: &[Subscribe]) -> Self {
+        subscribes.iter().fold(Self::default(), |mut acc, s| {
+            match s.status() {
+                SubscribeStatus::TRIAL => acc.trial += 1,
+                SubscribeStatus::ACTIVE => acc.active += 1,
+                SubscribeStatus::PAUSED => acc.paused += 1,
+                SubscribeStatus::CANCELLED => acc.cancelled += 1,
+            }
+            acc
+        })
+    }
+
+    pub fn trial(&self) -> usize {
+        self.trial
+    }
+
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    pub fn paused(&self) -> usize {
+        self.paused
+    }
+
+    pub fn cancelled(&self) -> usize {
+        self.cancelled
+    }
+}
+
+/// 直近の支払予定を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UpcomingPaymentDto {
+    subscribe_id: String,
+    name: String,
+    payment_date: DateTime<Utc>,
+    /// 1回の請求額
+    amount: String,
+    currency: String,
+}
+
+impl UpcomingPaymentDto {
+    /// サブスクと直近の請求からDTOを生成する
+    ///
+    /// # 引数
+    /// * `subscribe` - [Subscribe] 支払予定のサブスク
+    /// * `payment` - [ScheduledPayment] 直近の請求
+    ///
+    /// # 戻り値
+    /// - [UpcomingPaymentDto] 生成されたDTO
+    pub fn new(subscribe: &Subscribe, payment: &ScheduledPayment) -> Self {
+        Self {
+            subscribe_id: subscribe.subscribe_id().to_string(),
+            name: subscribe.name().to_string(),
+            payment_date: *payment.payment_date(),
+            amount: payment.amount().amount().to_string(),
+            currency: payment.amount().currency().to_string(),
+        }
+    }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn payment_date(&self) -> &DateTime<Utc> {
+        &self.payment_date
+    }
+}
+
+/// ダッシュボードの集計結果を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DashboardSummaryDto {
+    /// ACTIVEなサブスクの通貨ごとの合計 (通貨コード順)
+    totals: Vec<CurrencyTotalDto>,
+    status_counts: StatusCountDto,
+    /// 今後請求が発生するサブスクのうち最も近い支払予定。対象がない場合はNULL
+    next_payment: Option<UpcomingPaymentDto>,
+}
+
+impl DashboardSummaryDto {
+    pub fn new(
+        totals: Vec<CurrencyTotalDto>,
+        status_counts: StatusCountDto,
+        next_payment: Option<UpcomingPaymentDto>,
+    ) -> Self {
+        Self { totals, status_counts, next_payment }
+    }
+
+    pub fn totals(&self) -> &Vec<CurrencyTotalDto> {
+        &self.totals
+    }
+
+    pub fn status_counts(&self) -> &StatusCountDto {
+        &self.status_counts
+    }
+
+    pub fn next_payment(&self) -> &Option<UpcomingPaymentDto> {
+        &self.next_payment
+    }
+}
+
+/// カテゴリごとの支出を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CategorySpendDto {
+    /// カテゴリID。カテゴリが存在しないサブスクをまとめた「未分類」の場合はNULL
+    category_id: Option<String>,
+    category_name: String,
+    /// 月額換算の負担額
+    monthly_amount: String,
+    /// 年額換算の負担額
+    yearly_amount: String,
+    /// 通貨ごとの合計に占める割合(%)。小数第1位まで
+    percentage: String,
+    subscribe_count: usize,
+}
+
+impl CategorySpendDto {
+    pub fn new(
+        category_id: Option<String>,
+        category_name: String,
+        monthly_amount: Decimal,
+        yearly_amount: Decimal,
+        percentage: Decimal,
+        subscribe_count: usize,
+    ) -> Self {
+        Self {
+            category_id,
+            category_name,
+            monthly_amount: monthly_amount.to_string(),
+            yearly_amount: yearly_amount.to_string(),
+            percentage: percentage.to_string(),
+            subscribe_count,
+        }
+    }
+
+    pub fn category_id(&self) -> &Option<String> {
+        &self.category_id
+    }
+
+    pub fn category_name(&self) -> &str {
+        &self.category_name
+    }
+
+    pub fn monthly_amount(&self) -> &str {
+        &self.monthly_amount
+    }
+
+    pub fn yearly_amount(&self) -> &str {
+        &self.yearly_amount
+    }
+
+    pub fn percentage(&self) -> &str {
+        &self.percentage
+    }
+
+    pub fn subscribe_count(&self) -> usize {
+        self.subscribe_count
+    }
+}
+
+/// 通貨ごとのカテゴリ別支出の内訳を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CategoryBreakdownDto {
+    currency: String,
+    monthly_total: String,
+    yearly_total: String,
+    /// 月額の多い順
+    categories: Vec<CategorySpendDto>,
+}
+
+impl CategoryBreakdownDto {
+    pub fn new(
+        currency: &Currency,
+        monthly_total: Decimal,
+        yearly_total: Decimal,
+        categories: Vec<CategorySpendDto>,
+    ) -> Self {
+        Self {
+            currency: currency.to_string(),
+            monthly_total: monthly_total.to_string(),
+            yearly_total: yearly_total.to_string(),
+            categories,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn monthly_total(&self) -> &str {
+        &self.monthly_total
+    }
+
+    pub fn yearly_total(&self) -> &str {
+        &self.yearly_total
+    }
+
+    pub fn categories(&self) -> &Vec<CategorySpendDto> {
+        &self.categories
+    }
+}
+
+/// 前月からの支出の変動の要因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SpendChangeKind {
+    /// 課金が始まった
+    Added,
+    /// 解約により課金が止まった
+    Cancelled,
+    /// 一時停止により課金が止まった
+    Paused,
+    /// 金額が変わった
+    PriceChanged,
+}
+
+/// 前月からの支出の変動の要因となったサブスクを表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SpendChangeDto {
+    subscribe_id: String,
+    name: String,
+    kind: SpendChangeKind,
+    /// 前月の月額換算の負担額。課金されていなかった場合はNULL
+    before: Option<String>,
+    /// 当月の月額換算の負担額。課金されていなかった場合はNULL
+    after: Option<String>,
+    /// 前月からの増減額
+    delta: String,
+}
+
+impl SpendChangeDto {
+    /// 前月と当月の負担額から変動の要因を判定してDTOを生成する
+    ///
+    /// # 引数
+    /// * `subscribe` - [Subscribe] 対象のサブスク
+    /// * `before` - 前月の月額換算の負担額
+    /// * `after` - 当月の月額換算の負担額
+    ///
+    /// # 戻り値
+    /// - Option<[SpendChangeDto]> 負担額が変わっていない場合は [None]
+    pub fn between(subscribe: &Subscribe, before: Option<Decimal>, after: Option<Decimal>) -> Option<Self> {
+        let kind = match (before, after) {
+            (None, Some(_)) => SpendChangeKind::Added,
+            (Some(_), None) if subscribe.status() == &SubscribeStatus::PAUSED => SpendChangeKind::Paused,
+            (Some(_), None) => SpendChangeKind::Cancelled,
+            (Some(b), Some(a)) if b != a => SpendChangeKind::PriceChanged,
+            _ => return None,
+        };
+        let delta = after.unwrap_or_default() - before.unwrap_or_default();
+        Some(Self {
+            subscribe_id: subscribe.subscribe_id().to_string(),
+            name: subscribe.name().to_string(),
+            kind,
+            before: before.map(|v| v.to_string()),
+            after: after.map(|v| v.to_string()),
+            delta: delta.to_string(),
+        })
+    }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn kind(&self) -> SpendChangeKind {
+        self.kind
+    }
+
+    pub fn delta(&self) -> &str {
+        &self.delta
+    }
+}
+
+/// 1か月分の支出と前月からの変動を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MonthlySpendDto {
+    /// 対象月 (YYYY-MM)
+    month: String,
+    /// 月末時点で課金されていたサブスクの月額換算の負担額の合計
+    total: String,
+    /// 前月からの増減額
+    delta: String,
+    /// 変動の要因となったサブスク
+    changes: Vec<SpendChangeDto>,
+}
+
+impl MonthlySpendDto {
+    pub fn new(month: String, total: Decimal, delta: Decimal, changes: Vec<SpendChangeDto>) -> Self {
+        Self { month, total: total.to_string(), delta: delta.to_string(), changes }
+    }
+
+    pub fn month(&self) -> &str {
+        &self.month
+    }
+
+    pub fn total(&self) -> &str {
+        &self.total
+    }
+
+    pub fn delta(&self) -> &str {
+        &self.delta
+    }
+
+    pub fn changes(&self) -> &Vec<SpendChangeDto> {
+        &self.changes
+    }
+}
+
+/// 通貨ごとの月次の支出の推移を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SpendVarianceDto {
+    currency: String,
+    /// 古い月から順
+    months: Vec<MonthlySpendDto>,
+}
+
+impl SpendVarianceDto {
+    pub fn new(currency: &Currency, months: Vec<MonthlySpendDto>) -> Self {
+        Self { currency: currency.to_string(), months }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn months(&self) -> &Vec<MonthlySpendDto> {
+        &self.months
+    }
+}
+
+/// 将来の請求の1件を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ScheduledChargeDto {
+    subscribe_id: String,
+    name: String,
+    payment_method_id: String,
+    payment_date: DateTime<Utc>,
+    /// 請求額
+    amount: String,
+    /// 費用を分担している場合の所有者自身の負担額
+    own_amount: String,
+    currency: String,
+}
+
+impl ScheduledChargeDto {
+    /// サブスクと将来の請求からDTOを生成する
+    ///
+    /// # 引数
+    /// * `subscribe` - [Subscribe] 請求されるサブスク
+    /// * `payment` - [ScheduledPayment] 将来の請求
+    ///
+    /// # 戻り値
+    /// - [ScheduledChargeDto] 生成されたDTO
+    pub fn new(subscribe: &Subscribe, payment: &ScheduledPayment) -> Self {
+        Self {
+            subscribe_id: subscribe.subscribe_id().to_string(),
+            name: subscribe.name().to_string(),
+            payment_method_id: subscribe.payment_method_id().to_string(),
+            payment_date: *payment.payment_date(),
+            amount: payment.amount().amount().to_string(),
+            own_amount: payment.own_amount().to_string(),
+            currency: payment.amount().currency().to_string(),
+        }
+    }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn payment_date(&self) -> &DateTime<Utc> {
+        &self.payment_date
+    }
+
+    pub fn amount(&self) -> &str {
+        &self.amount
+    }
+}
+
+/// 通貨ごとの請求額の合計を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ChargeTotalDto {
+    currency: String,
+    /// 請求額の合計
+    total: String,
+    /// 所有者自身の負担額の合計
+    own_total: String,
+    charge_count: usize,
+}
+
+impl ChargeTotalDto {
+    pub fn new(currency: &Currency, total: Decimal, own_total: Decimal, charge_count: usize) -> Self {
+        Self {
+            currency: currency.to_string(),
+            total: total.to_string(),
+            own_total: own_total.to_string(),
+            charge_count,
+        }
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn total(&self) -> &str {
+        &self.total
+    }
+
+    pub fn own_total(&self) -> &str {
+        &self.own_total
+    }
+
+    pub fn charge_count(&self) -> usize {
+        self.charge_count
+    }
+}
+
+/// 1か月分の請求の予測を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct MonthlyForecastDto {
+    /// 対象月 (YYYY-MM)
+    month: String,
+    /// 通貨コード順の合計
+    totals: Vec<ChargeTotalDto>,
+    /// 支払予定日の順
+    charges: Vec<ScheduledChargeDto>,
+}
+
+impl MonthlyForecastDto {
+    pub fn new(month: String, totals: Vec<ChargeTotalDto>, charges: Vec<ScheduledChargeDto>) -> Self {
+        Self { month, totals, charges }
+    }
+
+    pub fn month(&self) -> &str {
+        &self.month
+    }
+
+    pub fn totals(&self) -> &Vec<ChargeTotalDto> {
+        &self.totals
+    }
+
+    pub fn charges(&self) -> &Vec<ScheduledChargeDto> {
+        &self.charges
+    }
+}
+
+/// 今後12か月の請求の予測を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SpendForecastDto {
+    /// 当月から順
+    months: Vec<MonthlyForecastDto>,
+    /// 12か月分の通貨コード順の合計
+    totals: Vec<ChargeTotalDto>,
+}
+
+impl SpendForecastDto {
+    pub fn new(months: Vec<MonthlyForecastDto>, totals: Vec<ChargeTotalDto>) -> Self {
+        Self { months, totals }
+    }
+
+    pub fn months(&self) -> &Vec<MonthlyForecastDto> {
+        &self.months
+    }
+
+    pub fn totals(&self) -> &Vec<ChargeTotalDto> {
+        &self.totals
+    }
+}
+
+/// 1日分の請求を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct CalendarDayDto {
+    /// 支払予定日 (YYYY-MM-DD)
+    date: NaiveDate,
+    /// 通貨コード順の合計
+    totals: Vec<ChargeTotalDto>,
+    charges: Vec<ScheduledChargeDto>,
+}
+
+impl CalendarDayDto {
+    pub fn new(date: NaiveDate, totals: Vec<ChargeTotalDto>, charges: Vec<ScheduledChargeDto>) -> Self {
+        Self { date, totals, charges }
+    }
+
+    pub fn date(&self) -> &NaiveDate {
+        &self.date
+    }
+
+    pub fn totals(&self) -> &Vec<ChargeTotalDto> {
+        &self.totals
+    }
+
+    pub fn charges(&self) -> &Vec<ScheduledChargeDto> {
+        &self.charges
+    }
+}
+
+/// 指定期間の支払カレンダーを表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PaymentCalendarDto {
+    from: NaiveDate,
+    to: NaiveDate,
+    /// 請求がある日のみ、日付の順
+    days: Vec<CalendarDayDto>,
+    /// 期間全体の通貨コード順の合計
+    totals: Vec<ChargeTotalDto>,
+}
+
+impl PaymentCalendarDto {
+    pub fn new(from: NaiveDate, to: NaiveDate, days: Vec<CalendarDayDto>, totals: Vec<ChargeTotalDto>) -> Self {
+        Self { from, to, days, totals }
+    }
+
+    pub fn days(&self) -> &Vec<CalendarDayDto> {
+        &self.days
+    }
+
+    pub fn totals(&self) -> &Vec<ChargeTotalDto> {
+        &self.totals
+    }
+}
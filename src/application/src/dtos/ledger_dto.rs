@@ -0,0 +1,164 @@
+use std::str::FromStr;
+
+use crate::error::{self, ApplicationError};
+use domain::ledger::Payment;
+
+/// 支払記録を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PaymentLedgerDto {
+    payment_id: String,
+    user_id: String,
+    subscribe_id: String,
+    due_date: chrono::DateTime<chrono::Utc>,
+    paid_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 実際に請求された金額
+    amount: String,
+    currency: String,
+    status: String,
+    note: Option<String>,
+}
+
+impl PaymentLedgerDto {
+    pub fn payment_id(&self) -> &str {
+        &self.payment_id
+    }
+
+    pub fn due_date(&self) -> &chrono::DateTime<chrono::Utc> {
+        &self.due_date
+    }
+
+    pub fn amount(&self) -> &str {
+        &self.amount
+    }
+
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+}
+
+impl super::DTO<PaymentLedgerDto, Payment, ApplicationError> for PaymentLedgerDto {
+    fn map_to_domain_model(v: PaymentLedgerDto) -> Result<Payment, ApplicationError> {
+        use domain::ledger::{payment_id::PaymentId, payment_status::PaymentStatus};
+        use domain::subscribe::subscribe_id::SubscribeId;
+        use domain::user::user_id::UserId;
+        use domain::value_object::{amount::Amount, currency::Currency, money::Money};
+
+        let payment_id = PaymentId::from_str(&v.payment_id).map_err(error::to_aggregate_id_error)?;
+        let user_id = UserId::from_str(&v.user_id).map_err(error::to_aggregate_id_error)?;
+        let subscribe_id = SubscribeId::from_str(&v.subscribe_id).map_err(error::to_aggregate_id_error)?;
+        let amount = Amount::from_str(&v.amount).map_err(error::to_ledger_error)?;
+        let currency = Currency::from_str(&v.currency).map_err(error::to_ledger_error)?;
+        let amount = Money::new(amount, currency).map_err(error::to_ledger_error)?;
+        let status = PaymentStatus::from_str(&v.status).map_err(error::to_ledger_error)?;
+
+        Ok(Payment::from(payment_id, user_id, subscribe_id, v.due_date, v.paid_date, amount, status, v.note))
+    }
+
+    fn map_to_dto(v: &Payment) -> PaymentLedgerDto {
+        PaymentLedgerDto {
+            payment_id: v.payment_id().to_string(),
+            user_id: v.user_id().to_string(),
+            subscribe_id: v.subscribe_id().to_string(),
+            due_date: *v.due_date(),
+            paid_date: *v.paid_date(),
+            amount: v.amount().amount().to_string(),
+            currency: v.amount().currency().to_string(),
+            status: v.status().to_string(),
+            note: v.note().clone(),
+        }
+    }
+}
+
+/// 支払の確定リクエストを表すDTO
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConfirmPaymentDto {
+    user_id: String,
+    subscribe_id: String,
+    /// 対象の支払予定日。未指定の場合は現在までに到来した最後の支払予定日
+    #[serde(default)]
+    due_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 実際の支払日。未指定の場合は確定した日時
+    #[serde(default)]
+    paid_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 実際に請求された金額。未指定の場合はサブスクの金額
+    #[serde(default)]
+    amount: Option<String>,
+}
+
+impl ConfirmPaymentDto {
+    pub fn new(
+        user_id: String,
+        subscribe_id: String,
+        due_date: Option<chrono::DateTime<chrono::Utc>>,
+        paid_date: Option<chrono::DateTime<chrono::Utc>>,
+        amount: Option<String>,
+    ) -> Self {
+        Self { user_id, subscribe_id, due_date, paid_date, amount }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn due_date(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.due_date
+    }
+
+    pub fn paid_date(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.paid_date
+    }
+
+    pub fn amount(&self) -> &Option<String> {
+        &self.amount
+    }
+}
+
+/// 支払への異議申し立てリクエストを表すDTO
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DisputePaymentDto {
+    user_id: String,
+    subscribe_id: String,
+    /// 対象の支払予定日。未指定の場合は現在までに到来した最後の支払予定日
+    #[serde(default)]
+    due_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 変更後のステータス(SKIPPED、FAILED、REFUNDED)
+    status: String,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+impl DisputePaymentDto {
+    pub fn new(
+        user_id: String,
+        subscribe_id: String,
+        due_date: Option<chrono::DateTime<chrono::Utc>>,
+        status: String,
+        note: Option<String>,
+    ) -> Self {
+        Self { user_id, subscribe_id, due_date, status, note }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn due_date(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.due_date
+    }
+
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    pub fn note(&self) -> &Option<String> {
+        &self.note
+    }
+}
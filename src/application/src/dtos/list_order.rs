@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+
+/// 一覧取得時の並び順
+///
+/// クエリパラメータ `sort` で指定する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListOrder {
+    /// 作成日時の新しい順 (最近追加した順)
+    Newest,
+    /// 作成日時の古い順
+    Oldest,
+    /// 更新日時の新しい順。一度も更新されていない場合は作成日時で比較する
+    RecentlyUpdated,
+}
+
+impl ListOrder {
+    /// 作成日時・更新日時をもとにリストを並び替える
+    ///
+    /// 同じ日時の要素は元の順序を保つ
+    ///
+    /// # 引数
+    /// * `items` - 並び替える要素
+    /// * `timestamps` - 要素から作成日時と更新日時を取り出す関数
+    pub fn sort<T>(self, items: &mut [T], timestamps: impl Fn(&T) -> (DateTime<Utc>, Option<DateTime<Utc>>)) {
+        match self {
+            ListOrder::Newest => items.sort_by_key(|v| std::cmp::Reverse(timestamps(v).0)),
+            ListOrder::Oldest => items.sort_by_key(|v| timestamps(v).0),
+            ListOrder::RecentlyUpdated => items.sort_by_key(|v| {
+                let (created_at, updated_at) = timestamps(v);
+                std::cmp::Reverse(updated_at.unwrap_or(created_at))
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_sort() {
+        let items = vec![
+            ("a", date(1), Some(date(20))),
+            ("b", date(10), None),
+            ("c", date(5), Some(date(6))),
+        ];
+        let test_case = vec![
+            (
+                ListOrder::Newest,
+                vec![
+                    "b", "c", "a",
+                ],
+            ),
+            (
+                ListOrder::Oldest,
+                vec![
+                    "a", "c", "b",
+                ],
+            ),
+            (
+                ListOrder::RecentlyUpdated,
+                vec![
+                    "a", "b", "c",
+                ],
+            ),
+        ];
+
+        for (order, expected) in test_case {
+            let mut v = items.clone();
+            order.sort(&mut v, |(_, created_at, updated_at)| (*created_at, *updated_at));
+            assert_eq!(v.iter().map(|(name, _, _)| *name).collect::<Vec<_>>(), expected, "order: {:?}", order);
+        }
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let result: ListOrder = serde_json::from_str("\"recently_updated\"").unwrap();
+        assert_eq!(result, ListOrder::RecentlyUpdated);
+        assert!(serde_json::from_str::<ListOrder>("\"latest\"").is_err());
+    }
+}
@@ -16,8 +16,15 @@ pub struct PaymentMethodDTO {
     pub method_name: String,
     pub method_kind_name: String,
     pub additional_name: String,
+    /// 作成日時 (参照専用)
+    #[serde(default)]
     pub created_at: Option<DateTime<Utc>>,
+    /// 更新日時 (参照専用)
+    #[serde(default)]
     pub updated_at: Option<DateTime<Utc>>,
+    /// ゴミ箱に移動した日時 (参照専用)
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl PaymentMethodDTO {
@@ -29,6 +36,7 @@ impl PaymentMethodDTO {
         additional_name: &str,
         created_at: &Option<DateTime<Utc>>,
         updated_at: &Option<DateTime<Utc>>,
+        deleted_at: &Option<DateTime<Utc>>,
     ) -> PaymentMethodDTO {
         PaymentMethodDTO {
             payment_method_id: payment_method_id.to_owned(),
@@ -38,6 +46,7 @@ impl PaymentMethodDTO {
             additional_name: additional_name.to_string(),
             created_at: created_at.to_owned(),
             updated_at: updated_at.to_owned(),
+            deleted_at: deleted_at.to_owned(),
         }
     }
 }
@@ -46,20 +55,16 @@ impl DTO<PaymentMethodDTO, PaymentMethod, ApplicationError> for PaymentMethodDTO
     fn map_to_domain_model(v: PaymentMethodDTO) -> Result<PaymentMethod, ApplicationError> {
         let payment_method_id = match &v.payment_method_id {
             s if s.is_empty() => PaymentMethodId::new(),
-            _ => PaymentMethodId::from_str(&v.payment_method_id).map_err(|e| error::to_aggregate_id_error(e))?,
+            _ => PaymentMethodId::from_str(&v.payment_method_id).map_err(error::to_aggregate_id_error)?,
         };
-        let user_id = UserId::from_str(&v.user_id).map_err(|e| error::to_aggregate_id_error(e))?;
+        let user_id = UserId::from_str(&v.user_id).map_err(error::to_aggregate_id_error)?;
         let method_name =
-            PaymentMethodCategoryName::from_str(&v.method_name).map_err(|e| error::to_payment_method_error(e))?;
+            PaymentMethodCategoryName::from_str(&v.method_name).map_err(error::to_payment_method_error)?;
         let method_kind_name =
-            PaymentMethodKindName::from_str(&v.method_kind_name).map_err(|e| error::to_payment_method_error(e))?;
-
-        let created_at = PaymentMethod::make_created_at(&v.payment_method_id, v.created_at);
-
-        let updated_at = PaymentMethod::make_updated_at(&v.created_at);
+            PaymentMethodKindName::from_str(&v.method_kind_name).map_err(error::to_payment_method_error)?;
 
         PaymentMethod::is_valid_method_combination(&method_name, &method_kind_name)
-            .map_err(|e| error::to_payment_method_error(e))?;
+            .map_err(error::to_payment_method_error)?;
 
         Ok(PaymentMethod::new(
             payment_method_id,
@@ -67,8 +72,10 @@ impl DTO<PaymentMethodDTO, PaymentMethod, ApplicationError> for PaymentMethodDTO
             method_name,
             method_kind_name,
             &v.additional_name,
-            created_at,
-            updated_at,
+            // 作成日時・更新日時はクライアントの値を用いず、サービス層で記録する
+            Utc::now(),
+            None,
+            None,
         ))
     }
 
@@ -80,8 +87,9 @@ impl DTO<PaymentMethodDTO, PaymentMethod, ApplicationError> for PaymentMethodDTO
         let additional_name = v.additional_name();
         let created_at = Some(v.created_at().to_owned());
         let updated_at = v.updated_at();
+        let deleted_at = v.deleted_at();
 
-        let payment_dto = PaymentMethodDTO::new(
+        PaymentMethodDTO::new(
             payment_method_id,
             user_id,
             &method_name,
@@ -89,9 +97,8 @@ impl DTO<PaymentMethodDTO, PaymentMethod, ApplicationError> for PaymentMethodDTO
             additional_name,
             &created_at,
             updated_at,
-        );
-
-        payment_dto
+            deleted_at,
+        )
     }
 }
 
@@ -116,8 +123,9 @@ mod tests {
             method_name.clone(),
             method_kind_name.clone(),
             additional_name,
-            created_at.clone(),
-            updated_at.clone(),
+            created_at,
+            updated_at,
+            None,
         );
 
         let result = PaymentMethodDTO::map_to_dto(&payment_method);
@@ -136,8 +144,9 @@ mod tests {
         // Setup test data
         let payment_method_id = PaymentMethodId::new();
         let user_id = UserId::new();
-        let created_at = PaymentMethod::make_created_at(payment_method_id.value(), Some(Utc::now()));
-        let updated_at = PaymentMethod::make_updated_at(&Some(Utc::now()));
+        // クライアントから送られた日時は用いない
+        let created_at = Utc::now() - chrono::Duration::days(365);
+        let updated_at = Some(created_at);
 
         let dto = PaymentMethodDTO {
             payment_method_id: payment_method_id.value().to_string(),
@@ -147,20 +156,19 @@ mod tests {
             additional_name: "test_card".to_string(),
             created_at: Some(created_at),
             updated_at,
+            deleted_at: None,
         };
 
         // Execute
         let result = PaymentMethodDTO::map_to_domain_model(dto).unwrap();
-        println!("{:?}", result.updated_at());
-        println!("{:?}", updated_at);
         // Assert
         assert_eq!(result.payment_method_id().value(), payment_method_id.value());
         assert_eq!(result.user_id().value(), user_id.value());
         assert_eq!(result.method_name().to_string(), "Credit Card");
         assert_eq!(result.method_kind_name().to_string(), "JCB");
         assert_eq!(result.additional_name(), "test_card");
-        assert_eq!(result.created_at(), &created_at);
-        assert_eq!(result.updated_at().unwrap().timestamp(), updated_at.unwrap().timestamp());
+        assert!(result.created_at() > &created_at);
+        assert!(result.updated_at().is_none());
     }
 
     #[test]
@@ -174,6 +182,7 @@ mod tests {
             additional_name: "test_card".to_string(),
             created_at: Some(Utc::now()),
             updated_at: None,
+            deleted_at: None,
         };
 
         // Execute and assert error
@@ -193,6 +202,7 @@ mod tests {
             additional_name: "test_card".to_string(),
             created_at: Some(Utc::now()),
             updated_at: None,
+            deleted_at: None,
         };
 
         // Execute and assert error
@@ -0,0 +1,74 @@
+use domain::subscribe::{price_change::PriceChange, Subscribe};
+
+/// 金額の推移の1件を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PriceChangeDto {
+    effective_date: chrono::DateTime<chrono::Utc>,
+    amount: String,
+    currency: String,
+}
+
+impl PriceChangeDto {
+    pub fn new(effective_date: chrono::DateTime<chrono::Utc>, amount: String, currency: String) -> Self {
+        Self { effective_date, amount, currency }
+    }
+
+    /// 金額の変更履歴からDTOを生成する
+    ///
+    /// # 引数
+    /// * `v` - [PriceChange] 金額の変更履歴
+    ///
+    /// # 戻り値
+    /// - [PriceChangeDto] 生成されたDTO
+    pub fn map_to_dto(v: &PriceChange) -> Self {
+        Self::new(*v.effective_date(), v.amount().amount().to_string(), v.amount().currency().to_string())
+    }
+}
+
+/// サブスクの値上げを表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PriceIncreaseDto {
+    subscribe_id: String,
+    name: String,
+    effective_date: chrono::DateTime<chrono::Utc>,
+    previous_amount: String,
+    amount: String,
+    currency: String,
+    /// 値上げ率(%)
+    increase_rate: String,
+}
+
+impl PriceIncreaseDto {
+    /// サブスクと値上げ前後の変更履歴からDTOを生成する
+    ///
+    /// # 引数
+    /// * `subscribe` - [Subscribe] 値上げされたサブスク
+    /// * `previous` - [PriceChange] 値上げ前の変更履歴
+    /// * `current` - [PriceChange] 値上げ後の変更履歴
+    ///
+    /// # 戻り値
+    /// - [PriceIncreaseDto] 生成されたDTO
+    pub fn new(subscribe: &Subscribe, previous: &PriceChange, current: &PriceChange) -> Self {
+        Self {
+            subscribe_id: subscribe.subscribe_id().to_string(),
+            name: subscribe.name().to_string(),
+            effective_date: *current.effective_date(),
+            previous_amount: previous.amount().amount().to_string(),
+            amount: current.amount().amount().to_string(),
+            currency: current.amount().currency().to_string(),
+            increase_rate: current.change_rate(previous).to_string(),
+        }
+    }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn effective_date(&self) -> &chrono::DateTime<chrono::Utc> {
+        &self.effective_date
+    }
+
+    pub fn increase_rate(&self) -> &str {
+        &self.increase_rate
+    }
+}
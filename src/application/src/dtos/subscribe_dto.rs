@@ -1,5 +1,6 @@
 use std::str::FromStr;
 
+use crate::dtos::cost_split_dto::SplitMemberDto;
 use crate::error::{self, ApplicationError};
 use domain::subscribe::Subscribe;
 
@@ -10,6 +11,9 @@ pub struct SubscribeDto {
     name: String,
     payment_method_id: String,
     amount: String,
+    /// ISO 4217 の通貨コード。未指定の場合、登録時はユーザーの国の通貨、更新時は現在の通貨
+    #[serde(default)]
+    currency: String,
     payment_cycle: String,
     category_id: String,
     icon_local_path: String,
@@ -19,6 +23,39 @@ pub struct SubscribeDto {
     auto_renewal: bool,
     status: String,
     memo: Option<String>,
+    /// 無料トライアル終了日
+    #[serde(default)]
+    trial_end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 無料トライアル終了後の金額
+    #[serde(default)]
+    post_trial_amount: Option<String>,
+    /// 最低契約期間の終了日
+    #[serde(default)]
+    commitment_end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 解約の事前通知期間(日数)
+    #[serde(default)]
+    notice_period_days: Option<u32>,
+    /// 違約金なしで解約できる最終日 (参照専用)
+    #[serde(default)]
+    last_cancellation_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// 所有者以外で費用を分担するメンバー
+    #[serde(default)]
+    cost_split: Vec<SplitMemberDto>,
+    /// 所有者自身の1回の請求あたりの負担額 (参照専用)
+    #[serde(default)]
+    own_amount: Option<String>,
+    /// カテゴリとは別に付けるタグ
+    #[serde(default)]
+    tags: Vec<String>,
+    /// 作成日時 (参照専用)
+    #[serde(default)]
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// 更新日時 (参照専用)
+    #[serde(default)]
+    updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// ゴミ箱に移動した日時 (参照専用)
+    #[serde(default)]
+    deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl SubscribeDto {
@@ -28,6 +65,7 @@ impl SubscribeDto {
         name: String,
         payment_method_id: String,
         amount: String,
+        currency: String,
         payment_cycle: String,
         category_id: String,
         icon_local_path: String,
@@ -37,6 +75,12 @@ impl SubscribeDto {
         auto_renewal: bool,
         status: String,
         memo: Option<String>,
+        trial_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        post_trial_amount: Option<String>,
+        commitment_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        notice_period_days: Option<u32>,
+        cost_split: Vec<SplitMemberDto>,
+        tags: Vec<String>,
     ) -> Self {
         Self {
             subscribe_id,
@@ -44,6 +88,7 @@ impl SubscribeDto {
             name,
             payment_method_id,
             amount,
+            currency,
             payment_cycle,
             category_id,
             icon_local_path,
@@ -53,12 +98,63 @@ impl SubscribeDto {
             auto_renewal,
             status,
             memo,
+            trial_end_date,
+            post_trial_amount,
+            commitment_end_date,
+            notice_period_days,
+            last_cancellation_date: None,
+            cost_split,
+            own_amount: None,
+            tags,
+            created_at: None,
+            updated_at: None,
+            deleted_at: None,
         }
     }
 
     pub fn builder() -> SubscribeDtoBuilder {
         SubscribeDtoBuilder::default()
     }
+
+    pub fn subscribe_id(&self) -> &str {
+        &self.subscribe_id
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    /// 通貨を差し替えたDTOを返す
+    ///
+    /// # 引数
+    /// * `currency` - ISO 4217 の通貨コード
+    pub fn with_currency(self, currency: String) -> Self {
+        Self { currency, ..self }
+    }
+
+    pub fn last_cancellation_date(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.last_cancellation_date
+    }
+
+    pub fn own_amount(&self) -> &Option<String> {
+        &self.own_amount
+    }
+
+    pub fn created_at(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.updated_at
+    }
+
+    pub fn deleted_at(&self) -> &Option<chrono::DateTime<chrono::Utc>> {
+        &self.deleted_at
+    }
 }
 
 #[derive(Default)]
@@ -68,6 +164,7 @@ pub struct SubscribeDtoBuilder {
     name: Option<String>,
     payment_method_id: Option<String>,
     amount: Option<String>,
+    currency: Option<String>,
     payment_cycle: Option<String>,
     category_id: Option<String>,
     icon_local_path: Option<String>,
@@ -77,6 +174,17 @@ pub struct SubscribeDtoBuilder {
     auto_renewal: Option<bool>,
     status: Option<String>,
     memo: Option<String>,
+    trial_end_date: Option<chrono::DateTime<chrono::Utc>>,
+    post_trial_amount: Option<String>,
+    commitment_end_date: Option<chrono::DateTime<chrono::Utc>>,
+    notice_period_days: Option<u32>,
+    last_cancellation_date: Option<chrono::DateTime<chrono::Utc>>,
+    cost_split: Vec<SplitMemberDto>,
+    own_amount: Option<String>,
+    tags: Vec<String>,
+    created_at: Option<chrono::DateTime<chrono::Utc>>,
+    updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    deleted_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl SubscribeDtoBuilder {
@@ -105,6 +213,11 @@ impl SubscribeDtoBuilder {
         self
     }
 
+    pub fn currency(mut self, currency: String) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
     pub fn payment_cycle(mut self, payment_cycle: String) -> Self {
         self.payment_cycle = Some(payment_cycle);
         self
@@ -150,6 +263,61 @@ impl SubscribeDtoBuilder {
         self
     }
 
+    pub fn trial_end_date(mut self, trial_end_date: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.trial_end_date = trial_end_date;
+        self
+    }
+
+    pub fn post_trial_amount(mut self, post_trial_amount: Option<String>) -> Self {
+        self.post_trial_amount = post_trial_amount;
+        self
+    }
+
+    pub fn commitment_end_date(mut self, commitment_end_date: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.commitment_end_date = commitment_end_date;
+        self
+    }
+
+    pub fn notice_period_days(mut self, notice_period_days: Option<u32>) -> Self {
+        self.notice_period_days = notice_period_days;
+        self
+    }
+
+    pub fn last_cancellation_date(mut self, last_cancellation_date: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.last_cancellation_date = last_cancellation_date;
+        self
+    }
+
+    pub fn cost_split(mut self, cost_split: Vec<SplitMemberDto>) -> Self {
+        self.cost_split = cost_split;
+        self
+    }
+
+    pub fn own_amount(mut self, own_amount: Option<String>) -> Self {
+        self.own_amount = own_amount;
+        self
+    }
+
+    pub fn tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn created_at(mut self, created_at: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.created_at = created_at;
+        self
+    }
+
+    pub fn updated_at(mut self, updated_at: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.updated_at = updated_at;
+        self
+    }
+
+    pub fn deleted_at(mut self, deleted_at: Option<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.deleted_at = deleted_at;
+        self
+    }
+
     pub fn build(self) -> Result<SubscribeDto, domain::subscribe::subscribe_error::SubscribeError> {
         use domain::subscribe::subscribe_error::SubscribeError;
 
@@ -161,6 +329,7 @@ impl SubscribeDtoBuilder {
                 .payment_method_id
                 .ok_or_else(|| SubscribeError::MissingField("payment_method_id".to_string()))?,
             amount: self.amount.ok_or_else(|| SubscribeError::MissingField("amount".to_string()))?,
+            currency: self.currency.unwrap_or_default(),
             payment_cycle: self
                 .payment_cycle
                 .ok_or_else(|| SubscribeError::MissingField("payment_cycle".to_string()))?,
@@ -178,6 +347,17 @@ impl SubscribeDtoBuilder {
             auto_renewal: self.auto_renewal.unwrap_or(false),
             status: self.status.ok_or_else(|| SubscribeError::MissingField("status".to_string()))?,
             memo: self.memo,
+            trial_end_date: self.trial_end_date,
+            post_trial_amount: self.post_trial_amount,
+            commitment_end_date: self.commitment_end_date,
+            notice_period_days: self.notice_period_days,
+            last_cancellation_date: self.last_cancellation_date,
+            cost_split: self.cost_split,
+            own_amount: self.own_amount,
+            tags: self.tags,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            deleted_at: self.deleted_at,
         })
     }
 }
@@ -188,26 +368,67 @@ impl crate::dtos::DTO<SubscribeDto, domain::subscribe::Subscribe, ApplicationErr
         use domain::payment::payment_method_id::PaymentMethodId;
         use domain::payment_cycle::PaymentCycle;
         use domain::subscribe::{
-            subscribe_id::SubscribeId, subscribe_name::SubscribeName, subscribe_status::SubscribeStatus,
+            contract_term::ContractTerm,
+            cost_split::CostSplit,
+            subscribe_error::SubscribeError,
+            subscribe_id::SubscribeId,
+            subscribe_name::SubscribeName,
+            subscribe_status::SubscribeStatus,
+            tag::{Tag, TagSet},
+            trial::Trial,
         };
         use domain::user::user_id::UserId;
-        use domain::value_object::amount::Amount;
+        use domain::value_object::{amount::Amount, currency::Currency, money::Money};
 
         let subscribe_id = match v.subscribe_id {
             s if s.is_empty() => SubscribeId::new(),
-            _ => SubscribeId::from_str(&v.subscribe_id).map_err(|e| error::to_aggregate_id_error(e))?,
+            _ => SubscribeId::from_str(&v.subscribe_id).map_err(error::to_aggregate_id_error)?,
         };
-        let user_id = UserId::from_str(&v.user_id).map_err(|e| error::to_aggregate_id_error(e))?;
-        let name = SubscribeName::from_str(&v.name).map_err(|e| error::to_subscribe_error(e))?;
+        let user_id = UserId::from_str(&v.user_id).map_err(error::to_aggregate_id_error)?;
+        let name = SubscribeName::from_str(&v.name).map_err(error::to_subscribe_error)?;
         let payment_method_id =
-            PaymentMethodId::from_str(&v.payment_method_id).map_err(|e| error::to_aggregate_id_error(e))?;
-        let amount = Amount::from_str(&v.amount).map_err(|e| error::to_subscribe_error(e))?;
+            PaymentMethodId::from_str(&v.payment_method_id).map_err(error::to_aggregate_id_error)?;
+        let amount = Amount::from_str(&v.amount).map_err(error::to_subscribe_error)?;
+        let currency = match v.currency {
+            s if s.is_empty() => Currency::default(),
+            _ => Currency::from_str(&v.currency).map_err(error::to_subscribe_error)?,
+        };
+        let amount = Money::new(amount, currency).map_err(error::to_subscribe_error)?;
+
+        let payment_cycle = PaymentCycle::from_str(&v.payment_cycle).map_err(error::to_subscribe_error)?;
 
-        let payment_cycle = PaymentCycle::from_str(&v.payment_cycle).map_err(|e| error::to_subscribe_error(e))?;
+        let category_id = CategoryId::from_str(&v.category_id).map_err(error::to_subscribe_error)?;
 
-        let category_id = CategoryId::from_str(&v.category_id).map_err(|e| error::to_subscribe_error(e))?;
+        let status = SubscribeStatus::from_str(&v.status).map_err(error::to_subscribe_error)?;
 
-        let status = SubscribeStatus::from_str(&v.status).map_err(|e| error::to_subscribe_error(e))?;
+        let post_trial_amount = match v.post_trial_amount {
+            Some(s) => {
+                let amount = Amount::from_str(&s).map_err(error::to_subscribe_error)?;
+                Some(Money::new(amount, currency).map_err(error::to_subscribe_error)?)
+            }
+            None => None,
+        };
+        let trial = v.trial_end_date.map(|end_date| Trial::new(end_date, post_trial_amount));
+        if status == SubscribeStatus::TRIAL && trial.is_none() {
+            return Err(error::to_subscribe_error(SubscribeError::MissingField("trial_end_date".to_string())));
+        }
+        if v.commitment_end_date.is_some_and(|end| end < v.first_payment_date) {
+            return Err(error::to_subscribe_error(SubscribeError::InvalidContractTerm(
+                "commitment end date must not be before first payment date".to_string(),
+            )));
+        }
+        let contract_term =
+            ContractTerm::new(v.commitment_end_date, v.notice_period_days).map_err(error::to_subscribe_error)?;
+        let members =
+            v.cost_split.into_iter().map(|m| m.map_to_domain_model(currency)).collect::<Result<Vec<_>, _>>()?;
+        let cost_split = CostSplit::new(members).map_err(error::to_subscribe_error)?;
+        cost_split.validate_against(&amount).map_err(error::to_subscribe_error)?;
+        let tags = v
+            .tags
+            .iter()
+            .map(|t| Tag::from_str(t).map_err(error::to_subscribe_error))
+            .collect::<Result<Vec<_>, _>>()?;
+        let tags = TagSet::new(tags).map_err(error::to_subscribe_error)?;
 
         Ok(Subscribe::from(
             subscribe_id,
@@ -224,6 +445,15 @@ impl crate::dtos::DTO<SubscribeDto, domain::subscribe::Subscribe, ApplicationErr
             v.auto_renewal,
             status,
             v.memo,
+            trial,
+            contract_term,
+            cost_split,
+            tags,
+            // 作成日時・更新日時はクライアントの値を用いず、サービス層で記録する
+            chrono::Utc::now(),
+            None,
+            None,
+            vec![],
         ))
     }
 
@@ -234,6 +464,7 @@ impl crate::dtos::DTO<SubscribeDto, domain::subscribe::Subscribe, ApplicationErr
             .name(v.name().to_string())
             .payment_method_id(v.payment_method_id().to_string())
             .amount(v.amount().to_string())
+            .currency(v.currency().to_string())
             .payment_cycle(v.payment_cycle().to_string())
             .category_id(v.category_id().to_string())
             .icon_local_path(v.icon_local_path().to_string())
@@ -243,6 +474,19 @@ impl crate::dtos::DTO<SubscribeDto, domain::subscribe::Subscribe, ApplicationErr
             .auto_renewal(v.auto_renewal())
             .status(v.status().to_string())
             .memo(v.memo().to_owned())
+            .trial_end_date(v.trial().as_ref().map(|t| *t.end_date()))
+            .post_trial_amount(
+                v.trial().as_ref().and_then(|t| t.post_trial_amount().as_ref()).map(|m| m.amount().to_string()),
+            )
+            .commitment_end_date(*v.contract_term().commitment_end_date())
+            .notice_period_days(v.contract_term().notice_period_days())
+            .last_cancellation_date(v.last_cancellation_date(&chrono::Utc::now()))
+            .cost_split(v.cost_split().members().iter().map(SplitMemberDto::map_to_dto).collect())
+            .own_amount(Some(v.own_amount().to_string()))
+            .tags(v.tags().iter().map(|t| t.to_string()).collect())
+            .created_at(Some(*v.created_at()))
+            .updated_at(*v.updated_at())
+            .deleted_at(*v.deleted_at())
             .build();
         builder.unwrap()
     }
@@ -0,0 +1,20 @@
+/// タグとそのタグが付いたサブスクの件数を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TagCountDto {
+    tag: String,
+    count: usize,
+}
+
+impl TagCountDto {
+    pub fn new(tag: String, count: usize) -> Self {
+        Self { tag, count }
+    }
+
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
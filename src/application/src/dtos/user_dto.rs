@@ -0,0 +1,132 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use domain::user::User;
+
+use crate::error::{self, ApplicationError};
+
+/// ユーザーの設定を表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UserSettingsDto {
+    /// 支払予定などの通知を受け取るか
+    #[serde(default = "default_notification")]
+    notification: bool,
+    /// 既定の通貨 (ISO 4217)。未指定の場合は国の通貨
+    #[serde(default)]
+    currency: Option<String>,
+}
+
+fn default_notification() -> bool {
+    true
+}
+
+impl Default for UserSettingsDto {
+    fn default() -> Self {
+        Self { notification: default_notification(), currency: None }
+    }
+}
+
+impl UserSettingsDto {
+    pub fn new(notification: bool, currency: Option<String>) -> Self {
+        Self { notification, currency }
+    }
+}
+
+/// ユーザーを表すDTO
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct UserDto {
+    /// 新規作成時は空文字
+    #[serde(default)]
+    user_id: String,
+    name: String,
+    email: String,
+    #[serde(default)]
+    profile_icon_path: Option<String>,
+    /// 国マスタの国ID
+    country_id: i32,
+    #[serde(default)]
+    settings: UserSettingsDto,
+    /// 設定と国から決まる既定の通貨 (参照専用)
+    #[serde(default)]
+    default_currency: Option<String>,
+    /// 作成日時 (参照専用)
+    #[serde(default)]
+    created_at: Option<DateTime<Utc>>,
+    /// 更新日時 (参照専用)
+    #[serde(default)]
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl UserDto {
+    pub fn new(
+        user_id: String,
+        name: String,
+        email: String,
+        profile_icon_path: Option<String>,
+        country_id: i32,
+        settings: UserSettingsDto,
+    ) -> Self {
+        Self {
+            user_id,
+            name,
+            email,
+            profile_icon_path,
+            country_id,
+            settings,
+            default_currency: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    pub fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    pub fn default_currency(&self) -> &Option<String> {
+        &self.default_currency
+    }
+}
+
+impl super::DTO<UserDto, User, ApplicationError> for UserDto {
+    fn map_to_domain_model(v: UserDto) -> Result<User, ApplicationError> {
+        use domain::user::{user_id::UserId, user_profile::UserProfile, user_settings::UserSettings};
+        use domain::value_object::currency::Currency;
+
+        let profile = UserProfile::new(&v.name, &v.email, v.profile_icon_path).map_err(error::to_user_error)?;
+        let currency = match &v.settings.currency {
+            Some(c) => Some(Currency::from_str(c).map_err(error::to_user_error)?),
+            None => None,
+        };
+        let settings = UserSettings::new(v.settings.notification, currency);
+
+        let mut user = match &v.user_id {
+            s if s.is_empty() => User::new(profile.clone(), v.country_id)?,
+            s => {
+                let user_id = UserId::from_str(s).map_err(error::to_aggregate_id_error)?;
+                // 作成日時・更新日時はクライアントの値を用いず、サービス層で記録する
+                let now = Utc::now();
+                User::from(user_id, profile.clone(), v.country_id, UserSettings::default(), now, now)
+            }
+        };
+        user.change(profile, v.country_id, settings)?;
+        Ok(user)
+    }
+
+    fn map_to_dto(v: &User) -> UserDto {
+        UserDto {
+            user_id: v.user_id().to_string(),
+            name: v.profile().name().to_string(),
+            email: v.profile().email().to_string(),
+            profile_icon_path: v.profile().profile_icon_path().clone(),
+            country_id: v.country_id(),
+            settings: UserSettingsDto {
+                notification: v.settings().notification(),
+                currency: v.settings().currency().map(|c| c.to_string()),
+            },
+            default_currency: Some(v.default_currency().to_string()),
+            created_at: Some(*v.created_at()),
+            updated_at: Some(*v.updated_at()),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use domain::{
-    category::category_error::CategoryError, payment::payment_error::PaymentError,
-    subscribe::subscribe_error::SubscribeError, AggregateIdError,
+    budget::budget_error::BudgetError, category::category_error::CategoryError, ledger::ledger_error::LedgerError,
+    payment::payment_error::PaymentError, subscribe::subscribe_error::SubscribeError, user::user_error::UserError,
+    AggregateIdError,
 };
 use thiserror::Error;
 use tracing::error;
@@ -18,32 +19,64 @@ pub enum ApplicationError {
 
     #[error("Category error: '{0}")]
     CategoryError(String),
+
+    #[error("Ledger error: '{0}")]
+    LedgerError(String),
+
+    #[error("Budget error: '{0}")]
+    BudgetError(String),
+
+    #[error("User error: '{0}")]
+    UserError(String),
+
+    #[error("Dashboard error: '{0}")]
+    DashboardError(String),
+
+    #[error("Conflict: '{0}'")]
+    Conflict(String),
 }
 impl From<PaymentError> for ApplicationError {
     fn from(value: PaymentError) -> Self {
-        let error = Self::PaymentMethodError(value.to_string());
-        error
+        Self::PaymentMethodError(value.to_string())
     }
 }
 
 impl From<AggregateIdError> for ApplicationError {
     fn from(value: AggregateIdError) -> Self {
-        let error = Self::InvalidAggregateIdFormatError(value.to_string());
-        error
+        Self::InvalidAggregateIdFormatError(value.to_string())
     }
 }
 
 impl From<SubscribeError> for ApplicationError {
     fn from(value: SubscribeError) -> Self {
-        let error = Self::SubscribeError(value.to_string());
-        error
+        Self::SubscribeError(value.to_string())
     }
 }
 
 impl From<CategoryError> for ApplicationError {
     fn from(value: CategoryError) -> Self {
-        let error = Self::CategoryError(value.to_string());
-        error
+        Self::CategoryError(value.to_string())
+    }
+}
+
+impl From<LedgerError> for ApplicationError {
+    fn from(value: LedgerError) -> Self {
+        Self::LedgerError(value.to_string())
+    }
+}
+
+impl From<BudgetError> for ApplicationError {
+    fn from(value: BudgetError) -> Self {
+        Self::BudgetError(value.to_string())
+    }
+}
+
+impl From<UserError> for ApplicationError {
+    fn from(value: UserError) -> Self {
+        match value {
+            UserError::AlreadyExists => Self::Conflict(value.to_string()),
+            _ => Self::UserError(value.to_string()),
+        }
     }
 }
 
@@ -63,6 +96,22 @@ pub fn to_category_error<E: ToString>(e: E) -> ApplicationError {
     ApplicationError::CategoryError(e.to_string())
 }
 
+pub fn to_ledger_error<E: ToString>(e: E) -> ApplicationError {
+    ApplicationError::LedgerError(e.to_string())
+}
+
+pub fn to_budget_error<E: ToString>(e: E) -> ApplicationError {
+    ApplicationError::BudgetError(e.to_string())
+}
+
+pub fn to_user_error<E: ToString>(e: E) -> ApplicationError {
+    ApplicationError::UserError(e.to_string())
+}
+
+pub fn to_dashboard_error<E: ToString>(e: E) -> ApplicationError {
+    ApplicationError::DashboardError(e.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::error::ApplicationError;
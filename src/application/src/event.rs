@@ -0,0 +1,194 @@
+use std::sync::{Arc, RwLock};
+
+use domain::event::{event_publisher::EventPublisher, DomainEvent, DomainEventKind};
+use domain::user::user_id::UserId;
+use tracing::{error, info};
+
+use crate::error::ApplicationError;
+
+/// ドメインイベントを購読するハンドラのトレイト定義
+#[async_trait::async_trait]
+pub trait EventHandler: Send + Sync {
+    /// ドメインイベントを処理する
+    ///
+    /// 処理対象外のイベントは何もせずに `Ok(())` を返す
+    ///
+    /// # 引数
+    /// * `event` - [DomainEvent] 発行されたドメインイベント
+    ///
+    /// # エラー
+    /// - [ApplicationError] 処理に失敗した場合 (発行元の処理には影響しない)
+    async fn handle(&self, event: &DomainEvent) -> Result<(), ApplicationError>;
+}
+
+/// 同じプロセス内のハンドラにドメインイベントを配信する [EventPublisher] の実装
+///
+/// イベントごとに購読順でハンドラを呼び出し、失敗したハンドラはログに記録して次のハンドラに進む
+#[derive(Default)]
+pub struct EventDispatcher {
+    handlers: RwLock<Vec<Arc<dyn EventHandler>>>,
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ハンドラを購読者として登録する
+    ///
+    /// # 引数
+    /// * `handler` - [EventHandler] 登録するハンドラ
+    pub fn subscribe(&self, handler: Arc<dyn EventHandler>) {
+        match self.handlers.write() {
+            Ok(mut handlers) => handlers.push(handler),
+            Err(poisoned) => poisoned.into_inner().push(handler),
+        }
+    }
+
+    /// 登録済みのハンドラを取得する (配信中のロック保持を避けるため複製する)
+    fn handlers(&self) -> Vec<Arc<dyn EventHandler>> {
+        match self.handlers.read() {
+            Ok(handlers) => handlers.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventPublisher for EventDispatcher {
+    async fn publish(&self, events: Vec<DomainEvent>) {
+        let handlers = self.handlers();
+        for event in &events {
+            for handler in &handlers {
+                if let Err(e) = handler.handle(event).await {
+                    error!("failed to handle domain event {:?}: {}", event.kind(), e);
+                }
+            }
+        }
+    }
+}
+
+/// サービスがドメインイベントを発行するための共通の窓口
+///
+/// 既定では購読者のいない [EventDispatcher] に発行する
+#[derive(Clone)]
+pub struct EventEmitter {
+    publisher: Arc<dyn EventPublisher>,
+}
+
+impl Default for EventEmitter {
+    fn default() -> Self {
+        Self { publisher: Arc::new(EventDispatcher::new()) }
+    }
+}
+
+impl EventEmitter {
+    /// 集約に記録されたドメインイベントをまとめて発行する
+    ///
+    /// # 引数
+    /// * `events` - Vec<[DomainEvent]> 発生順のドメインイベント
+    pub async fn publish_all(&self, events: Vec<DomainEvent>) {
+        if !events.is_empty() {
+            self.publisher.publish(events).await;
+        }
+    }
+
+    /// ドメインイベントを1件発行する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 対象のデータを所有するユーザーID
+    /// * `kind` - [DomainEventKind] イベントの種類と内容
+    pub async fn publish(&self, user_id: &UserId, kind: DomainEventKind) {
+        self.publisher.publish(vec![DomainEvent::new(user_id.clone(), kind)]).await;
+    }
+}
+
+/// ドメインイベントを発行するサービスのトレイト定義
+pub trait PublishEvents: Sized {
+    /// サービスが保持する [EventEmitter] を取得する
+    fn emitter_mut(&mut self) -> &mut EventEmitter;
+
+    /// ドメインイベントの発行先を設定する
+    ///
+    /// 未設定の場合は購読者のいない [EventDispatcher] に発行する
+    ///
+    /// # 引数
+    /// * `publisher` - [EventPublisher] ドメインイベントの発行先
+    fn with_event_publisher(mut self, publisher: Arc<dyn EventPublisher>) -> Self {
+        self.emitter_mut().publisher = publisher;
+        self
+    }
+}
+
+/// ドメインイベントをログに出力するハンドラ
+pub struct LoggingEventHandler;
+
+#[async_trait::async_trait]
+impl EventHandler for LoggingEventHandler {
+    async fn handle(&self, event: &DomainEvent) -> Result<(), ApplicationError> {
+        info!(user_id = %event.user_id(), occurred_at = %event.occurred_at(), "domain event: {:?}", event.kind());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::event::DomainEventKind;
+    use domain::subscribe::subscribe_id::SubscribeId;
+    use domain::user::user_id::UserId;
+    use std::sync::Mutex;
+
+    /// 受け取ったイベントを記録するハンドラ
+    #[derive(Default)]
+    struct RecordingHandler {
+        received: Mutex<Vec<DomainEvent>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventHandler for RecordingHandler {
+        async fn handle(&self, event: &DomainEvent) -> Result<(), ApplicationError> {
+            self.received.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    struct FailingHandler;
+
+    #[async_trait::async_trait]
+    impl EventHandler for FailingHandler {
+        async fn handle(&self, _event: &DomainEvent) -> Result<(), ApplicationError> {
+            Err(ApplicationError::SubscribeError("handler failed".to_string()))
+        }
+    }
+
+    fn created_event() -> DomainEvent {
+        DomainEvent::new(UserId::new(), DomainEventKind::SubscribeCreated { subscribe_id: SubscribeId::new() })
+    }
+
+    #[tokio::test]
+    async fn test_publish_delivers_events_to_all_handlers() {
+        let dispatcher = EventDispatcher::new();
+        let first = Arc::new(RecordingHandler::default());
+        let second = Arc::new(RecordingHandler::default());
+        dispatcher.subscribe(first.clone());
+        dispatcher.subscribe(Arc::new(FailingHandler));
+        dispatcher.subscribe(second.clone());
+        let events = vec![
+            created_event(),
+            created_event(),
+        ];
+
+        dispatcher.publish(events.clone()).await;
+
+        assert_eq!(*first.received.lock().unwrap(), events);
+        assert_eq!(*second.received.lock().unwrap(), events);
+    }
+
+    #[tokio::test]
+    async fn test_publish_without_handlers() {
+        let dispatcher = EventDispatcher::new();
+
+        dispatcher.publish(vec![created_event()]).await;
+    }
+}
@@ -1,3 +1,4 @@
 pub mod dtos;
 pub mod error;
+pub mod event;
 pub mod service;
@@ -2,14 +2,22 @@ use crate::dtos;
 use crate::dtos::payment_method_dto::PaymentMethodDTO;
 use crate::error::ApplicationError;
 
+pub mod budget_service;
 pub mod category_service;
+pub mod dashboard_service;
+pub mod ledger_service;
 pub mod payment_method_service;
 pub mod subscribe_service;
+pub mod user_service;
 
 #[async_trait::async_trait]
 pub trait PaymentMethodService: Send + Sync {
     async fn create_payment_method(&self, payment: PaymentMethodDTO) -> Result<(), ApplicationError>;
-    async fn find_payment_method_all(&self, user_id: &str) -> Result<Vec<PaymentMethodDTO>, ApplicationError>;
+    async fn find_payment_method_all(
+        &self,
+        user_id: &str,
+        order: Option<dtos::list_order::ListOrder>,
+    ) -> Result<Vec<PaymentMethodDTO>, ApplicationError>;
     async fn find_payment_method_by_id(
         &self,
         payment_id: &str,
@@ -17,6 +25,150 @@ pub trait PaymentMethodService: Send + Sync {
     ) -> Result<PaymentMethodDTO, ApplicationError>;
     async fn update_payment_method(&self, payment: PaymentMethodDTO) -> Result<(), ApplicationError>;
     async fn delete_payment_method(&self, payment_id: &str, user_id: &str) -> Result<(), ApplicationError>;
+    async fn find_deleted_payment_methods(&self, user_id: &str) -> Result<Vec<PaymentMethodDTO>, ApplicationError>;
+    async fn restore_payment_method(&self, payment_id: &str, user_id: &str) -> Result<(), ApplicationError>;
+    async fn purge_deleted_payment_methods(&self, retention_days: u32) -> Result<usize, ApplicationError>;
+}
+
+#[async_trait::async_trait]
+pub trait LedgerService: Send + Sync {
+    async fn confirm_payment(
+        &self,
+        payment: dtos::ledger_dto::ConfirmPaymentDto,
+    ) -> Result<dtos::ledger_dto::PaymentLedgerDto, ApplicationError>;
+    async fn dispute_payment(
+        &self,
+        payment: dtos::ledger_dto::DisputePaymentDto,
+    ) -> Result<dtos::ledger_dto::PaymentLedgerDto, ApplicationError>;
+    async fn find_payments(
+        &self,
+        user_id: &str,
+        subscribe_id: Option<&str>,
+    ) -> Result<Vec<dtos::ledger_dto::PaymentLedgerDto>, ApplicationError>;
+}
+
+#[async_trait::async_trait]
+pub trait BudgetService: Send + Sync {
+    async fn create_budget(&self, budget: dtos::budget_dto::BudgetDto) -> Result<(), ApplicationError>;
+    async fn find_budget_all(&self, user_id: &str) -> Result<Vec<dtos::budget_dto::BudgetDto>, ApplicationError>;
+    async fn find_budget_by_id(
+        &self,
+        budget_id: &str,
+        user_id: &str,
+    ) -> Result<dtos::budget_dto::BudgetDto, ApplicationError>;
+    async fn update_budget(&self, budget: dtos::budget_dto::BudgetDto) -> Result<(), ApplicationError>;
+    async fn delete_budget(&self, budget_id: &str, user_id: &str) -> Result<(), ApplicationError>;
+
+    /// ユーザーの全ての予算について、ACTIVEなサブスクの月額換算の負担額と比較した状況を取得する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    ///
+    /// # 戻り値
+    /// - Vec<[dtos::budget_dto::BudgetEvaluationDto]> 予算ごとの評価結果
+    async fn evaluate_budgets(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<dtos::budget_dto::BudgetEvaluationDto>, ApplicationError>;
+}
+
+#[async_trait::async_trait]
+pub trait DashboardService: Send + Sync {
+    /// ダッシュボードに表示する支出の合計、ステータスごとの件数、直近の支払予定を集計する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    ///
+    /// # 戻り値
+    /// - [dtos::dashboard_dto::DashboardSummaryDto] 集計結果
+    async fn find_summary(&self, user_id: &str) -> Result<dtos::dashboard_dto::DashboardSummaryDto, ApplicationError>;
+
+    /// ACTIVEなサブスクの支出をカテゴリごとに集計する
+    ///
+    /// カテゴリが削除されたサブスクは「未分類」にまとめる
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    ///
+    /// # 戻り値
+    /// - Vec<[dtos::dashboard_dto::CategoryBreakdownDto]> 通貨コード順の内訳
+    async fn find_category_breakdown(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<dtos::dashboard_dto::CategoryBreakdownDto>, ApplicationError>;
+
+    /// 指定した期間の月ごとの支出と、前月からの変動の要因を集計する
+    ///
+    /// サブスクの初回支払日・支払周期・ステータス・金額の変更履歴から、各月末時点で課金されていたサブスクの月額換算の負担額を再構成する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    /// * `from` - 期間の開始月 (YYYY-MM)
+    /// * `to` - 期間の終了月 (YYYY-MM)
+    ///
+    /// # 戻り値
+    /// - Vec<[dtos::dashboard_dto::SpendVarianceDto]> 通貨コード順の月次の推移
+    ///
+    /// # エラー
+    /// - [ApplicationError::DashboardError] 月の形式が不正な場合、開始月が終了月より後の場合、期間が長すぎる場合
+    async fn find_monthly_variance(
+        &self,
+        user_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<dtos::dashboard_dto::SpendVarianceDto>, ApplicationError>;
+
+    /// 当月から12か月分の請求を、月ごとに予測する
+    ///
+    /// 年払いなどのサブスクは月額に換算せず、支払予定日の月に請求額をそのまま計上する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    ///
+    /// # 戻り値
+    /// - [dtos::dashboard_dto::SpendForecastDto] 月ごとの請求と合計
+    async fn find_forecast(&self, user_id: &str) -> Result<dtos::dashboard_dto::SpendForecastDto, ApplicationError>;
+
+    /// 指定期間のACTIVEなサブスクの請求を、支払周期から展開して日ごとにまとめる
+    ///
+    /// 次回支払予定日より前の支払済みの請求は含めない
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    /// * `from` - 期間の開始日 (YYYY-MM-DD)
+    /// * `to` - 期間の終了日 (YYYY-MM-DD)
+    ///
+    /// # 戻り値
+    /// - [dtos::dashboard_dto::PaymentCalendarDto] 日ごとの請求と合計
+    ///
+    /// # エラー
+    /// - [ApplicationError::DashboardError] 日付の形式が不正な場合、開始日が終了日より後の場合、期間が長すぎる場合
+    async fn find_payment_calendar(
+        &self,
+        user_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<dtos::dashboard_dto::PaymentCalendarDto, ApplicationError>;
+}
+
+#[async_trait::async_trait]
+pub trait UserService: Send + Sync {
+    /// ユーザーを作成する
+    ///
+    /// # 引数
+    /// * `user` - [dtos::user_dto::UserDto] 作成するユーザー。ユーザーIDは空文字
+    ///
+    /// # 戻り値
+    /// - [dtos::user_dto::UserDto] 採番したユーザーIDを含む作成したユーザー
+    async fn create_user(&self, user: dtos::user_dto::UserDto) -> Result<dtos::user_dto::UserDto, ApplicationError>;
+    async fn find_user(&self, user_id: &str) -> Result<dtos::user_dto::UserDto, ApplicationError>;
+
+    /// ユーザーのプロフィール、国、設定を更新する
+    ///
+    /// # 引数
+    /// * `user` - [dtos::user_dto::UserDto] 更新後のユーザー
+    async fn update_user(&self, user: dtos::user_dto::UserDto) -> Result<(), ApplicationError>;
+    async fn delete_user(&self, user_id: &str) -> Result<(), ApplicationError>;
 }
 
 pub trait SubscribeService: Send + Sync {
@@ -28,6 +180,8 @@ pub trait SubscribeService: Send + Sync {
     fn find_subscribe_all<'a>(
         &'a self,
         user_id: &'a str,
+        tag: Option<&'a str>,
+        order: Option<dtos::list_order::ListOrder>,
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
@@ -58,6 +212,152 @@ pub trait SubscribeService: Send + Sync {
         user_id: &'a str,
         subscribe_id: &'a str,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn pause_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn resume_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn cancel_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn reactivate_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn roll_forward_payment_date<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
+
+    fn roll_forward_payment_date_all(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
+
+    fn find_trials_ending_within<'a>(
+        &'a self,
+        user_id: &'a str,
+        days: u32,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    >;
+
+    fn convert_expired_trials_all(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
+
+    fn find_cancellation_windows_closing<'a>(
+        &'a self,
+        user_id: &'a str,
+        days: u32,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    >;
+
+    fn find_price_history<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<crate::dtos::price_change_dto::PriceChangeDto>, ApplicationError>,
+                > + Send
+                + '_,
+        >,
+    >;
+
+    fn find_recent_price_increases<'a>(
+        &'a self,
+        user_id: &'a str,
+        days: u32,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<crate::dtos::price_change_dto::PriceIncreaseDto>, ApplicationError>,
+                > + Send
+                + '_,
+        >,
+    >;
+
+    fn find_cost_split<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<crate::dtos::cost_split_dto::CostSplitDto, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    >;
+
+    fn add_tag<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+        tag: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn remove_tag<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+        tag: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn find_tag_counts<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::tag_dto::TagCountDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    >;
+
+    fn find_deleted_subscribes<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    >;
+
+    fn restore_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn purge_deleted_subscribes(
+        &self,
+        retention_days: u32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
 }
 
 pub trait CategoryService: Send + Sync {
@@ -69,6 +369,7 @@ pub trait CategoryService: Send + Sync {
     fn find_category_all<'a>(
         &'a self,
         user_id: &'a str,
+        order: Option<dtos::list_order::ListOrder>,
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<Output = Result<Vec<dtos::category_dto::CategoryDto>, ApplicationError>>
@@ -95,4 +396,72 @@ pub trait CategoryService: Send + Sync {
         user_id: &'a str,
         category_id: &'a str,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    /// カテゴリのサブスク(ゴミ箱にあるものを含む)を別のカテゴリに付け替え、カテゴリの削除と同じトランザクションで保存する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    /// * `category_id` - 削除するカテゴリID
+    /// * `target_category_id` - 付け替え先のカテゴリID。[None]の場合は「未分類」カテゴリ(なければ作成する)
+    ///
+    /// # 戻り値
+    /// - [usize] 付け替えたサブスクの件数
+    fn delete_category_with_reassignment<'a>(
+        &'a self,
+        user_id: &'a str,
+        category_id: &'a str,
+        target_category_id: Option<&'a str>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
+
+    /// カテゴリを指定した順に並び替える
+    ///
+    /// # 引数
+    /// * `order` - ユーザーの全カテゴリのIDを表示順に並べたリスト
+    ///
+    /// # エラー
+    /// - [ApplicationError::CategoryError] 過不足や重複がある場合
+    fn reorder_categories(
+        &self,
+        order: dtos::category_dto::CategoryOrderDto,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    /// ユーザーの国に応じた既定のカテゴリを作成する
+    ///
+    /// 同じ名前のカテゴリが既にある場合は作成しない
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    ///
+    /// # 戻り値
+    /// - [usize] 作成したカテゴリの件数
+    ///
+    /// # エラー
+    /// - [ApplicationError::UserError] ユーザーが存在しない場合
+    /// - [ApplicationError::CategoryError] ユーザーの国に既定のカテゴリがない場合
+    fn seed_default_categories<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
+
+    fn find_deleted_categories<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<dtos::category_dto::CategoryDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    >;
+
+    fn restore_category<'a>(
+        &'a self,
+        user_id: &'a str,
+        category_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>>;
+
+    fn purge_deleted_categories(
+        &self,
+        retention_days: u32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>>;
 }
@@ -0,0 +1,378 @@
+use crate::dtos::budget_dto::{BudgetDto, BudgetEvaluationDto};
+use crate::dtos::DTO;
+use crate::error::ApplicationError;
+use crate::event::{EventEmitter, PublishEvents};
+use crate::service::BudgetService;
+use chrono::Utc;
+use domain::budget::budget_id::BudgetId;
+use domain::budget::Budget;
+use domain::event::DomainEventKind;
+use domain::repository::budget_repository::BudgetRepository;
+use domain::repository::category_repository::CategoryRepository;
+use domain::repository::subscribe_repository::SubscribeRepository;
+use domain::user::user_id::UserId;
+use std::str::FromStr;
+
+pub struct BudgetServiceImpl<B: BudgetRepository, S: SubscribeRepository, C: CategoryRepository> {
+    repository: B,
+    subscribe_repository: S,
+    category_repository: C,
+    events: EventEmitter,
+}
+
+impl<B: BudgetRepository, S: SubscribeRepository, C: CategoryRepository> PublishEvents for BudgetServiceImpl<B, S, C> {
+    fn emitter_mut(&mut self) -> &mut EventEmitter {
+        &mut self.events
+    }
+}
+
+impl<B: BudgetRepository, S: SubscribeRepository, C: CategoryRepository> BudgetServiceImpl<B, S, C> {
+    pub fn new(repository: B, subscribe_repository: S, category_repository: C) -> BudgetServiceImpl<B, S, C> {
+        Self { repository, subscribe_repository, category_repository, events: EventEmitter::default() }
+    }
+
+    /// 予算の対象のカテゴリが存在し、同じ対象の予算が他にないか検証する
+    ///
+    /// # 引数
+    /// * `budget` - 検証する予算
+    ///
+    /// # エラー
+    /// - [ApplicationError::CategoryError] カテゴリが存在しない場合
+    /// - [ApplicationError::Conflict] 同じ対象の予算が既にある場合
+    async fn verify_scope(&self, budget: &Budget) -> Result<(), ApplicationError> {
+        if let Some(category_id) = budget.category_id() {
+            self.category_repository.find_by_id(category_id, budget.user_id()).await?;
+        }
+
+        let budgets = self.repository.find_all(budget.user_id()).await?;
+        let duplicated = budgets.iter().any(|b| b.budget_id() != budget.budget_id() && b.has_same_scope(budget));
+        if duplicated {
+            let scope = match budget.category_id() {
+                Some(c) => format!("category {}", c),
+                None => "overall".to_string(),
+            };
+            return Err(ApplicationError::Conflict(format!("budget for {} already exists", scope)));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<B: BudgetRepository, S: SubscribeRepository, C: CategoryRepository> BudgetService for BudgetServiceImpl<B, S, C> {
+    async fn create_budget(&self, budget: BudgetDto) -> Result<(), ApplicationError> {
+        let mut budget = BudgetDto::map_to_domain_model(budget)?;
+        budget.mark_created(Utc::now());
+        self.verify_scope(&budget).await?;
+
+        self.repository.create(&budget).await?;
+        self.events
+            .publish(budget.user_id(), DomainEventKind::BudgetCreated { budget_id: budget.budget_id().clone() })
+            .await;
+        Ok(())
+    }
+
+    async fn find_budget_all(&self, user_id: &str) -> Result<Vec<BudgetDto>, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let mut budgets = self.repository.find_all(&user_id).await?;
+        // 全体の予算を先頭に、カテゴリの予算は登録順に並べる
+        budgets.sort_by_key(|b| (!b.is_overall(), *b.created_at()));
+
+        Ok(budgets.iter().map(BudgetDto::map_to_dto).collect())
+    }
+
+    async fn find_budget_by_id(&self, budget_id: &str, user_id: &str) -> Result<BudgetDto, ApplicationError> {
+        let budget_id = BudgetId::from_str(budget_id)?;
+        let user_id = UserId::from_str(user_id)?;
+        let budget = self.repository.find_by_id(&budget_id, &user_id).await?;
+
+        Ok(BudgetDto::map_to_dto(&budget))
+    }
+
+    async fn update_budget(&self, budget: BudgetDto) -> Result<(), ApplicationError> {
+        let mut budget = BudgetDto::map_to_domain_model(budget)?;
+        budget.mark_updated(Utc::now());
+        self.verify_scope(&budget).await?;
+
+        self.repository.update(&budget).await?;
+        self.events
+            .publish(budget.user_id(), DomainEventKind::BudgetUpdated { budget_id: budget.budget_id().clone() })
+            .await;
+        Ok(())
+    }
+
+    async fn delete_budget(&self, budget_id: &str, user_id: &str) -> Result<(), ApplicationError> {
+        let budget_id = BudgetId::from_str(budget_id)?;
+        let user_id = UserId::from_str(user_id)?;
+
+        self.repository.delete(&budget_id, &user_id).await?;
+        self.events.publish(&user_id, DomainEventKind::BudgetDeleted { budget_id }).await;
+        Ok(())
+    }
+
+    async fn evaluate_budgets(&self, user_id: &str) -> Result<Vec<BudgetEvaluationDto>, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let mut budgets = self.repository.find_all(&user_id).await?;
+        budgets.sort_by_key(|b| (!b.is_overall(), *b.created_at()));
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+
+        let evaluations = budgets
+            .iter()
+            .map(|budget| {
+                let spent = budget.monthly_spending(&subscribes);
+                let excluded =
+                    subscribes.iter().filter(|s| budget.covers(s) && s.currency() != budget.limit().currency()).count();
+                BudgetEvaluationDto::new(budget, spent, excluded)
+            })
+            .collect();
+        Ok(evaluations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+    use domain::budget::budget_error::BudgetError;
+    use domain::category::category_error::CategoryError;
+    use domain::category::category_id::CategoryId;
+    use domain::category::category_name::CategoryName;
+    use domain::category::Category;
+    use domain::payment::payment_method_id::PaymentMethodId;
+    use domain::payment_cycle::PaymentCycle;
+    use domain::subscribe::contract_term::ContractTerm;
+    use domain::subscribe::cost_split::CostSplit;
+    use domain::subscribe::subscribe_error::SubscribeError;
+    use domain::subscribe::subscribe_id::SubscribeId;
+    use domain::subscribe::subscribe_name::SubscribeName;
+    use domain::subscribe::subscribe_status::SubscribeStatus;
+    use domain::subscribe::tag::TagSet;
+    use domain::subscribe::Subscribe;
+    use domain::value_object::amount::Amount;
+    use domain::value_object::currency::Currency;
+    use domain::value_object::money::Money;
+    use mockall::mock;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    mock! {
+        BudgetRepository {}
+        #[async_trait::async_trait]
+        impl BudgetRepository for BudgetRepository {
+            async fn create(&self, budget: &Budget) -> Result<(), BudgetError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<Budget>, BudgetError>;
+            async fn find_by_id(&self, budget_id: &BudgetId, user_id: &UserId) -> Result<Budget, BudgetError>;
+            async fn update(&self, budget: &Budget) -> Result<(), BudgetError>;
+            async fn delete(&self, budget_id: &BudgetId, user_id: &UserId) -> Result<(), BudgetError>;
+        }
+    }
+
+    mock! {
+        SubscribeRepository {}
+        #[async_trait::async_trait]
+        impl SubscribeRepository for SubscribeRepository {
+            async fn create(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_category(&self, category_id: &CategoryId, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
+            async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        }
+    }
+
+    mock! {
+        CategoryRepository {}
+        impl CategoryRepository for CategoryRepository {
+            fn create<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_all<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_by_id<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Category, CategoryError>> + Send + 'static>>;
+            fn update<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn reorder<'a>(&'a self, categories: &'a [Category]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete_with_reassignment<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId, subscribes: &'a [Subscribe]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn restore<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_deleted<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_deleted_before<'a>(&'a self, before: &'a DateTime<Utc>) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn purge<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+        }
+    }
+
+    type TestService = BudgetServiceImpl<MockBudgetRepository, MockSubscribeRepository, MockCategoryRepository>;
+
+    fn jpy(value: &str) -> Money {
+        Money::new(Amount::from_str(value).unwrap(), Currency::JPY).unwrap()
+    }
+
+    fn create_subscribe(user_id: &UserId, category_id: &CategoryId, amount: Money) -> Subscribe {
+        let now = Utc::now();
+        Subscribe::new(
+            user_id.clone(),
+            SubscribeName::new("Netflix").unwrap(),
+            PaymentMethodId::new(),
+            amount,
+            PaymentCycle::Monthly,
+            category_id.clone(),
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now,
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
+    fn create_service(
+        repository: MockBudgetRepository,
+        subscribe_repository: MockSubscribeRepository,
+        category_exists: bool,
+    ) -> TestService {
+        let mut category_repository = MockCategoryRepository::new();
+        category_repository.expect_find_by_id().returning(move |c, u| {
+            let category = Category::from(
+                c.clone(),
+                u.clone(),
+                CategoryName::new("動画配信").unwrap(),
+                None,
+                None,
+                0,
+                Utc::now(),
+                None,
+                None,
+            );
+            Box::pin(async move {
+                match category_exists {
+                    true => Ok(category),
+                    false => Err(CategoryError::NotExist),
+                }
+            })
+        });
+        BudgetServiceImpl::new(repository, subscribe_repository, category_repository)
+    }
+
+    #[tokio::test]
+    async fn test_create_budget_success() {
+        let mut repository = MockBudgetRepository::new();
+        repository.expect_find_all().returning(|_| Ok(vec![]));
+        repository.expect_create().return_once(|_| Ok(())).times(1);
+        let service = create_service(repository, MockSubscribeRepository::new(), true);
+        let dto = BudgetDto::new(
+            String::new(),
+            UserId::new().to_string(),
+            Some(CategoryId::new().to_string()),
+            "5000".to_string(),
+            "JPY".to_string(),
+        );
+
+        let result = service.create_budget(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_budget_failed_when_category_not_exist() {
+        let mut repository = MockBudgetRepository::new();
+        repository.expect_create().never();
+        let service = create_service(repository, MockSubscribeRepository::new(), false);
+        let dto = BudgetDto::new(
+            String::new(),
+            UserId::new().to_string(),
+            Some(CategoryId::new().to_string()),
+            "5000".to_string(),
+            "JPY".to_string(),
+        );
+
+        let result = service.create_budget(dto).await;
+
+        assert!(matches!(result, Err(ApplicationError::CategoryError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_budget_conflict_when_same_scope_exists() {
+        let user_id = UserId::new();
+        let existing = Budget::new(user_id.clone(), None, jpy("10000"));
+        let mut repository = MockBudgetRepository::new();
+        repository.expect_find_all().return_once(move |_| Ok(vec![existing]));
+        repository.expect_create().never();
+        let service = create_service(repository, MockSubscribeRepository::new(), true);
+        let dto = BudgetDto::new(String::new(), user_id.to_string(), None, "5000".to_string(), "JPY".to_string());
+
+        let result = service.create_budget(dto).await;
+
+        assert_eq!(result, Err(ApplicationError::Conflict("budget for overall already exists".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_update_budget_keeps_own_scope() {
+        let user_id = UserId::new();
+        let existing = Budget::new(user_id.clone(), None, jpy("10000"));
+        let dto = BudgetDto::new(
+            existing.budget_id().to_string(),
+            user_id.to_string(),
+            None,
+            "8000".to_string(),
+            "JPY".to_string(),
+        );
+        let mut repository = MockBudgetRepository::new();
+        repository.expect_find_all().return_once(move |_| Ok(vec![existing]));
+        repository
+            .expect_update()
+            .withf(|b| b.limit() == &jpy("8000") && b.updated_at().is_some())
+            .return_once(|_| Ok(()))
+            .times(1);
+        let service = create_service(repository, MockSubscribeRepository::new(), true);
+
+        let result = service.update_budget(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_budgets() {
+        let user_id = UserId::new();
+        let streaming = CategoryId::new();
+        let music = CategoryId::new();
+        let budgets = vec![
+            Budget::new(user_id.clone(), Some(streaming.clone()), jpy("5000")),
+            Budget::new(user_id.clone(), Some(music.clone()), jpy("1000")),
+            Budget::new(user_id.clone(), None, jpy("10000")),
+        ];
+        let subscribes = vec![
+            create_subscribe(&user_id, &streaming, jpy("1980")),
+            create_subscribe(&user_id, &streaming, jpy("2200")),
+            create_subscribe(&user_id, &music, jpy("1080")),
+            create_subscribe(
+                &user_id,
+                &streaming,
+                Money::new(Amount::from_str("9.99").unwrap(), Currency::USD).unwrap(),
+            ),
+        ];
+        let mut repository = MockBudgetRepository::new();
+        repository.expect_find_all().return_once(move |_| Ok(budgets));
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        subscribe_repository.expect_find_all().return_once(move |_| Ok(subscribes));
+        let service = create_service(repository, subscribe_repository, true);
+
+        let result = service.evaluate_budgets(&user_id.to_string()).await.unwrap();
+
+        assert_eq!(result.len(), 3);
+        // 全体の予算が先頭
+        assert_eq!(result[0].spent(), "5260");
+        assert_eq!(result[0].status(), "UNDER");
+        assert_eq!(result[0].excluded_count(), 1);
+        assert_eq!(result[1].spent(), "4180");
+        assert_eq!(result[1].usage_percent(), "83.6");
+        assert_eq!(result[1].status(), "NEAR");
+        assert_eq!(result[2].remaining(), "-80");
+        assert_eq!(result[2].status(), "OVER");
+        assert_eq!(result[2].excluded_count(), 0);
+    }
+}
@@ -1,40 +1,99 @@
 use std::str::FromStr;
 
+use chrono::Utc;
 use domain::{
-    category::category_id::CategoryId, repository::category_repository::CategoryRepository, user::user_id::UserId,
+    category::{
+        category_appearance::{CategoryColor, CategoryIcon},
+        category_error::CategoryError,
+        category_id::CategoryId,
+        category_name::CategoryName,
+        default_category::DefaultCategory,
+        Category,
+    },
+    event::DomainEventKind,
+    repository::{
+        category_repository::CategoryRepository, subscribe_repository::SubscribeRepository,
+        user_repository::UserRepository,
+    },
+    user::user_id::UserId,
 };
 
-use crate::dtos::{category_dto::CategoryDto, DTO};
+use crate::dtos::{
+    category_dto::{CategoryDto, CategoryOrderDto},
+    list_order::ListOrder,
+    DTO,
+};
+use crate::event::{EventEmitter, PublishEvents};
 
 use super::CategoryService;
 
-pub struct CategoryServiceImpl<T: CategoryRepository> {
+pub struct CategoryServiceImpl<T: CategoryRepository, S: SubscribeRepository, U: UserRepository> {
     repository: T,
+    subscribe_repository: S,
+    user_repository: U,
+    events: EventEmitter,
 }
 
-impl<T: CategoryRepository> CategoryServiceImpl<T> {
-    pub fn new(repository: T) -> CategoryServiceImpl<T> {
-        Self { repository }
+impl<T: CategoryRepository, S: SubscribeRepository, U: UserRepository> PublishEvents for CategoryServiceImpl<T, S, U> {
+    fn emitter_mut(&mut self) -> &mut EventEmitter {
+        &mut self.events
     }
 }
 
-impl<T: CategoryRepository> CategoryService for CategoryServiceImpl<T> {
+impl<T: CategoryRepository, S: SubscribeRepository, U: UserRepository> CategoryServiceImpl<T, S, U> {
+    pub fn new(repository: T, subscribe_repository: S, user_repository: U) -> CategoryServiceImpl<T, S, U> {
+        Self { repository, subscribe_repository, user_repository, events: EventEmitter::default() }
+    }
+
+    /// 新しいカテゴリを末尾に追加するための並び順を求める
+    ///
+    /// # 引数
+    /// * `categories` - ユーザーの既存のカテゴリ
+    fn next_sort_order(categories: &[Category]) -> u32 {
+        categories.iter().map(|c| c.sort_order() + 1).max().unwrap_or(0)
+    }
+
+    /// ユーザーの「未分類」カテゴリを取得する。存在しない場合は末尾に作成する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] ユーザーID
+    async fn find_or_create_uncategorized(&self, user_id: &UserId) -> Result<Category, CategoryError> {
+        let categories = self.repository.find_all(user_id).await?;
+        if let Some(category) = categories.iter().find(|c| c.is_uncategorized()) {
+            return Ok(category.clone());
+        }
+        let mut category = Category::uncategorized(user_id.clone());
+        category.move_to(Self::next_sort_order(&categories));
+        category.mark_created(Utc::now());
+        self.repository.create(&category).await?;
+        Ok(category)
+    }
+}
+
+impl<T: CategoryRepository, S: SubscribeRepository, U: UserRepository> CategoryService
+    for CategoryServiceImpl<T, S, U>
+{
     fn create_category<'a>(
         &'a self,
         category: crate::dtos::category_dto::CategoryDto,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), crate::error::ApplicationError>> + Send + '_>>
     {
-        let result = Box::pin(async move {
-            let category = CategoryDto::map_to_domain_model(category)?;
-            let result = self.repository.create(&category).await?;
-            Ok(result)
-        });
-        result
+        Box::pin(async move {
+            let mut category = CategoryDto::map_to_domain_model(category)?;
+            let categories = self.repository.find_all(category.user_id()).await?;
+            category.move_to(Self::next_sort_order(&categories));
+            category.mark_created(Utc::now());
+            self.repository.create(&category).await?;
+            let kind = DomainEventKind::CategoryCreated { category_id: category.category_id().clone() };
+            self.events.publish(category.user_id(), kind).await;
+            Ok(())
+        })
     }
 
     fn find_category_all<'a>(
         &'a self,
         user_id: &'a str,
+        order: Option<ListOrder>,
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<
@@ -43,13 +102,15 @@ impl<T: CategoryRepository> CategoryService for CategoryServiceImpl<T> {
                 + '_,
         >,
     > {
-        let result = Box::pin(async move {
+        Box::pin(async move {
             let user_id = UserId::from_str(user_id)?;
-            let v = self.repository.find_all(&user_id).await?;
+            let mut v = self.repository.find_all(&user_id).await?;
+            if let Some(order) = order {
+                order.sort(&mut v, |c| (*c.created_at(), *c.updated_at()));
+            }
             let result = v.into_iter().map(|item| CategoryDto::map_to_dto(&item)).collect();
             Ok(result)
-        });
-        result
+        })
     }
 
     fn find_category_by_id<'a>(
@@ -64,14 +125,13 @@ impl<T: CategoryRepository> CategoryService for CategoryServiceImpl<T> {
                 + '_,
         >,
     > {
-        let result = Box::pin(async move {
+        Box::pin(async move {
             let user_id = UserId::from_str(user_id)?;
             let category_id = CategoryId::from_str(category_id)?;
             let v = self.repository.find_by_id(&category_id, &user_id).await?;
             let result = CategoryDto::map_to_dto(&v);
             Ok(result)
-        });
-        result
+        })
     }
 
     fn update_category<'a>(
@@ -79,12 +139,14 @@ impl<T: CategoryRepository> CategoryService for CategoryServiceImpl<T> {
         category: crate::dtos::category_dto::CategoryDto,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), crate::error::ApplicationError>> + Send + '_>>
     {
-        let result = Box::pin(async move {
-            let category = CategoryDto::map_to_domain_model(category)?;
-            let result = self.repository.update(&category).await?;
-            Ok(result)
-        });
-        result
+        Box::pin(async move {
+            let mut category = CategoryDto::map_to_domain_model(category)?;
+            category.mark_updated(Utc::now());
+            self.repository.update(&category).await?;
+            let kind = DomainEventKind::CategoryUpdated { category_id: category.category_id().clone() };
+            self.events.publish(category.user_id(), kind).await;
+            Ok(())
+        })
     }
 
     fn delete_category<'a>(
@@ -93,12 +155,622 @@ impl<T: CategoryRepository> CategoryService for CategoryServiceImpl<T> {
         category_id: &'a str,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), crate::error::ApplicationError>> + Send + '_>>
     {
-        let result = Box::pin(async move {
+        Box::pin(async move {
+            let user_id = UserId::from_str(user_id)?;
+            let category_id = CategoryId::from_str(category_id)?;
+
+            // サブスクから参照されているカテゴリは削除しない
+            let in_use = self.subscribe_repository.find_by_category(&category_id, &user_id).await?.len();
+            if in_use > 0 {
+                return Err(crate::error::ApplicationError::Conflict(format!(
+                    "category {} is used by {} subscriptions",
+                    category_id, in_use
+                )));
+            }
+
+            self.repository.delete(&category_id, &user_id).await?;
+            self.events.publish(&user_id, DomainEventKind::CategoryDeleted { category_id, reassigned: 0 }).await;
+            Ok(())
+        })
+    }
+
+    fn delete_category_with_reassignment<'a>(
+        &'a self,
+        user_id: &'a str,
+        category_id: &'a str,
+        target_category_id: Option<&'a str>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, crate::error::ApplicationError>> + Send + '_>>
+    {
+        Box::pin(async move {
             let user_id = UserId::from_str(user_id)?;
             let category_id = CategoryId::from_str(category_id)?;
-            let result = self.repository.delete(&category_id, &user_id).await?;
+            self.repository.find_by_id(&category_id, &user_id).await?;
+
+            let target = match target_category_id {
+                Some(id) => {
+                    let target_id = CategoryId::from_str(id)?;
+                    self.repository.find_by_id(&target_id, &user_id).await?
+                }
+                None => self.find_or_create_uncategorized(&user_id).await?,
+            };
+            if target.category_id() == &category_id {
+                return Err(CategoryError::SameReassignTarget.into());
+            }
+
+            // ゴミ箱にあるサブスクも、復元時に削除済みのカテゴリを参照しないよう付け替える
+            let mut subscribes = self.subscribe_repository.find_by_category(&category_id, &user_id).await?;
+            let deleted = self.subscribe_repository.find_deleted(&user_id).await?;
+            subscribes.extend(deleted.into_iter().filter(|s| s.category_id() == &category_id));
+
+            let now = Utc::now();
+            let mut reassigned = Vec::with_capacity(subscribes.len());
+            for mut subscribe in subscribes {
+                if subscribe.reassign_category(target.category_id().clone()) {
+                    subscribe.mark_updated(now);
+                    reassigned.push(subscribe);
+                }
+            }
+            let moved = reassigned.len();
+
+            self.repository.delete_with_reassignment(&category_id, &user_id, &reassigned).await?;
+            self.events.publish(&user_id, DomainEventKind::CategoryDeleted { category_id, reassigned: moved }).await;
+            Ok(moved)
+        })
+    }
+
+    fn reorder_categories(
+        &self,
+        order: CategoryOrderDto,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), crate::error::ApplicationError>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let user_id = UserId::from_str(order.user_id())?;
+            let category_ids =
+                order.category_ids().iter().map(|id| CategoryId::from_str(id)).collect::<Result<Vec<_>, _>>()?;
+
+            let mut categories = self.repository.find_all(&user_id).await?;
+            let unique = category_ids.iter().collect::<std::collections::HashSet<_>>();
+            if unique.len() != category_ids.len() {
+                return Err(CategoryError::InvalidOrder("duplicate category ids".to_string()).into());
+            }
+            if category_ids.len() != categories.len() || categories.iter().any(|c| !unique.contains(c.category_id())) {
+                return Err(
+                    CategoryError::InvalidOrder("all categories must be listed exactly once".to_string()).into()
+                );
+            }
+
+            let now = Utc::now();
+            let mut changed = vec![];
+            for category in categories.iter_mut() {
+                let position = category_ids.iter().position(|id| id == category.category_id()).unwrap_or_default();
+                if category.move_to(position as u32) {
+                    category.mark_updated(now);
+                    changed.push(category.clone());
+                }
+            }
+            self.repository.reorder(&changed).await?;
+            Ok(())
+        })
+    }
+
+    fn seed_default_categories<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, crate::error::ApplicationError>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let user_id = UserId::from_str(user_id)?;
+            let user = self.user_repository.find_by_id(&user_id).await?;
+            let country = user.country().ok_or(CategoryError::UnsupportedCountry(user.country_id()))?;
+
+            let existing = self.repository.find_all(&user_id).await?;
+            let mut sort_order = Self::next_sort_order(&existing);
+            let now = Utc::now();
+            let mut created = 0;
+            for default in DefaultCategory::for_country(country) {
+                let name = CategoryName::from_str(default.name()).map_err(CategoryError::from)?;
+                if existing.iter().any(|c| c.category_name() == &name) {
+                    continue;
+                }
+                let mut category = Category::new(user_id.clone(), name);
+                category.change_appearance(
+                    Some(CategoryColor::from_str(default.color()).map_err(CategoryError::from)?),
+                    Some(CategoryIcon::from_str(default.icon()).map_err(CategoryError::from)?),
+                );
+                category.move_to(sort_order);
+                category.mark_created(now);
+                self.repository.create(&category).await?;
+                sort_order += 1;
+                created += 1;
+            }
+            Ok(created)
+        })
+    }
+
+    fn find_deleted_categories<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<crate::dtos::category_dto::CategoryDto>, crate::error::ApplicationError>,
+                > + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let user_id = UserId::from_str(user_id)?;
+            let v = self.repository.find_deleted(&user_id).await?;
+            let result = v.into_iter().map(|item| CategoryDto::map_to_dto(&item)).collect();
             Ok(result)
+        })
+    }
+
+    fn restore_category<'a>(
+        &'a self,
+        user_id: &'a str,
+        category_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), crate::error::ApplicationError>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let user_id = UserId::from_str(user_id)?;
+            let category_id = CategoryId::from_str(category_id)?;
+            self.repository.restore(&category_id, &user_id).await?;
+            self.events.publish(&user_id, DomainEventKind::CategoryRestored { category_id }).await;
+            Ok(())
+        })
+    }
+
+    fn purge_deleted_categories(
+        &self,
+        retention_days: u32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, crate::error::ApplicationError>> + Send + '_>>
+    {
+        Box::pin(async move {
+            let before = Utc::now() - chrono::Duration::days(i64::from(retention_days));
+            let mut count = 0;
+            for category in self.repository.find_deleted_before(&before).await? {
+                self.repository.purge(category.category_id(), category.user_id()).await?;
+                count += 1;
+            }
+            Ok(count)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ApplicationError;
+    use chrono::DateTime;
+    use domain::category::category_name::CategoryName;
+    use domain::payment::payment_method_id::PaymentMethodId;
+    use domain::payment_cycle::PaymentCycle;
+    use domain::subscribe::{
+        contract_term::ContractTerm, cost_split::CostSplit, subscribe_error::SubscribeError, subscribe_id::SubscribeId,
+        subscribe_name::SubscribeName, subscribe_status::SubscribeStatus, tag::TagSet, Subscribe,
+    };
+    use domain::user::{user_error::UserError, user_profile::UserProfile, user_settings::UserSettings, User};
+    use domain::value_object::{amount::Amount, currency::Currency, money::Money};
+    use mockall::mock;
+    use rust_decimal::Decimal;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    mock! {
+        CategoryRepository {}
+        impl CategoryRepository for CategoryRepository {
+            fn create<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_all<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_by_id<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Category, CategoryError>> + Send + 'static>>;
+            fn update<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn reorder<'a>(&'a self, categories: &'a [Category]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete_with_reassignment<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId, subscribes: &'a [Subscribe]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn restore<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_deleted<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_deleted_before<'a>(&'a self, before: &'a DateTime<Utc>) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn purge<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+        }
+    }
+
+    mock! {
+        SubscribeRepository {}
+        #[async_trait::async_trait]
+        impl SubscribeRepository for SubscribeRepository {
+            async fn create(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_category(&self, category_id: &CategoryId, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
+            async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        }
+    }
+
+    mock! {
+        UserRepository {}
+        #[async_trait::async_trait]
+        impl UserRepository for UserRepository {
+            async fn create(&self, user: &User) -> Result<(), UserError>;
+            async fn find_by_id(&self, user_id: &UserId) -> Result<User, UserError>;
+            async fn update(&self, user: &User) -> Result<(), UserError>;
+            async fn delete(&self, user_id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    /// 指定の国に住むユーザーを返すリポジトリを作成する
+    fn create_user_repository(user_id: &UserId, country_id: i32) -> MockUserRepository {
+        let user_id = user_id.clone();
+        let mut mock_user_repository = MockUserRepository::new();
+        mock_user_repository.expect_find_by_id().return_once(move |_| {
+            let now = Utc::now();
+            let profile = UserProfile::new("山田 太郎", "taro@example.com", None).unwrap();
+            Ok(User::from(user_id, profile, country_id, UserSettings::default(), now, now))
+        });
+        mock_user_repository
+    }
+
+    fn create_mock_category(user_id: &UserId, name: &str) -> Category {
+        Category::new(user_id.clone(), CategoryName::from_str(name).unwrap())
+    }
+
+    fn create_mock_subscribe(user_id: &UserId, category_id: &CategoryId) -> Subscribe {
+        let now = Utc::now();
+        Subscribe::new(
+            user_id.clone(),
+            SubscribeName::new("hoge").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            category_id.clone(),
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now + chrono::Duration::days(30),
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_update_category_not_updatable() {
+        // 存在しないカテゴリ、ゴミ箱にあるカテゴリはどちらもリポジトリの条件で弾かれる
+        let cases = [
+            "存在しない",
+            "ゴミ箱",
+        ];
+        for case in cases {
+            let user_id = UserId::new();
+            let category = create_mock_category(&user_id, "動画配信");
+            let dto = CategoryDto::map_to_dto(&category);
+
+            let mut mock_repository = MockCategoryRepository::new();
+            mock_repository.expect_update().return_once(|_| Box::pin(async { Err(CategoryError::NotExist) })).times(1);
+
+            let service =
+                CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), MockUserRepository::new());
+            let result = service.update_category(dto).await;
+
+            assert_eq!(
+                result.unwrap_err(),
+                ApplicationError::CategoryError(CategoryError::NotExist.to_string()),
+                "case: {}",
+                case
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_in_use() {
+        let user_id = UserId::new();
+        let category = create_mock_category(&user_id, "動画配信");
+        let category_id = category.category_id().clone();
+        let subscribes = vec![create_mock_subscribe(&user_id, &category_id)];
+
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_delete().times(0);
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_by_category().return_once(move |_, _| Ok(subscribes)).times(1);
+
+        let service = CategoryServiceImpl::new(mock_repository, mock_subscribe_repository, MockUserRepository::new());
+        let result = service.delete_category(&user_id.to_string(), &category_id.to_string()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ApplicationError::Conflict(format!("category {} is used by 1 subscriptions", category_id))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_with_reassignment_to_target() {
+        let user_id = UserId::new();
+        let category = create_mock_category(&user_id, "動画配信");
+        let target = create_mock_category(&user_id, "音楽");
+        let category_id = category.category_id().clone();
+        let target_id = target.category_id().clone();
+        let subscribes = vec![
+            create_mock_subscribe(&user_id, &category_id),
+            create_mock_subscribe(&user_id, &category_id),
+        ];
+
+        let mut mock_repository = MockCategoryRepository::new();
+        let found = vec![
+            category, target,
+        ];
+        mock_repository.expect_find_by_id().times(2).returning(move |id, _| {
+            let result = found.iter().find(|c| c.category_id() == id).cloned().ok_or(CategoryError::NotExist);
+            Box::pin(async move { result })
+        });
+        mock_repository.expect_create().times(0);
+        mock_repository.expect_delete().times(0);
+        let expected = target_id.clone();
+        mock_repository
+            .expect_delete_with_reassignment()
+            .withf(move |_, _, s| {
+                s.len() == 3 && s.iter().all(|s| s.category_id() == &expected && s.updated_at().is_some())
+            })
+            .returning(|_, _, _| Box::pin(async { Ok(()) }))
+            .times(1);
+
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_by_category().return_once(move |_, _| Ok(subscribes)).times(1);
+        // ゴミ箱にあるサブスクのうち、削除するカテゴリに所属するものだけを付け替える
+        let deleted = vec![
+            create_mock_subscribe(&user_id, &category_id),
+            create_mock_subscribe(&user_id, &CategoryId::new()),
+        ];
+        mock_subscribe_repository.expect_find_deleted().return_once(move |_| Ok(deleted)).times(1);
+        mock_subscribe_repository.expect_update().times(0);
+
+        let service = CategoryServiceImpl::new(mock_repository, mock_subscribe_repository, MockUserRepository::new());
+        let result = service
+            .delete_category_with_reassignment(
+                &user_id.to_string(),
+                &category_id.to_string(),
+                Some(&target_id.to_string()),
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_with_reassignment_failure() {
+        let user_id = UserId::new();
+        let category = create_mock_category(&user_id, "動画配信");
+        let target = create_mock_category(&user_id, "音楽");
+        let category_id = category.category_id().clone();
+        let target_id = target.category_id().clone();
+        let subscribes = vec![create_mock_subscribe(&user_id, &category_id)];
+
+        let mut mock_repository = MockCategoryRepository::new();
+        let found = vec![
+            category, target,
+        ];
+        mock_repository.expect_find_by_id().times(2).returning(move |id, _| {
+            let result = found.iter().find(|c| c.category_id() == id).cloned().ok_or(CategoryError::NotExist);
+            Box::pin(async move { result })
         });
-        result
+        mock_repository
+            .expect_delete_with_reassignment()
+            .returning(|_, _, _| Box::pin(async { Err(CategoryError::NotExist) }))
+            .times(1);
+
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_by_category().return_once(move |_, _| Ok(subscribes)).times(1);
+        mock_subscribe_repository.expect_find_deleted().return_once(|_| Ok(vec![])).times(1);
+        mock_subscribe_repository.expect_update().times(0);
+
+        let service = CategoryServiceImpl::new(mock_repository, mock_subscribe_repository, MockUserRepository::new());
+        let result = service
+            .delete_category_with_reassignment(
+                &user_id.to_string(),
+                &category_id.to_string(),
+                Some(&target_id.to_string()),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err(), ApplicationError::from(CategoryError::NotExist));
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_with_reassignment_creates_uncategorized() {
+        let user_id = UserId::new();
+        let category = create_mock_category(&user_id, "動画配信");
+        let category_id = category.category_id().clone();
+        let subscribes = vec![create_mock_subscribe(&user_id, &category_id)];
+
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_find_by_id().return_once(move |_, _| Box::pin(async move { Ok(category) })).times(1);
+        mock_repository.expect_find_all().returning(|_| Box::pin(async { Ok(vec![]) })).times(1);
+        mock_repository
+            .expect_create()
+            .withf(|c| c.is_uncategorized())
+            .returning(|_| Box::pin(async { Ok(()) }))
+            .times(1);
+        mock_repository
+            .expect_delete_with_reassignment()
+            .withf(|_, _, s| s.len() == 1)
+            .returning(|_, _, _| Box::pin(async { Ok(()) }))
+            .times(1);
+
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_by_category().return_once(move |_, _| Ok(subscribes)).times(1);
+        mock_subscribe_repository.expect_find_deleted().return_once(|_| Ok(vec![])).times(1);
+
+        let service = CategoryServiceImpl::new(mock_repository, mock_subscribe_repository, MockUserRepository::new());
+        let result =
+            service.delete_category_with_reassignment(&user_id.to_string(), &category_id.to_string(), None).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_delete_uncategorized_with_reassignment_to_itself() {
+        let user_id = UserId::new();
+        let category = Category::uncategorized(user_id.clone());
+        let category_id = category.category_id().clone();
+        let existing = category.clone();
+
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_find_by_id().return_once(move |_, _| Box::pin(async move { Ok(category) })).times(1);
+        mock_repository.expect_find_all().return_once(move |_| Box::pin(async move { Ok(vec![existing]) })).times(1);
+        mock_repository.expect_delete().times(0);
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_by_category().times(0);
+
+        let service = CategoryServiceImpl::new(mock_repository, mock_subscribe_repository, MockUserRepository::new());
+        let result =
+            service.delete_category_with_reassignment(&user_id.to_string(), &category_id.to_string(), None).await;
+
+        assert_eq!(result.unwrap_err(), ApplicationError::from(CategoryError::SameReassignTarget));
+    }
+
+    #[tokio::test]
+    async fn test_create_category_appended_at_end() {
+        let user_id = UserId::new();
+        let mut first = create_mock_category(&user_id, "動画配信");
+        first.move_to(0);
+        let mut second = create_mock_category(&user_id, "音楽");
+        second.move_to(4);
+        let existing = vec![
+            first, second,
+        ];
+
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_find_all().return_once(move |_| Box::pin(async move { Ok(existing) })).times(1);
+        mock_repository
+            .expect_create()
+            .withf(|c| c.sort_order() == 5)
+            .returning(|_| Box::pin(async { Ok(()) }))
+            .times(1);
+
+        let service =
+            CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), MockUserRepository::new());
+        let dto = CategoryDto::new(String::new(), user_id.to_string(), "ゲーム".to_string());
+        let result = service.create_category(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reorder_categories() {
+        let user_id = UserId::new();
+        let categories = vec![
+            create_mock_category(&user_id, "動画配信"),
+            create_mock_category(&user_id, "音楽"),
+            create_mock_category(&user_id, "ゲーム"),
+        ];
+        let ids = categories.iter().rev().map(|c| c.category_id().to_string()).collect::<Vec<_>>();
+        let expected = ids.clone();
+
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_find_all().return_once(move |_| Box::pin(async move { Ok(categories) })).times(1);
+        mock_repository
+            .expect_reorder()
+            .withf(move |changed| {
+                // 並び順が0のまま変わらない先頭のカテゴリは含まれない
+                changed.len() == 2
+                    && changed.iter().all(|c| expected[c.sort_order() as usize] == c.category_id().to_string())
+            })
+            .returning(|_| Box::pin(async { Ok(()) }))
+            .times(1);
+
+        let service =
+            CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), MockUserRepository::new());
+        let result = service.reorder_categories(CategoryOrderDto::new(user_id.to_string(), ids)).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reorder_categories_invalid_order() {
+        let user_id = UserId::new();
+        let categories = vec![
+            create_mock_category(&user_id, "動画配信"),
+            create_mock_category(&user_id, "音楽"),
+        ];
+        let first = categories[0].category_id().to_string();
+        let test_case = vec![
+            vec![first.clone()],
+            vec![
+                first.clone(),
+                first.clone(),
+            ],
+            vec![
+                first.clone(),
+                CategoryId::new().to_string(),
+            ],
+        ];
+
+        for ids in test_case {
+            let categories = categories.clone();
+            let mut mock_repository = MockCategoryRepository::new();
+            mock_repository.expect_find_all().return_once(move |_| Box::pin(async move { Ok(categories) }));
+            mock_repository.expect_reorder().times(0);
+
+            let service =
+                CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), MockUserRepository::new());
+            let result = service.reorder_categories(CategoryOrderDto::new(user_id.to_string(), ids)).await;
+
+            assert!(matches!(result.unwrap_err(), ApplicationError::CategoryError(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seed_default_categories_skips_existing() {
+        let user_id = UserId::new();
+        let mut existing = create_mock_category(&user_id, "音楽");
+        existing.move_to(2);
+
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_find_all().return_once(move |_| Box::pin(async move { Ok(vec![existing]) })).times(1);
+        mock_repository
+            .expect_create()
+            .withf(|c| c.category_name().to_string() != "音楽" && c.sort_order() >= 3 && c.color().is_some())
+            .returning(|_| Box::pin(async { Ok(()) }))
+            .times(7);
+
+        let mock_user_repository = create_user_repository(&user_id, 1);
+        let service = CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), mock_user_repository);
+        let result = service.seed_default_categories(&user_id.to_string()).await;
+
+        assert_eq!(result.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_seed_default_categories_unsupported_country() {
+        let user_id = UserId::new();
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_create().times(0);
+
+        let mock_user_repository = create_user_repository(&user_id, 99);
+        let service = CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), mock_user_repository);
+        let result = service.seed_default_categories(&user_id.to_string()).await;
+
+        assert_eq!(result.unwrap_err(), ApplicationError::from(CategoryError::UnsupportedCountry(99)));
+    }
+
+    #[tokio::test]
+    async fn test_seed_default_categories_user_not_found() {
+        let mut mock_repository = MockCategoryRepository::new();
+        mock_repository.expect_create().times(0);
+        let mut mock_user_repository = MockUserRepository::new();
+        mock_user_repository.expect_find_by_id().return_once(|_| Err(UserError::NotExists));
+
+        let service = CategoryServiceImpl::new(mock_repository, MockSubscribeRepository::new(), mock_user_repository);
+        let result = service.seed_default_categories(&UserId::new().to_string()).await;
+
+        assert!(matches!(result.unwrap_err(), ApplicationError::UserError(_)));
     }
 }
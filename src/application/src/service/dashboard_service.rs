@@ -0,0 +1,1018 @@
+use crate::dtos::dashboard_dto::{
+    CalendarDayDto, CategoryBreakdownDto, CategorySpendDto, ChargeTotalDto, CurrencyTotalDto, DashboardSummaryDto,
+    MonthlyForecastDto, MonthlySpendDto, PaymentCalendarDto, ScheduledChargeDto, SpendChangeDto, SpendForecastDto,
+    SpendVarianceDto, StatusCountDto, UpcomingPaymentDto,
+};
+use crate::error::{self, ApplicationError};
+use crate::service::DashboardService;
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use domain::category::{category_id::CategoryId, category_name::CategoryName, Category};
+use domain::repository::category_repository::CategoryRepository;
+use domain::repository::subscribe_repository::SubscribeRepository;
+use domain::subscribe::{scheduled_payment::ScheduledPayment, subscribe_status::SubscribeStatus, Subscribe};
+use domain::user::user_id::UserId;
+use domain::value_object::currency::Currency;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// 月次の推移を集計できる期間の最大の月数
+const MAX_VARIANCE_MONTHS: i32 = 60;
+
+/// 請求を予測する月数
+const FORECAST_MONTHS: u32 = 12;
+
+/// 支払カレンダーを取得できる期間の最大の日数
+const MAX_CALENDAR_DAYS: i64 = 366;
+
+/// カテゴリごとの月額換算・年額換算の負担額とサブスクの件数
+type Spend = (Decimal, Decimal, usize);
+
+pub struct DashboardServiceImpl<S: SubscribeRepository, C: CategoryRepository> {
+    subscribe_repository: S,
+    category_repository: C,
+}
+
+impl<S: SubscribeRepository, C: CategoryRepository> DashboardServiceImpl<S, C> {
+    pub fn new(subscribe_repository: S, category_repository: C) -> DashboardServiceImpl<S, C> {
+        Self { subscribe_repository, category_repository }
+    }
+
+    /// ACTIVEなサブスクの負担額を通貨ごとに合計する
+    ///
+    /// 費用を分担しているサブスクは所有者自身の負担額を、支払周期から月額・年額に換算して合計する
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    ///
+    /// # 戻り値
+    /// - Vec<[CurrencyTotalDto]> 通貨コード順の合計
+    fn totals(subscribes: &[Subscribe]) -> Vec<CurrencyTotalDto> {
+        let mut totals: HashMap<Currency, (Decimal, Decimal, usize)> = HashMap::new();
+        for subscribe in subscribes.iter().filter(|s| s.status() == &SubscribeStatus::ACTIVE) {
+            let total = totals.entry(*subscribe.currency()).or_default();
+            total.0 += subscribe.own_monthly_equivalent();
+            total.1 += subscribe.own_yearly_equivalent();
+            total.2 += 1;
+        }
+
+        let mut result: Vec<_> = totals
+            .into_iter()
+            .map(|(currency, (monthly, yearly, count))| CurrencyTotalDto::new(&currency, monthly, yearly, count))
+            .collect();
+        result.sort_by(|a, b| a.currency().cmp(b.currency()));
+        result
+    }
+
+    /// 基準日以降で最も近い支払予定を求める
+    ///
+    /// 予測と同じく、自動更新しないサブスクの契約終了後の請求や、無料トライアル終了後の請求も考慮する
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - Option<[UpcomingPaymentDto]> 直近の支払予定。今後請求が発生するサブスクがない場合は [None]
+    fn next_payment(subscribes: &[Subscribe], base: &DateTime<Utc>) -> Option<UpcomingPaymentDto> {
+        subscribes
+            .iter()
+            .filter_map(|s| s.next_scheduled_payment(base).map(|p| (s, p)))
+            .min_by(|(a, a_payment), (b, b_payment)| {
+                a_payment
+                    .payment_date()
+                    .cmp(b_payment.payment_date())
+                    .then_with(|| a.name().to_string().cmp(&b.name().to_string()))
+            })
+            .map(|(s, payment)| UpcomingPaymentDto::new(s, &payment))
+    }
+
+    /// ACTIVEなサブスクの負担額を通貨・カテゴリごとに集計する
+    ///
+    /// カテゴリが存在しないサブスクは、ユーザーの「未分類」カテゴリがあればそこに、なければカテゴリIDなしの「未分類」にまとめる
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    /// * `categories` - ユーザーのカテゴリ
+    ///
+    /// # 戻り値
+    /// - Vec<[CategoryBreakdownDto]> 通貨コード順の内訳
+    fn category_breakdown(subscribes: &[Subscribe], categories: &[Category]) -> Vec<CategoryBreakdownDto> {
+        let uncategorized = categories.iter().find(|c| c.is_uncategorized());
+        let mut groups: HashMap<Currency, HashMap<Option<&CategoryId>, Spend>> = HashMap::new();
+        for subscribe in subscribes.iter().filter(|s| s.status() == &SubscribeStatus::ACTIVE) {
+            let category_id = categories
+                .iter()
+                .find(|c| c.category_id() == subscribe.category_id())
+                .or(uncategorized)
+                .map(|c| c.category_id());
+            let spend = groups.entry(*subscribe.currency()).or_default().entry(category_id).or_default();
+            spend.0 += subscribe.own_monthly_equivalent();
+            spend.1 += subscribe.own_yearly_equivalent();
+            spend.2 += 1;
+        }
+
+        let category_name = |category_id: Option<&CategoryId>| {
+            categories
+                .iter()
+                .find(|c| Some(c.category_id()) == category_id)
+                .map(|c| c.category_name().to_string())
+                .unwrap_or(CategoryName::uncategorized().to_string())
+        };
+        let mut result: Vec<_> = groups
+            .into_iter()
+            .map(|(currency, spends)| {
+                let monthly_total: Decimal = spends.values().map(|s| s.0).sum();
+                let yearly_total: Decimal = spends.values().map(|s| s.1).sum();
+                let mut spends: Vec<_> = spends
+                    .into_iter()
+                    .map(|(category_id, spend)| (category_id, category_name(category_id), spend))
+                    .collect();
+                spends.sort_by(|(_, a_name, a), (_, b_name, b)| b.0.cmp(&a.0).then_with(|| a_name.cmp(b_name)));
+
+                let spends = spends
+                    .into_iter()
+                    .map(|(category_id, name, (monthly, yearly, count))| {
+                        let percentage = match monthly_total.is_zero() {
+                            true => Decimal::ZERO,
+                            false => (monthly * Decimal::from(100) / monthly_total).round_dp(1),
+                        };
+                        CategorySpendDto::new(
+                            category_id.map(|c| c.to_string()),
+                            name,
+                            monthly,
+                            yearly,
+                            percentage,
+                            count,
+                        )
+                    })
+                    .collect();
+                CategoryBreakdownDto::new(&currency, monthly_total, yearly_total, spends)
+            })
+            .collect();
+        result.sort_by(|a, b| a.currency().cmp(b.currency()));
+        result
+    }
+
+    /// 月ごとの支出と前月からの変動を通貨ごとに集計する
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    /// * `months` - 各月の1日。先頭は最初の月と比較するための前月
+    ///
+    /// # 戻り値
+    /// - Vec<[SpendVarianceDto]> 通貨コード順の月次の推移
+    fn monthly_variance(subscribes: &[Subscribe], months: &[NaiveDate]) -> Vec<SpendVarianceDto> {
+        let spends: Vec<Vec<Option<(Currency, Decimal)>>> = subscribes
+            .iter()
+            .map(|s| {
+                months
+                    .iter()
+                    .map(|m| {
+                        let end = end_of_month(m);
+                        s.own_monthly_equivalent_at(&end).map(|v| (*s.price_at(&end).currency(), v))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut currencies: Vec<Currency> = spends.iter().flatten().flatten().map(|(c, _)| *c).collect();
+        currencies.sort_by(|a, b| a.code().cmp(b.code()));
+        currencies.dedup();
+
+        currencies
+            .iter()
+            .map(|currency| {
+                let spend_in = |spend: &[Option<(Currency, Decimal)>], i: usize| {
+                    spend[i].filter(|(c, _)| c == currency).map(|(_, v)| v)
+                };
+                let total = |i: usize| spends.iter().filter_map(|s| spend_in(s, i)).sum::<Decimal>();
+
+                let result = (1..months.len())
+                    .map(|i| {
+                        let mut changes: Vec<_> = subscribes
+                            .iter()
+                            .zip(&spends)
+                            .filter_map(|(s, spend)| {
+                                SpendChangeDto::between(s, spend_in(spend, i - 1), spend_in(spend, i))
+                            })
+                            .collect();
+                        changes.sort_by(|a, b| a.kind().cmp(&b.kind()).then_with(|| a.name().cmp(b.name())));
+                        let current = total(i);
+                        MonthlySpendDto::new(
+                            months[i].format("%Y-%m").to_string(),
+                            current,
+                            current - total(i - 1),
+                            changes,
+                        )
+                    })
+                    .collect();
+                SpendVarianceDto::new(currency, result)
+            })
+            .collect()
+    }
+
+    /// 基準日から当月を含む12か月分の請求を月ごとに集計する
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    /// * `base` - [DateTime<Utc>] 基準日時。これより前の請求は含めない
+    ///
+    /// # 戻り値
+    /// - [SpendForecastDto] 月ごとの請求と合計
+    fn forecast(subscribes: &[Subscribe], base: &DateTime<Utc>) -> SpendForecastDto {
+        let first_month = base.date_naive().with_day(1).unwrap_or_default();
+        let months: Vec<NaiveDate> = (0..FORECAST_MONTHS).map(|i| first_month + Months::new(i)).collect();
+        let to = end_of_month(&months[months.len() - 1]);
+        let charges = scheduled_charges(subscribes, base, &to);
+
+        let monthly = months
+            .iter()
+            .map(|month| {
+                let in_month: Vec<_> = charges
+                    .iter()
+                    .filter(|(_, p)| {
+                        p.payment_date().year() == month.year() && p.payment_date().month() == month.month()
+                    })
+                    .collect();
+                MonthlyForecastDto::new(
+                    month.format("%Y-%m").to_string(),
+                    charge_totals(&in_month),
+                    in_month.iter().map(|(s, p)| ScheduledChargeDto::new(s, p)).collect(),
+                )
+            })
+            .collect();
+        SpendForecastDto::new(monthly, charge_totals(&charges.iter().collect::<Vec<_>>()))
+    }
+
+    /// 期間内のACTIVEなサブスクの請求を日ごとにまとめる
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    /// * `from` - 期間の開始日
+    /// * `to` - 期間の終了日
+    ///
+    /// # 戻り値
+    /// - [PaymentCalendarDto] 日ごとの請求と合計
+    fn payment_calendar(subscribes: &[Subscribe], from: NaiveDate, to: NaiveDate) -> PaymentCalendarDto {
+        let active: Vec<Subscribe> =
+            subscribes.iter().filter(|s| s.status() == &SubscribeStatus::ACTIVE).cloned().collect();
+        let start = from.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc();
+        let end = (to + chrono::Days::new(1)).and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+            - chrono::Duration::seconds(1);
+        let charges = scheduled_charges(&active, &start, &end);
+
+        let days = charges
+            .chunk_by(|(_, a), (_, b)| a.payment_date().date_naive() == b.payment_date().date_naive())
+            .map(|day| {
+                let on_date: Vec<_> = day.iter().collect();
+                CalendarDayDto::new(
+                    day[0].1.payment_date().date_naive(),
+                    charge_totals(&on_date),
+                    day.iter().map(|(s, p)| ScheduledChargeDto::new(s, p)).collect(),
+                )
+            })
+            .collect();
+        PaymentCalendarDto::new(from, to, days, charge_totals(&charges.iter().collect::<Vec<_>>()))
+    }
+}
+
+/// 期間内に発生するサブスクの請求を支払予定日の順に列挙する
+fn scheduled_charges<'a>(
+    subscribes: &'a [Subscribe],
+    from: &DateTime<Utc>,
+    to: &DateTime<Utc>,
+) -> Vec<(&'a Subscribe, ScheduledPayment)> {
+    let mut charges: Vec<_> =
+        subscribes.iter().flat_map(|s| s.scheduled_payments(from, to).into_iter().map(move |p| (s, p))).collect();
+    charges.sort_by(|(a, p), (b, q)| {
+        p.payment_date().cmp(q.payment_date()).then_with(|| a.name().to_string().cmp(&b.name().to_string()))
+    });
+    charges
+}
+
+/// 請求額と所有者自身の負担額を通貨ごとに合計する
+fn charge_totals(charges: &[&(&Subscribe, ScheduledPayment)]) -> Vec<ChargeTotalDto> {
+    let mut totals: HashMap<Currency, (Decimal, Decimal, usize)> = HashMap::new();
+    for (_, payment) in charges {
+        let total = totals.entry(*payment.amount().currency()).or_default();
+        total.0 += payment.amount().amount().value();
+        total.1 += payment.own_amount();
+        total.2 += 1;
+    }
+
+    let mut result: Vec<_> = totals
+        .into_iter()
+        .map(|(currency, (total, own_total, count))| ChargeTotalDto::new(&currency, total, own_total, count))
+        .collect();
+    result.sort_by(|a, b| a.currency().cmp(b.currency()));
+    result
+}
+
+/// YYYY-MM形式の月をその月の1日に変換する
+fn parse_month(value: &str) -> Result<NaiveDate, ApplicationError> {
+    NaiveDate::parse_from_str(&format!("{}-01", value), "%Y-%m-%d")
+        .map_err(|_| error::to_dashboard_error(format!("invalid month: {}", value)))
+}
+
+/// YYYY-MM-DD形式の日付を変換する
+fn parse_date(value: &str) -> Result<NaiveDate, ApplicationError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| error::to_dashboard_error(format!("invalid date: {}", value)))
+}
+
+/// 月の最終時刻 (翌月1日の1秒前) を取得する
+fn end_of_month(month: &NaiveDate) -> DateTime<Utc> {
+    let next = *month + Months::new(1);
+    next.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc() - chrono::Duration::seconds(1)
+}
+
+#[async_trait::async_trait]
+impl<S: SubscribeRepository, C: CategoryRepository> DashboardService for DashboardServiceImpl<S, C> {
+    async fn find_summary(&self, user_id: &str) -> Result<DashboardSummaryDto, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+
+        Ok(DashboardSummaryDto::new(
+            Self::totals(&subscribes),
+            StatusCountDto::count(&subscribes),
+            Self::next_payment(&subscribes, &Utc::now()),
+        ))
+    }
+
+    async fn find_category_breakdown(&self, user_id: &str) -> Result<Vec<CategoryBreakdownDto>, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+        let categories = self.category_repository.find_all(&user_id).await?;
+
+        Ok(Self::category_breakdown(&subscribes, &categories))
+    }
+
+    async fn find_monthly_variance(
+        &self,
+        user_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<SpendVarianceDto>, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let (from, to) = (parse_month(from)?, parse_month(to)?);
+        let count = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32 + 1;
+        if count < 1 {
+            return Err(error::to_dashboard_error("from must not be after to"));
+        }
+        if count > MAX_VARIANCE_MONTHS {
+            return Err(error::to_dashboard_error(format!("period must be within {} months", MAX_VARIANCE_MONTHS)));
+        }
+
+        let months: Vec<NaiveDate> = (0..=count as u32).map(|i| from - Months::new(1) + Months::new(i)).collect();
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+
+        Ok(Self::monthly_variance(&subscribes, &months))
+    }
+
+    async fn find_forecast(&self, user_id: &str) -> Result<SpendForecastDto, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+
+        Ok(Self::forecast(&subscribes, &Utc::now()))
+    }
+
+    async fn find_payment_calendar(
+        &self,
+        user_id: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<PaymentCalendarDto, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let (from, to) = (parse_date(from)?, parse_date(to)?);
+        if from > to {
+            return Err(error::to_dashboard_error("from must not be after to"));
+        }
+        if (to - from).num_days() >= MAX_CALENDAR_DAYS {
+            return Err(error::to_dashboard_error(format!("period must be within {} days", MAX_CALENDAR_DAYS)));
+        }
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+
+        Ok(Self::payment_calendar(&subscribes, from, to))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::dashboard_dto::SpendChangeKind;
+    use chrono::TimeZone;
+    use domain::category::category_error::CategoryError;
+    use domain::payment::payment_method_id::PaymentMethodId;
+    use domain::payment_cycle::PaymentCycle;
+    use domain::subscribe::contract_term::ContractTerm;
+    use domain::subscribe::cost_split::{CostSplit, MemberShare, Share, SplitMember};
+    use domain::subscribe::subscribe_error::SubscribeError;
+    use domain::subscribe::subscribe_id::SubscribeId;
+    use domain::subscribe::subscribe_name::SubscribeName;
+    use domain::subscribe::tag::TagSet;
+    use domain::value_object::amount::Amount;
+    use domain::value_object::money::Money;
+    use mockall::mock;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    mock! {
+        SubscribeRepository {}
+        #[async_trait::async_trait]
+        impl SubscribeRepository for SubscribeRepository {
+            async fn create(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_category(&self, category_id: &CategoryId, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
+            async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        }
+    }
+
+    mock! {
+        CategoryRepository {}
+        impl CategoryRepository for CategoryRepository {
+            fn create<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_all<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_by_id<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Category, CategoryError>> + Send + 'static>>;
+            fn update<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn reorder<'a>(&'a self, categories: &'a [Category]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete_with_reassignment<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId, subscribes: &'a [Subscribe]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn restore<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_deleted<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_deleted_before<'a>(&'a self, before: &'a DateTime<Utc>) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn purge<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+        }
+    }
+
+    fn create_subscribe(
+        name: &str,
+        amount: &str,
+        currency: Currency,
+        cycle: PaymentCycle,
+        status: SubscribeStatus,
+        next_payment_date: DateTime<Utc>,
+    ) -> Subscribe {
+        Subscribe::new(
+            UserId::new(),
+            SubscribeName::new(name).unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::from_str(amount).unwrap(), currency).unwrap(),
+            cycle,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            next_payment_date - chrono::Duration::days(365),
+            next_payment_date,
+            true,
+            status,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_find_summary_totals_by_currency() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let next = Utc::now() + chrono::Duration::days(10);
+        let subscribes = vec![
+            create_subscribe("Netflix", "1980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, next),
+            create_subscribe("Prime", "5900", Currency::JPY, PaymentCycle::Yearly, SubscribeStatus::ACTIVE, next),
+            create_subscribe("Spotify", "9.99", Currency::USD, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, next),
+            create_subscribe("Hulu", "1026", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::PAUSED, next),
+            create_subscribe("Disney", "990", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::CANCELLED, next),
+        ];
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscribes)).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_summary(&UserId::new().to_string()).await.unwrap();
+
+        let totals = result.totals();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals[0].currency(), "JPY");
+        // 1980 + 5900 / 12 (四捨五入)
+        assert_eq!(totals[0].monthly_total(), "2472");
+        assert_eq!(totals[0].yearly_total(), "29660");
+        assert_eq!(totals[0].subscribe_count(), 2);
+        assert_eq!(totals[1].currency(), "USD");
+        assert_eq!(totals[1].monthly_total(), "9.99");
+        assert_eq!(totals[1].yearly_total(), "119.88");
+
+        let counts = result.status_counts();
+        assert_eq!((counts.trial(), counts.active(), counts.paused(), counts.cancelled()), (0, 3, 1, 1));
+    }
+
+    #[tokio::test]
+    async fn test_find_summary_uses_own_share() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let share =
+            MemberShare::new(SplitMember::Person("田中".to_string()), Share::Percentage(Decimal::from(50))).unwrap();
+        let subscribe = Subscribe::new(
+            UserId::new(),
+            SubscribeName::new("YouTube Premium").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::from_str("2280").unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            Utc::now(),
+            Utc::now(),
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::new(vec![share]).unwrap(),
+            TagSet::default(),
+        );
+        mock_repository.expect_find_all().return_once(move |_| Ok(vec![subscribe])).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_summary(&UserId::new().to_string()).await.unwrap();
+
+        assert_eq!(result.totals()[0].monthly_total(), "1140");
+        assert_eq!(result.totals()[0].yearly_total(), "13680");
+    }
+
+    #[tokio::test]
+    async fn test_find_summary_next_payment() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let now = Utc::now();
+        let soon = now + chrono::Duration::days(3);
+        let later = now + chrono::Duration::days(7);
+        let subscribes = vec![
+            create_subscribe("Netflix", "1980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, later),
+            create_subscribe("Spotify", "980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, soon),
+            create_subscribe("Hulu", "1026", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::PAUSED, now),
+        ];
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscribes)).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_summary(&UserId::new().to_string()).await.unwrap();
+
+        let next = result.next_payment().as_ref().unwrap();
+        assert_eq!(next.payment_date(), &soon);
+    }
+
+    #[tokio::test]
+    async fn test_find_summary_next_payment_skips_ended_commitment() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let now = Utc::now();
+        let soon = now + chrono::Duration::days(3);
+        let later = now + chrono::Duration::days(7);
+        let ending = Subscribe::new(
+            UserId::new(),
+            SubscribeName::new("Spotify").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::from_str("980").unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            soon - chrono::Duration::days(365),
+            soon,
+            false,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::new(Some(now), None).unwrap(),
+            CostSplit::default(),
+            TagSet::default(),
+        );
+        let subscribes = vec![
+            create_subscribe("Netflix", "1980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, later),
+            ending,
+        ];
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscribes)).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_summary(&UserId::new().to_string()).await.unwrap();
+
+        let next = result.next_payment().as_ref().unwrap();
+        assert_eq!(next.payment_date(), &later);
+    }
+
+    #[tokio::test]
+    async fn test_find_summary_empty() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_all().return_once(|_| Ok(vec![])).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_summary(&UserId::new().to_string()).await.unwrap();
+
+        assert!(result.totals().is_empty());
+        assert_eq!(result.status_counts(), &StatusCountDto::default());
+        assert!(result.next_payment().is_none());
+    }
+
+    fn in_category(mut subscribe: Subscribe, category: &Category) -> Subscribe {
+        subscribe.reassign_category(category.category_id().clone());
+        subscribe
+    }
+
+    fn create_breakdown_service(subscribes: Vec<Subscribe>, categories: Vec<Category>) -> impl DashboardService {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscribes)).times(1);
+        let mut category_repository = MockCategoryRepository::new();
+        category_repository.expect_find_all().return_once(move |_| Box::pin(async move { Ok(categories) })).times(1);
+        DashboardServiceImpl::new(mock_repository, category_repository)
+    }
+
+    #[tokio::test]
+    async fn test_find_category_breakdown() {
+        let user_id = UserId::new();
+        let video = Category::new(user_id.clone(), CategoryName::from_str("動画配信").unwrap());
+        let music = Category::new(user_id.clone(), CategoryName::from_str("音楽").unwrap());
+        let next = Utc::now();
+        let subscribes = vec![
+            in_category(
+                create_subscribe(
+                    "Netflix",
+                    "1980",
+                    Currency::JPY,
+                    PaymentCycle::Monthly,
+                    SubscribeStatus::ACTIVE,
+                    next,
+                ),
+                &video,
+            ),
+            in_category(
+                create_subscribe("Prime", "6000", Currency::JPY, PaymentCycle::Yearly, SubscribeStatus::ACTIVE, next),
+                &video,
+            ),
+            in_category(
+                create_subscribe("Spotify", "980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, next),
+                &music,
+            ),
+            in_category(
+                create_subscribe("Hulu", "1026", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::PAUSED, next),
+                &music,
+            ),
+        ];
+
+        let service = create_breakdown_service(
+            subscribes,
+            vec![
+                music,
+                video.clone(),
+            ],
+        );
+        let result = service.find_category_breakdown(&user_id.to_string()).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].monthly_total(), "3460");
+        let categories = result[0].categories();
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].category_id(), &Some(video.category_id().to_string()));
+        assert_eq!(categories[0].category_name(), "動画配信");
+        assert_eq!(categories[0].monthly_amount(), "2480");
+        assert_eq!(categories[0].yearly_amount(), "29760");
+        assert_eq!(categories[0].percentage(), "71.7");
+        assert_eq!(categories[0].subscribe_count(), 2);
+        assert_eq!(categories[1].category_name(), "音楽");
+        assert_eq!(categories[1].percentage(), "28.3");
+        assert_eq!(categories[1].subscribe_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_category_breakdown_missing_category() {
+        let user_id = UserId::new();
+        let next = Utc::now();
+        let subscribes = vec![
+            create_subscribe("Netflix", "1980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, next),
+            create_subscribe("Spotify", "9.99", Currency::USD, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, next),
+        ];
+
+        let service = create_breakdown_service(subscribes, vec![]);
+        let result = service.find_category_breakdown(&user_id.to_string()).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].currency(), "USD");
+        for breakdown in &result {
+            let categories = breakdown.categories();
+            assert_eq!(categories.len(), 1);
+            assert_eq!(categories[0].category_id(), &None);
+            assert_eq!(categories[0].category_name(), CategoryName::uncategorized().to_string());
+            assert_eq!(categories[0].percentage(), "100");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_category_breakdown_merges_into_uncategorized() {
+        let user_id = UserId::new();
+        let uncategorized = Category::uncategorized(user_id.clone());
+        let next = Utc::now();
+        let subscribes = vec![
+            in_category(
+                create_subscribe(
+                    "Netflix",
+                    "1980",
+                    Currency::JPY,
+                    PaymentCycle::Monthly,
+                    SubscribeStatus::ACTIVE,
+                    next,
+                ),
+                &uncategorized,
+            ),
+            create_subscribe("Spotify", "980", Currency::JPY, PaymentCycle::Monthly, SubscribeStatus::ACTIVE, next),
+        ];
+
+        let service = create_breakdown_service(subscribes, vec![uncategorized.clone()]);
+        let result = service.find_category_breakdown(&user_id.to_string()).await.unwrap();
+
+        let categories = result[0].categories();
+        assert_eq!(categories.len(), 1);
+        assert_eq!(categories[0].category_id(), &Some(uncategorized.category_id().to_string()));
+        assert_eq!(categories[0].subscribe_count(), 2);
+    }
+
+    fn create_subscribe_since(
+        name: &str,
+        amount: &str,
+        cycle: PaymentCycle,
+        status: SubscribeStatus,
+        first_payment_date: (i32, u32, u32),
+        next_payment_date: (i32, u32, u32),
+    ) -> Subscribe {
+        let date = |(y, m, d): (i32, u32, u32)| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap();
+        Subscribe::new(
+            UserId::new(),
+            SubscribeName::new(name).unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::from_str(amount).unwrap(), Currency::JPY).unwrap(),
+            cycle,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            date(first_payment_date),
+            date(next_payment_date),
+            true,
+            status,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_find_monthly_variance() {
+        let mut netflix = create_subscribe_since(
+            "Netflix",
+            "1000",
+            PaymentCycle::Monthly,
+            SubscribeStatus::ACTIVE,
+            (2023, 6, 10),
+            (2024, 5, 10),
+        );
+        netflix.change_amount(
+            Money::new(Amount::from_str("1200").unwrap(), Currency::JPY).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap(),
+        );
+        let subscribes = vec![
+            netflix,
+            create_subscribe_since(
+                "Spotify",
+                "980",
+                PaymentCycle::Monthly,
+                SubscribeStatus::CANCELLED,
+                (2023, 1, 1),
+                (2024, 2, 1),
+            ),
+            create_subscribe_since(
+                "Prime",
+                "6000",
+                PaymentCycle::Yearly,
+                SubscribeStatus::ACTIVE,
+                (2024, 2, 20),
+                (2025, 2, 20),
+            ),
+            create_subscribe_since(
+                "Hulu",
+                "1026",
+                PaymentCycle::Monthly,
+                SubscribeStatus::PAUSED,
+                (2023, 1, 1),
+                (2024, 4, 10),
+            ),
+            create_subscribe_since(
+                "Disney",
+                "990",
+                PaymentCycle::Monthly,
+                SubscribeStatus::TRIAL,
+                (2024, 1, 1),
+                (2024, 1, 1),
+            ),
+        ];
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscribes)).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_monthly_variance(&UserId::new().to_string(), "2024-01", "2024-04").await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        let months = result[0].months();
+        let summary: Vec<_> = months.iter().map(|m| (m.month(), m.total(), m.delta())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("2024-01", "3006", "0"),
+                ("2024-02", "2526", "-480"),
+                ("2024-03", "2726", "200"),
+                ("2024-04", "1700", "-1026"),
+            ]
+        );
+        assert!(months[0].changes().is_empty());
+        let changes: Vec<_> = months[1].changes().iter().map(|c| (c.name(), c.kind(), c.delta())).collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("Prime", SpendChangeKind::Added, "500"),
+                ("Spotify", SpendChangeKind::Cancelled, "-980")
+            ]
+        );
+        assert_eq!(months[2].changes()[0].kind(), SpendChangeKind::PriceChanged);
+        assert_eq!(months[3].changes()[0].kind(), SpendChangeKind::Paused);
+    }
+
+    #[tokio::test]
+    async fn test_find_monthly_variance_invalid_period() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_all().times(0);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let user_id = UserId::new().to_string();
+        for (from, to) in [
+            ("2024-04", "2024-01"),
+            ("2024/01", "2024-04"),
+            ("2020-01", "2025-01"),
+        ] {
+            let result = service.find_monthly_variance(&user_id, from, to).await;
+
+            assert!(matches!(result, Err(ApplicationError::DashboardError(_))), "{} - {}", from, to);
+        }
+    }
+
+    #[test]
+    fn test_forecast() {
+        let subscribes = vec![
+            create_subscribe_since(
+                "Netflix",
+                "1980",
+                PaymentCycle::Monthly,
+                SubscribeStatus::ACTIVE,
+                (2023, 6, 20),
+                (2024, 1, 20),
+            ),
+            create_subscribe_since(
+                "Prime",
+                "5900",
+                PaymentCycle::Yearly,
+                SubscribeStatus::ACTIVE,
+                (2023, 5, 10),
+                (2024, 5, 10),
+            ),
+            create_subscribe_since(
+                "Hulu",
+                "1026",
+                PaymentCycle::Monthly,
+                SubscribeStatus::PAUSED,
+                (2023, 1, 1),
+                (2024, 2, 1),
+            ),
+            create_subscribe_since(
+                "Spotify",
+                "980",
+                PaymentCycle::Monthly,
+                SubscribeStatus::CANCELLED,
+                (2023, 1, 1),
+                (2024, 2, 1),
+            ),
+        ];
+        let base = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+
+        let result =
+            DashboardServiceImpl::<MockSubscribeRepository, MockCategoryRepository>::forecast(&subscribes, &base);
+
+        let months = result.months();
+        assert_eq!(months.len(), 12);
+        assert_eq!(months[0].month(), "2024-01");
+        assert_eq!(months[11].month(), "2024-12");
+        assert_eq!(months[0].totals()[0].total(), "1980");
+        // 年払いは月額に換算せず、更新月に全額を計上する
+        assert_eq!(months[4].totals()[0].total(), "7880");
+        assert_eq!(months[4].totals()[0].charge_count(), 2);
+        let names: Vec<_> = months[4].charges().iter().map(|c| c.name()).collect();
+        assert_eq!(names, vec!["Prime", "Netflix"]);
+        assert_eq!(months[5].totals()[0].total(), "1980");
+        assert_eq!(result.totals()[0].total(), "29660");
+        assert_eq!(result.totals()[0].charge_count(), 13);
+    }
+
+    #[tokio::test]
+    async fn test_find_forecast_empty() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_all().return_once(|_| Ok(vec![])).times(1);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let result = service.find_forecast(&UserId::new().to_string()).await.unwrap();
+
+        assert_eq!(result.months().len(), 12);
+        assert!(result.months().iter().all(|m| m.charges().is_empty()));
+        assert!(result.totals().is_empty());
+    }
+
+    #[test]
+    fn test_payment_calendar() {
+        let subscribes = vec![
+            create_subscribe_since(
+                "Netflix",
+                "1980",
+                PaymentCycle::Monthly,
+                SubscribeStatus::ACTIVE,
+                (2023, 6, 10),
+                (2024, 3, 10),
+            ),
+            create_subscribe_since(
+                "Gym",
+                "300",
+                PaymentCycle::Weekly,
+                SubscribeStatus::ACTIVE,
+                (2024, 2, 27),
+                (2024, 3, 5),
+            ),
+            create_subscribe_since(
+                "Hulu",
+                "1026",
+                PaymentCycle::Monthly,
+                SubscribeStatus::ACTIVE,
+                (2023, 1, 10),
+                (2024, 3, 10),
+            ),
+            create_subscribe_since(
+                "Disney",
+                "990",
+                PaymentCycle::Monthly,
+                SubscribeStatus::PAUSED,
+                (2023, 1, 10),
+                (2024, 3, 10),
+            ),
+        ];
+        let from = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+
+        let result = DashboardServiceImpl::<MockSubscribeRepository, MockCategoryRepository>::payment_calendar(
+            &subscribes,
+            from,
+            to,
+        );
+
+        let days: Vec<_> = result.days().iter().map(|d| (d.date().day(), d.charges().len())).collect();
+        assert_eq!(
+            days,
+            vec![
+                (5, 1),
+                (10, 2),
+                (12, 1),
+                (19, 1),
+                (26, 1)
+            ]
+        );
+        let march_10 = &result.days()[1];
+        let names: Vec<_> = march_10.charges().iter().map(|c| c.name()).collect();
+        assert_eq!(names, vec!["Hulu", "Netflix"]);
+        assert_eq!(march_10.totals()[0].total(), "3006");
+        assert_eq!(result.totals()[0].total(), "4206");
+        assert_eq!(result.totals()[0].charge_count(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_find_payment_calendar_invalid_period() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_all().times(0);
+
+        let service = DashboardServiceImpl::new(mock_repository, MockCategoryRepository::new());
+        let user_id = UserId::new().to_string();
+        for (from, to) in [
+            ("2024-03-31", "2024-03-01"),
+            ("2024-03", "2024-03-31"),
+            ("2024-01-01", "2025-01-01"),
+        ] {
+            let result = service.find_payment_calendar(&user_id, from, to).await;
+
+            assert!(matches!(result, Err(ApplicationError::DashboardError(_))), "{} - {}", from, to);
+        }
+    }
+}
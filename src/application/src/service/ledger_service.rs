@@ -0,0 +1,427 @@
+use crate::dtos::ledger_dto::{ConfirmPaymentDto, DisputePaymentDto, PaymentLedgerDto};
+use crate::dtos::DTO;
+use crate::error::{self, ApplicationError};
+use crate::event::{EventEmitter, PublishEvents};
+use crate::service::LedgerService;
+use chrono::{DateTime, Utc};
+use domain::event::DomainEventKind;
+use domain::ledger::ledger_error::LedgerError;
+use domain::ledger::payment_status::PaymentStatus;
+use domain::ledger::Payment;
+use domain::repository::ledger_repository::LedgerRepository;
+use domain::repository::subscribe_repository::SubscribeRepository;
+use domain::subscribe::subscribe_id::SubscribeId;
+use domain::subscribe::Subscribe;
+use domain::user::user_id::UserId;
+use domain::value_object::amount::Amount;
+use domain::value_object::money::Money;
+use std::str::FromStr;
+
+pub struct LedgerServiceImpl<S: SubscribeRepository, L: LedgerRepository> {
+    subscribe_repository: S,
+    ledger_repository: L,
+    events: EventEmitter,
+}
+
+impl<S: SubscribeRepository, L: LedgerRepository> PublishEvents for LedgerServiceImpl<S, L> {
+    fn emitter_mut(&mut self) -> &mut EventEmitter {
+        &mut self.events
+    }
+}
+
+impl<S: SubscribeRepository, L: LedgerRepository> LedgerServiceImpl<S, L> {
+    pub fn new(subscribe_repository: S, ledger_repository: L) -> LedgerServiceImpl<S, L> {
+        Self { subscribe_repository, ledger_repository, events: EventEmitter::default() }
+    }
+
+    /// 支払記録を保存し、記録したことを表すドメインイベントを発行する
+    ///
+    /// # 引数
+    /// * `payment` - [Payment] 保存する支払記録
+    async fn save(&self, payment: &Payment) -> Result<(), ApplicationError> {
+        self.ledger_repository.save(payment).await?;
+        let kind = DomainEventKind::PaymentRecorded {
+            payment_id: payment.payment_id().clone(),
+            subscribe_id: payment.subscribe_id().clone(),
+            status: payment.status().clone(),
+        };
+        self.events.publish(payment.user_id(), kind).await;
+        Ok(())
+    }
+
+    /// 対象のサブスクと支払予定日の支払記録を取得する
+    ///
+    /// 支払記録が存在しない場合は、支払予定日時点のサブスクの金額で未確認の支払記録を作成する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    /// * `subscribe_id` - サブスクID
+    /// * `due_date` - 支払予定日。未指定の場合は現在までに到来した最後の支払予定日
+    ///
+    /// # 戻り値
+    /// - ([Subscribe], [Payment]) 対象のサブスクと支払記録
+    ///
+    /// # エラー
+    /// - [ApplicationError::LedgerError] 支払予定日が未指定で、まだ一度も課金されていない場合
+    async fn find_or_create_payment(
+        &self,
+        user_id: &str,
+        subscribe_id: &str,
+        due_date: &Option<DateTime<Utc>>,
+    ) -> Result<(Subscribe, Payment), ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let subscribe_id = SubscribeId::from_str(subscribe_id)?;
+        let subscribe = self.subscribe_repository.find_by_id(&subscribe_id, &user_id).await?;
+        let due_date = match due_date {
+            Some(d) => *d,
+            None => subscribe
+                .latest_payment_date(&Utc::now())
+                .ok_or_else(|| LedgerError::MissingField("due_date".to_string()))?,
+        };
+
+        let payments = self.ledger_repository.find_by_subscribe(&user_id, &subscribe_id).await?;
+        let payment = match payments.into_iter().find(|p| p.is_due_on(&due_date)) {
+            Some(p) => p,
+            None => Payment::new(user_id, subscribe_id, due_date, subscribe.price_at(&due_date).clone()),
+        };
+        Ok((subscribe, payment))
+    }
+}
+
+#[async_trait::async_trait]
+impl<S: SubscribeRepository, L: LedgerRepository> LedgerService for LedgerServiceImpl<S, L> {
+    async fn confirm_payment(&self, payment: ConfirmPaymentDto) -> Result<PaymentLedgerDto, ApplicationError> {
+        let (subscribe, mut entry) =
+            self.find_or_create_payment(payment.user_id(), payment.subscribe_id(), payment.due_date()).await?;
+
+        let amount = match payment.amount() {
+            Some(s) => {
+                let amount = Amount::from_str(s).map_err(error::to_ledger_error)?;
+                Some(Money::new(amount, *subscribe.currency()).map_err(error::to_ledger_error)?)
+            }
+            None => None,
+        };
+        entry.confirm(payment.paid_date().unwrap_or_else(Utc::now), amount)?;
+
+        self.save(&entry).await?;
+        Ok(PaymentLedgerDto::map_to_dto(&entry))
+    }
+
+    async fn dispute_payment(&self, payment: DisputePaymentDto) -> Result<PaymentLedgerDto, ApplicationError> {
+        let status = PaymentStatus::from_str(payment.status())?;
+        let (_, mut entry) =
+            self.find_or_create_payment(payment.user_id(), payment.subscribe_id(), payment.due_date()).await?;
+
+        entry.dispute(status, payment.note().clone())?;
+
+        self.save(&entry).await?;
+        Ok(PaymentLedgerDto::map_to_dto(&entry))
+    }
+
+    async fn find_payments(
+        &self,
+        user_id: &str,
+        subscribe_id: Option<&str>,
+    ) -> Result<Vec<PaymentLedgerDto>, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let mut payments = match subscribe_id {
+            Some(s) => {
+                let subscribe_id = SubscribeId::from_str(s)?;
+                self.ledger_repository.find_by_subscribe(&user_id, &subscribe_id).await?
+            }
+            None => self.ledger_repository.find_all(&user_id).await?,
+        };
+        payments.sort_by(|a, b| b.due_date().cmp(a.due_date()));
+
+        Ok(payments.iter().map(PaymentLedgerDto::map_to_dto).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::category::category_id::CategoryId;
+    use domain::ledger::payment_id::PaymentId;
+    use domain::payment::payment_method_id::PaymentMethodId;
+    use domain::payment_cycle::PaymentCycle;
+    use domain::subscribe::contract_term::ContractTerm;
+    use domain::subscribe::cost_split::CostSplit;
+    use domain::subscribe::subscribe_error::SubscribeError;
+    use domain::subscribe::subscribe_name::SubscribeName;
+    use domain::subscribe::subscribe_status::SubscribeStatus;
+    use domain::subscribe::tag::TagSet;
+    use domain::value_object::currency::Currency;
+    use mockall::mock;
+    use rust_decimal::Decimal;
+
+    mock! {
+        SubscribeRepository {}
+        #[async_trait::async_trait]
+        impl SubscribeRepository for SubscribeRepository {
+            async fn create(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_category(&self, category_id: &CategoryId, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
+            async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+            async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_deleted_before(&self, before: &chrono::DateTime<chrono::Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        }
+    }
+
+    mock! {
+        LedgerRepository {}
+        #[async_trait::async_trait]
+        impl LedgerRepository for LedgerRepository {
+            async fn save(&self, payment: &Payment) -> Result<(), LedgerError>;
+            async fn find_by_id(&self, payment_id: &PaymentId, user_id: &UserId) -> Result<Payment, LedgerError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<Payment>, LedgerError>;
+            async fn find_by_subscribe(&self, user_id: &UserId, subscribe_id: &SubscribeId) -> Result<Vec<Payment>, LedgerError>;
+        }
+    }
+
+    fn usd(value: &str) -> Money {
+        Money::new(Amount::from_str(value).unwrap(), Currency::USD).unwrap()
+    }
+
+    fn create_mock_subscribe() -> Subscribe {
+        let now = Utc::now();
+        create_mock_subscribe_with_dates(now, now)
+    }
+
+    fn create_mock_subscribe_with_dates(
+        first_payment_date: DateTime<Utc>,
+        next_payment_date: DateTime<Utc>,
+    ) -> Subscribe {
+        Subscribe::new(
+            UserId::new(),
+            SubscribeName::new("Netflix").unwrap(),
+            PaymentMethodId::new(),
+            usd("15.49"),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            first_payment_date,
+            next_payment_date,
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_confirm_payment_creates_entry_with_actual_amount() {
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let subscribe = create_mock_subscribe();
+        let dto = ConfirmPaymentDto::new(
+            subscribe.user_id().to_string(),
+            subscribe.subscribe_id().to_string(),
+            None,
+            None,
+            Some("16.99".to_string()),
+        );
+
+        subscribe_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        ledger_repository.expect_find_by_subscribe().return_once(|_, _| Ok(vec![])).times(1);
+        ledger_repository
+            .expect_save()
+            .withf(|p| p.status() == &PaymentStatus::PAID && p.amount() == &usd("16.99"))
+            .return_once(|_| Ok(()))
+            .times(1);
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.confirm_payment(dto).await;
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.status(), "PAID");
+        assert_eq!(result.amount(), "16.99");
+    }
+
+    #[tokio::test]
+    async fn test_confirm_payment_defaults_to_latest_due_date() {
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let now = Utc::now();
+        let first = now - chrono::Duration::days(40);
+        // 次回支払予定日は繰り越し済みで、値上げは直近の支払予定日より後に適用される
+        let mut subscribe = create_mock_subscribe_with_dates(first, now + chrono::Duration::days(20));
+        let due_date = subscribe.latest_payment_date(&now).unwrap();
+        subscribe.change_amount(usd("17.99"), now);
+        let dto = ConfirmPaymentDto::new(
+            subscribe.user_id().to_string(),
+            subscribe.subscribe_id().to_string(),
+            None,
+            None,
+            None,
+        );
+
+        subscribe_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        ledger_repository.expect_find_by_subscribe().return_once(|_, _| Ok(vec![])).times(1);
+        ledger_repository
+            .expect_save()
+            .withf(move |p| p.is_due_on(&due_date) && p.amount() == &usd("15.49"))
+            .return_once(|_| Ok(()))
+            .times(1);
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.confirm_payment(dto).await;
+
+        assert!(result.is_ok());
+        assert!(due_date < now);
+    }
+
+    #[tokio::test]
+    async fn test_confirm_payment_before_first_payment() {
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let first = Utc::now() + chrono::Duration::days(10);
+        let subscribe = create_mock_subscribe_with_dates(first, first);
+        let dto = ConfirmPaymentDto::new(
+            subscribe.user_id().to_string(),
+            subscribe.subscribe_id().to_string(),
+            None,
+            None,
+            None,
+        );
+
+        subscribe_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        ledger_repository.expect_find_by_subscribe().never();
+        ledger_repository.expect_save().never();
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.confirm_payment(dto).await;
+
+        assert!(matches!(result, Err(ApplicationError::LedgerError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_confirm_payment_invalid_amount_scale() {
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let subscribe = create_mock_subscribe();
+        let dto = ConfirmPaymentDto::new(
+            subscribe.user_id().to_string(),
+            subscribe.subscribe_id().to_string(),
+            None,
+            None,
+            Some("16.999".to_string()),
+        );
+
+        subscribe_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        ledger_repository.expect_find_by_subscribe().return_once(|_, _| Ok(vec![])).times(1);
+        ledger_repository.expect_save().never();
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.confirm_payment(dto).await;
+
+        assert!(matches!(result, Err(ApplicationError::LedgerError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dispute_payment_refunds_existing_entry() {
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let subscribe = create_mock_subscribe();
+        let due_date = *subscribe.next_payment_date();
+        let mut paid = Payment::new(
+            subscribe.user_id().clone(),
+            subscribe.subscribe_id().clone(),
+            due_date,
+            subscribe.money().clone(),
+        );
+        paid.confirm(due_date, None).unwrap();
+        let payment_id = paid.payment_id().to_string();
+        let dto = DisputePaymentDto::new(
+            subscribe.user_id().to_string(),
+            subscribe.subscribe_id().to_string(),
+            Some(due_date),
+            "REFUNDED".to_string(),
+            Some("double charged".to_string()),
+        );
+
+        subscribe_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        ledger_repository.expect_find_by_subscribe().return_once(move |_, _| Ok(vec![paid])).times(1);
+        ledger_repository.expect_save().return_once(|_| Ok(())).times(1);
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.dispute_payment(dto).await;
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.payment_id(), payment_id);
+        assert_eq!(result.status(), "REFUNDED");
+    }
+
+    #[tokio::test]
+    async fn test_dispute_payment_invalid_transition() {
+        let mut subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let subscribe = create_mock_subscribe();
+        let dto = DisputePaymentDto::new(
+            subscribe.user_id().to_string(),
+            subscribe.subscribe_id().to_string(),
+            None,
+            "REFUNDED".to_string(),
+            None,
+        );
+
+        subscribe_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        ledger_repository.expect_find_by_subscribe().return_once(|_, _| Ok(vec![])).times(1);
+        ledger_repository.expect_save().never();
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.dispute_payment(dto).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ApplicationError::LedgerError("Invalid payment status transition: PENDING -> REFUNDED".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_payments_sorted_by_due_date_desc() {
+        let subscribe_repository = MockSubscribeRepository::new();
+        let mut ledger_repository = MockLedgerRepository::new();
+        let subscribe = create_mock_subscribe();
+        let older = Payment::new(
+            subscribe.user_id().clone(),
+            subscribe.subscribe_id().clone(),
+            Utc::now() - chrono::Duration::days(30),
+            subscribe.money().clone(),
+        );
+        let newer = Payment::new(
+            subscribe.user_id().clone(),
+            subscribe.subscribe_id().clone(),
+            Utc::now(),
+            Money::new(Amount::try_from(Decimal::from(16)).unwrap(), Currency::USD).unwrap(),
+        );
+        let newer_id = newer.payment_id().to_string();
+
+        ledger_repository
+            .expect_find_all()
+            .return_once(move |_| {
+                Ok(vec![
+                    older, newer,
+                ])
+            })
+            .times(1);
+
+        let service = LedgerServiceImpl::new(subscribe_repository, ledger_repository);
+        let result = service.find_payments(&subscribe.user_id().to_string(), None).await;
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].payment_id(), newer_id);
+    }
+}
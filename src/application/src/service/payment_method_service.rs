@@ -1,36 +1,60 @@
+use crate::dtos::list_order::ListOrder;
 use crate::dtos::payment_method_dto::PaymentMethodDTO;
 use crate::dtos::DTO;
 use crate::error::ApplicationError;
+use crate::event::{EventEmitter, PublishEvents};
 use crate::service::PaymentMethodService;
+use chrono::Utc;
+use domain::event::DomainEventKind;
 use domain::payment::payment_method_id::PaymentMethodId;
 use domain::payment::PaymentMethod;
 use domain::repository::payment_repository::PaymentRepository;
+use domain::repository::subscribe_repository::SubscribeRepository;
 use domain::user::user_id::UserId;
 use std::str::FromStr;
 
-pub struct PaymentMethodServiceImpl<T: PaymentRepository> {
+pub struct PaymentMethodServiceImpl<T: PaymentRepository, S: SubscribeRepository> {
     repository: T,
+    subscribe_repository: S,
+    events: EventEmitter,
 }
 
-impl<T: PaymentRepository> PaymentMethodServiceImpl<T> {
-    pub fn new(repository: T) -> PaymentMethodServiceImpl<T> {
-        Self { repository }
+impl<T: PaymentRepository, S: SubscribeRepository> PublishEvents for PaymentMethodServiceImpl<T, S> {
+    fn emitter_mut(&mut self) -> &mut EventEmitter {
+        &mut self.events
+    }
+}
+
+impl<T: PaymentRepository, S: SubscribeRepository> PaymentMethodServiceImpl<T, S> {
+    pub fn new(repository: T, subscribe_repository: S) -> PaymentMethodServiceImpl<T, S> {
+        Self { repository, subscribe_repository, events: EventEmitter::default() }
     }
 }
 
 #[async_trait::async_trait]
-impl<T: PaymentRepository> PaymentMethodService for PaymentMethodServiceImpl<T> {
+impl<T: PaymentRepository, S: SubscribeRepository> PaymentMethodService for PaymentMethodServiceImpl<T, S> {
     async fn create_payment_method(&self, payment: PaymentMethodDTO) -> Result<(), ApplicationError> {
-        let payment_method = PaymentMethodDTO::map_to_domain_model(payment)?;
+        let mut payment_method = PaymentMethodDTO::map_to_domain_model(payment)?;
+        payment_method.mark_created(Utc::now());
 
         self.repository.create(&payment_method).await?;
+        let kind =
+            DomainEventKind::PaymentMethodCreated { payment_method_id: payment_method.payment_method_id().clone() };
+        self.events.publish(payment_method.user_id(), kind).await;
         Ok(())
     }
 
-    async fn find_payment_method_all(&self, user_id: &str) -> Result<Vec<PaymentMethodDTO>, ApplicationError> {
+    async fn find_payment_method_all(
+        &self,
+        user_id: &str,
+        order: Option<ListOrder>,
+    ) -> Result<Vec<PaymentMethodDTO>, ApplicationError> {
         let user_id = UserId::from_str(user_id)?;
-        let v = self.repository.find_all(&user_id).await?;
-        let result = v.iter().map(|item| PaymentMethodDTO::map_to_dto(item)).collect();
+        let mut v = self.repository.find_all(&user_id).await?;
+        if let Some(order) = order {
+            order.sort(&mut v, |p| (*p.created_at(), *p.updated_at()));
+        }
+        let result = v.iter().map(PaymentMethodDTO::map_to_dto).collect();
         Ok(result)
     }
 
@@ -47,35 +71,85 @@ impl<T: PaymentRepository> PaymentMethodService for PaymentMethodServiceImpl<T>
     }
 
     async fn update_payment_method(&self, payment: PaymentMethodDTO) -> Result<(), ApplicationError> {
-        let payment_method = PaymentMethodDTO::map_to_domain_model(payment)?;
+        let mut payment_method = PaymentMethodDTO::map_to_domain_model(payment)?;
+        payment_method.mark_updated(Utc::now());
         let exist = self.repository.exists(payment_method.payment_method_id(), payment_method.user_id()).await?;
 
         PaymentMethod::exists(exist)?;
         self.repository.update(&payment_method).await?;
+        let kind =
+            DomainEventKind::PaymentMethodUpdated { payment_method_id: payment_method.payment_method_id().clone() };
+        self.events.publish(payment_method.user_id(), kind).await;
         Ok(())
     }
 
     async fn delete_payment_method(&self, payment_id: &str, user_id: &str) -> Result<(), ApplicationError> {
-        let payment_id = PaymentMethodId::from_str(&payment_id)?;
-        let user_id = UserId::from_str(&user_id)?;
+        let payment_id = PaymentMethodId::from_str(payment_id)?;
+        let user_id = UserId::from_str(user_id)?;
 
         let exist = self.repository.exists(&payment_id, &user_id).await?;
 
         PaymentMethod::exists(exist)?;
+
+        // サブスクから参照されている支払方法は削除しない
+        let subscribes = self.subscribe_repository.find_all(&user_id).await?;
+        let in_use = subscribes.iter().filter(|s| s.payment_method_id() == &payment_id).count();
+        if in_use > 0 {
+            return Err(ApplicationError::Conflict(format!(
+                "payment method {} is used by {} subscriptions",
+                payment_id, in_use
+            )));
+        }
+
         self.repository.delete(&payment_id, &user_id).await?;
+        self.events.publish(&user_id, DomainEventKind::PaymentMethodDeleted { payment_method_id: payment_id }).await;
+        Ok(())
+    }
+
+    async fn find_deleted_payment_methods(&self, user_id: &str) -> Result<Vec<PaymentMethodDTO>, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let v = self.repository.find_deleted(&user_id).await?;
+        let result = v.iter().map(PaymentMethodDTO::map_to_dto).collect();
+        Ok(result)
+    }
+
+    async fn restore_payment_method(&self, payment_id: &str, user_id: &str) -> Result<(), ApplicationError> {
+        let payment_id = PaymentMethodId::from_str(payment_id)?;
+        let user_id = UserId::from_str(user_id)?;
+
+        self.repository.restore(&payment_id, &user_id).await?;
+        self.events.publish(&user_id, DomainEventKind::PaymentMethodRestored { payment_method_id: payment_id }).await;
         Ok(())
     }
+
+    async fn purge_deleted_payment_methods(&self, retention_days: u32) -> Result<usize, ApplicationError> {
+        let before = Utc::now() - chrono::Duration::days(i64::from(retention_days));
+        let mut count = 0;
+        for payment in self.repository.find_deleted_before(&before).await? {
+            self.repository.purge(payment.payment_method_id(), payment.user_id()).await?;
+            count += 1;
+        }
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
+    use chrono::DateTime;
+    use domain::category::category_id::CategoryId;
     use domain::payment::payment_error::PaymentError;
     use domain::payment::payment_method_name::{CreditCard, PaymentMethodCategoryName, PaymentMethodKindName};
     use domain::payment::PaymentMethod;
+    use domain::payment_cycle::PaymentCycle;
+    use domain::subscribe::{
+        contract_term::ContractTerm, cost_split::CostSplit, subscribe_error::SubscribeError, subscribe_id::SubscribeId,
+        subscribe_name::SubscribeName, subscribe_status::SubscribeStatus, tag::TagSet, Subscribe,
+    };
+    use domain::value_object::{amount::Amount, currency::Currency, money::Money};
     use domain::AggregateId;
     use mockall::mock;
+    use rust_decimal::Decimal;
 
     mock! {
       PaymentRepository {}
@@ -87,9 +161,54 @@ mod tests {
         async fn update(&self, payment: &PaymentMethod) -> Result<(), PaymentError>;
         async fn delete(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
         async fn exists(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<bool, PaymentError>;
+        async fn restore(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
+        async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<PaymentMethod>, PaymentError>;
+        async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<PaymentMethod>, PaymentError>;
+        async fn purge(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
+      }
+    }
+
+    mock! {
+      SubscribeRepository {}
+      #[async_trait::async_trait]
+      impl SubscribeRepository for SubscribeRepository {
+        async fn create(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+        async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+        async fn find_by_category(&self, category_id: &CategoryId, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+        async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
+        async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
+        async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
+        async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+        async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+        async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
       }
     }
 
+    fn create_mock_subscribe(user_id: UserId, payment_method_id: PaymentMethodId) -> Subscribe {
+        let now = Utc::now();
+        Subscribe::new(
+            user_id,
+            SubscribeName::new("hoge").unwrap(),
+            payment_method_id,
+            Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now + chrono::Duration::days(30),
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
     fn create_mock_dto() -> PaymentMethodDTO {
         let dto = PaymentMethodDTO {
             payment_method_id: PaymentMethodId::new().value().to_string(),
@@ -99,12 +218,13 @@ mod tests {
             additional_name: "test_card".to_string(),
             created_at: Some(Utc::now()),
             updated_at: None,
+            deleted_at: None,
         };
         dto
     }
 
     fn create_mock_payment_domain() -> PaymentMethod {
-        let domain = PaymentMethod::new(
+        PaymentMethod::new(
             PaymentMethodId::new(),
             UserId::new(),
             PaymentMethodCategoryName::CreditCard,
@@ -112,8 +232,8 @@ mod tests {
             "hoge",
             Utc::now(),
             None,
-        );
-        domain
+            None,
+        )
     }
     #[tokio::test]
     async fn test_create_payment_method() {
@@ -123,12 +243,11 @@ mod tests {
 
         let dto = create_mock_dto();
 
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
 
         let result = payment_service.create_payment_method(dto).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), ())
     }
 
     #[tokio::test]
@@ -141,7 +260,7 @@ mod tests {
 
         let dto = create_mock_dto();
 
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
 
         let result = payment_service.create_payment_method(dto).await;
 
@@ -158,20 +277,45 @@ mod tests {
         mock_repository
             .expect_find_all()
             .return_once(move |_| {
-                let mut vec: Vec<PaymentMethod> = vec![];
-                vec.push(create_mock_payment_domain());
+                let vec: Vec<PaymentMethod> = vec![create_mock_payment_domain()];
                 Ok(vec)
             })
             .times(1);
 
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
         let user_id = UserId::new();
-        let result = payment_service.find_payment_method_all(user_id.value()).await;
+        let result = payment_service.find_payment_method_all(user_id.value(), None).await;
 
         assert!(result.is_ok());
         assert_eq!(result.unwrap().len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_find_payment_method_all_sorted_by_newest() {
+        let mut mock_repository = MockPaymentRepository::new();
+        let now = Utc::now();
+        let mut older = create_mock_payment_domain();
+        older.mark_created(now - chrono::Duration::days(2));
+        let mut newer = create_mock_payment_domain();
+        newer.mark_created(now - chrono::Duration::days(1));
+        let expected = newer.payment_method_id().value().to_string();
+        mock_repository
+            .expect_find_all()
+            .return_once(move |_| {
+                Ok(vec![
+                    older, newer,
+                ])
+            })
+            .times(1);
+
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
+        let user_id = UserId::new();
+        let result = payment_service.find_payment_method_all(user_id.value(), Some(ListOrder::Newest)).await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].payment_method_id, expected);
+    }
+
     #[tokio::test]
     async fn test_find_payment_method_all_failed() {
         let mut mock_repository = MockPaymentRepository::new();
@@ -180,9 +324,9 @@ mod tests {
             .return_once(move |_| Err(PaymentError::QueryError("hoge".to_string())))
             .times(1);
 
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
         let user_id = UserId::new();
-        let result = payment_service.find_payment_method_all(user_id.value()).await;
+        let result = payment_service.find_payment_method_all(user_id.value(), None).await;
 
         assert!(result.is_err());
         assert_eq!(
@@ -202,7 +346,7 @@ mod tests {
             })
             .times(1);
 
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
         let user_id = UserId::new();
         let payment_id = PaymentMethodId::new();
 
@@ -218,7 +362,7 @@ mod tests {
             .return_once(move |_, _| Err(PaymentError::FindByIdError("hoge".to_string())))
             .times(1);
 
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
         let user_id = UserId::new();
         let payment_id = PaymentMethodId::new();
 
@@ -239,11 +383,10 @@ mod tests {
         mock_repository.expect_exists().return_once(move |_, _| Ok(true)).times(1);
 
         let dto = create_mock_dto();
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
         let result = payment_service.update_payment_method(dto).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), ());
     }
 
     #[tokio::test]
@@ -257,7 +400,7 @@ mod tests {
         mock_repository.expect_exists().return_once(move |_, _| Ok(true));
 
         let dto = create_mock_dto();
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
         let result = payment_service.update_payment_method(dto).await;
 
         assert!(result.is_err());
@@ -273,14 +416,43 @@ mod tests {
         mock_repository.expect_delete().return_once(move |_, _| Ok(())).times(1);
 
         mock_repository.expect_exists().return_once(move |_, _| Ok(true)).times(1);
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_all().return_once(move |_| Ok(vec![])).times(1);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, mock_subscribe_repository);
         let user_id = UserId::new();
         let payment_id = PaymentMethodId::new();
 
         let result = payment_service.delete_payment_method(payment_id.value(), user_id.value()).await;
 
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), ());
+    }
+
+    #[tokio::test]
+    async fn test_restore_payment_method() {
+        let mut mock_repository = MockPaymentRepository::new();
+        mock_repository.expect_restore().return_once(move |_, _| Ok(())).times(1);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
+        let user_id = UserId::new();
+        let payment_id = PaymentMethodId::new();
+
+        let result = payment_service.restore_payment_method(payment_id.value(), user_id.value()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_purge_deleted_payment_methods() {
+        let mut mock_repository = MockPaymentRepository::new();
+        mock_repository
+            .expect_find_deleted_before()
+            .return_once(move |_| Ok(vec![create_mock_payment_domain()]))
+            .times(1);
+        mock_repository.expect_purge().return_once(move |_, _| Ok(())).times(1);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, MockSubscribeRepository::new());
+
+        let result = payment_service.purge_deleted_payment_methods(30).await;
+
+        assert_eq!(result.unwrap(), 1);
     }
 
     #[tokio::test]
@@ -292,7 +464,9 @@ mod tests {
             .times(1);
 
         mock_repository.expect_exists().return_once(move |_, _| Ok(true)).times(1);
-        let payment_service = PaymentMethodServiceImpl::new(mock_repository);
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_all().return_once(move |_| Ok(vec![])).times(1);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, mock_subscribe_repository);
         let user_id = UserId::new();
         let payment_id = PaymentMethodId::new();
 
@@ -304,4 +478,28 @@ mod tests {
             ApplicationError::PaymentMethodError("Failed to delete payment method: hoge".to_string())
         );
     }
+
+    #[tokio::test]
+    async fn test_delete_payment_method_in_use() {
+        let user_id = UserId::new();
+        let payment_id = PaymentMethodId::new();
+        let mut mock_repository = MockPaymentRepository::new();
+        mock_repository.expect_exists().return_once(move |_, _| Ok(true)).times(1);
+        mock_repository.expect_delete().times(0);
+
+        let subscribes = vec![
+            create_mock_subscribe(user_id.clone(), payment_id.clone()),
+            create_mock_subscribe(user_id.clone(), PaymentMethodId::new()),
+        ];
+        let mut mock_subscribe_repository = MockSubscribeRepository::new();
+        mock_subscribe_repository.expect_find_all().return_once(move |_| Ok(subscribes)).times(1);
+        let payment_service = PaymentMethodServiceImpl::new(mock_repository, mock_subscribe_repository);
+
+        let result = payment_service.delete_payment_method(payment_id.value(), user_id.value()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ApplicationError::Conflict(format!("payment method {} is used by 1 subscriptions", payment_id))
+        );
+    }
 }
@@ -1,38 +1,169 @@
 use std::str::FromStr;
 
+use chrono::Utc;
+use domain::event::{DomainEvent, DomainEventKind};
+use domain::payment::PaymentMethod;
+use domain::repository::{
+    category_repository::CategoryRepository, payment_repository::PaymentRepository,
+    subscribe_repository::SubscribeRepository, user_repository::UserRepository,
+};
+use domain::subscribe::{subscribe_error::SubscribeError, subscribe_status::SubscribeStatus, tag::Tag, Subscribe};
+use domain::user::user_error::UserError;
+use domain::value_object::currency::Currency;
+
 use crate::{
-    dtos::{self, DTO},
+    dtos::{
+        self,
+        list_order::ListOrder,
+        price_change_dto::{PriceChangeDto, PriceIncreaseDto},
+        tag_dto::TagCountDto,
+        DTO,
+    },
     error::ApplicationError,
+    event::{EventEmitter, PublishEvents},
 };
 
-pub struct SubscribeServiceImpl<T: domain::repository::subscribe_repository::SubscribeRepository> {
+pub struct SubscribeServiceImpl<T: SubscribeRepository, C: CategoryRepository, P: PaymentRepository, U: UserRepository>
+{
     repository: T,
+    category_repository: C,
+    payment_repository: P,
+    user_repository: U,
+    events: EventEmitter,
+}
+
+impl<T: SubscribeRepository, C: CategoryRepository, P: PaymentRepository, U: UserRepository> PublishEvents
+    for SubscribeServiceImpl<T, C, P, U>
+{
+    fn emitter_mut(&mut self) -> &mut EventEmitter {
+        &mut self.events
+    }
 }
 
-impl<T: domain::repository::subscribe_repository::SubscribeRepository> SubscribeServiceImpl<T> {
-    pub fn new(repository: T) -> SubscribeServiceImpl<T> {
-        Self { repository }
+impl<T: SubscribeRepository, C: CategoryRepository, P: PaymentRepository, U: UserRepository>
+    SubscribeServiceImpl<T, C, P, U>
+{
+    pub fn new(
+        repository: T,
+        category_repository: C,
+        payment_repository: P,
+        user_repository: U,
+    ) -> SubscribeServiceImpl<T, C, P, U> {
+        Self { repository, category_repository, payment_repository, user_repository, events: EventEmitter::default() }
+    }
+
+    /// サブスクが参照するカテゴリと支払方法が同じユーザーに存在するか検証する
+    ///
+    /// # 引数
+    /// * `subscribe` - 検証するサブスク
+    ///
+    /// # エラー
+    /// - [ApplicationError::CategoryError] カテゴリが存在しない場合
+    /// - [ApplicationError::PaymentMethodError] 支払方法が存在しない場合
+    async fn verify_references(&self, subscribe: &Subscribe) -> Result<(), ApplicationError> {
+        self.category_repository.find_by_id(subscribe.category_id(), subscribe.user_id()).await?;
+        let exists = self.payment_repository.exists(subscribe.payment_method_id(), subscribe.user_id()).await?;
+        PaymentMethod::exists(exists)?;
+        Ok(())
+    }
+
+    /// 通貨が未指定のサブスクにユーザーの既定の通貨を設定する
+    ///
+    /// ユーザーが登録されていない場合は [Currency::default] を設定する
+    ///
+    /// # 引数
+    /// * `subscribe` - 登録するサブスク
+    ///
+    /// # 戻り値
+    /// - [dtos::subscribe_dto::SubscribeDto] 通貨を補完したサブスク
+    ///
+    /// # エラー
+    /// - [ApplicationError::UserError] ユーザーの取得に失敗した場合
+    async fn fill_default_currency(
+        &self,
+        subscribe: dtos::subscribe_dto::SubscribeDto,
+    ) -> Result<dtos::subscribe_dto::SubscribeDto, ApplicationError> {
+        if !subscribe.currency().is_empty() {
+            return Ok(subscribe);
+        }
+        let user_id = domain::user::user_id::UserId::from_str(subscribe.user_id())?;
+        let currency = match self.user_repository.find_by_id(&user_id).await {
+            Ok(user) => user.default_currency(),
+            Err(UserError::NotExists) => Currency::default(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(subscribe.with_currency(currency.to_string()))
+    }
+
+    /// サブスクのステータスを変更して保存する
+    ///
+    /// # 引数
+    /// * `user_id` - ユーザーID
+    /// * `subscribe_id` - サブスクID
+    /// * `f` - ステータス遷移を行う [Subscribe] のメソッド
+    async fn change_status(
+        &self,
+        user_id: &str,
+        subscribe_id: &str,
+        f: fn(&mut Subscribe) -> Result<(), SubscribeError>,
+    ) -> Result<(), ApplicationError> {
+        let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+        let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
+        let mut subscribe = self.repository.find_by_id(&subscribe_id, &user_id).await?;
+        f(&mut subscribe)?;
+        subscribe.mark_updated(Utc::now());
+        self.repository.update(&subscribe).await?;
+        self.events.publish_all(subscribe.take_events()).await;
+        Ok(())
+    }
+
+    /// 支払予定日を過ぎたACTIVEのサブスクの次回支払予定日を繰り越して保存する
+    ///
+    /// # 引数
+    /// * `subscribes` - 対象のサブスク
+    ///
+    /// # 戻り値
+    /// - [usize] 更新したサブスクの件数
+    async fn roll_forward(&self, subscribes: Vec<Subscribe>) -> Result<usize, ApplicationError> {
+        let now = Utc::now();
+        let mut count = 0;
+        for mut subscribe in subscribes {
+            if subscribe.roll_forward_next_payment_date(&now) {
+                subscribe.mark_updated(now);
+                self.repository.update(&subscribe).await?;
+                count += 1;
+            }
+        }
+        Ok(count)
     }
 }
 
-impl<T: domain::repository::subscribe_repository::SubscribeRepository> crate::service::SubscribeService
-    for SubscribeServiceImpl<T>
+impl<T: SubscribeRepository, C: CategoryRepository, P: PaymentRepository, U: UserRepository>
+    crate::service::SubscribeService for SubscribeServiceImpl<T, C, P, U>
 {
     fn create_subscribe(
         &self,
         subscribe: crate::dtos::subscribe_dto::SubscribeDto,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
-        let result = Box::pin(async move {
-            let subscribe = crate::dtos::subscribe_dto::SubscribeDto::map_to_domain_model(subscribe)?;
-            let result = self.repository.create(&subscribe).await?;
-            Ok(result)
-        });
-        result
+        Box::pin(async move {
+            let now = Utc::now();
+            let subscribe = self.fill_default_currency(subscribe).await?;
+            let mut subscribe = crate::dtos::subscribe_dto::SubscribeDto::map_to_domain_model(subscribe)?;
+            self.verify_references(&subscribe).await?;
+            subscribe.refresh_next_payment_date(&now);
+            subscribe.mark_created(now);
+            self.repository.create(&subscribe).await?;
+            let kind = DomainEventKind::SubscribeCreated { subscribe_id: subscribe.subscribe_id().clone() };
+            self.events.publish(subscribe.user_id(), kind).await;
+            Ok(())
+        })
     }
 
     fn find_subscribe_all<'a>(
         &'a self,
         user_id: &'a str,
+        tag: Option<&'a str>,
+        order: Option<ListOrder>,
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
@@ -40,15 +171,23 @@ impl<T: domain::repository::subscribe_repository::SubscribeRepository> crate::se
                 + '_,
         >,
     > {
-        let result = Box::pin(async move {
+        Box::pin(async move {
             let user_id = domain::user::user_id::UserId::from_str(user_id)?;
-            let v = self.repository.find_all(&user_id).await?;
-            let result =
-                v.into_iter().map(|item| crate::dtos::subscribe_dto::SubscribeDto::map_to_dto(&item)).collect();
+            let tag = tag.map(Tag::from_str).transpose().map_err(SubscribeError::from)?;
+            let mut v: Vec<Subscribe> = self
+                .repository
+                .find_all(&user_id)
+                .await?
+                .into_iter()
+                .filter(|item| tag.as_ref().map_or(true, |t| item.has_tag(t)))
+                .collect();
+            if let Some(order) = order {
+                order.sort(&mut v, |s| (*s.created_at(), *s.updated_at()));
+            }
+            let result = v.iter().map(crate::dtos::subscribe_dto::SubscribeDto::map_to_dto).collect();
 
             Ok(result)
-        });
-        result
+        })
     }
 
     fn find_subscribe_by_id<'a>(
@@ -62,27 +201,46 @@ impl<T: domain::repository::subscribe_repository::SubscribeRepository> crate::se
                 + '_,
         >,
     > {
-        let result = Box::pin(async move {
+        Box::pin(async move {
             let user_id = domain::user::user_id::UserId::from_str(user_id)?;
             let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
             let v = self.repository.find_by_id(&subscribe_id, &user_id).await?;
             let result = crate::dtos::subscribe_dto::SubscribeDto::map_to_dto(&v);
 
             Ok(result)
-        });
-        result
+        })
     }
 
     fn update_subscribe(
         &self,
         subscribe: crate::dtos::subscribe_dto::SubscribeDto,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
-        let result = Box::pin(async move {
-            let subscribe = dtos::subscribe_dto::SubscribeDto::map_to_domain_model(subscribe)?;
-            let result = self.repository.update(&subscribe).await?;
-            Ok(result)
-        });
-        result
+        Box::pin(async move {
+            let now = Utc::now();
+            let user_id = domain::user::user_id::UserId::from_str(subscribe.user_id())?;
+            let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe.subscribe_id())?;
+            let current = self.repository.find_by_id(&subscribe_id, &user_id).await?;
+            // 通貨が未指定の場合は現在の通貨のまま更新する
+            let subscribe = match subscribe.currency().is_empty() {
+                true => subscribe.with_currency(current.currency().to_string()),
+                false => subscribe,
+            };
+            let mut subscribe = dtos::subscribe_dto::SubscribeDto::map_to_domain_model(subscribe)?;
+            current.status().validate_transition(subscribe.status())?;
+            self.verify_references(&subscribe).await?;
+            subscribe.inherit_price_history(&current, now);
+            subscribe.refresh_next_payment_date(&now);
+            subscribe.inherit_billing_stop(&current);
+            subscribe.mark_created(*current.created_at());
+            subscribe.mark_updated(now);
+            self.repository.update(&subscribe).await?;
+            subscribe.track_status_change(&current);
+            let kind = DomainEventKind::SubscribeUpdated { subscribe_id: subscribe.subscribe_id().clone() };
+            let mut events = vec![DomainEvent::new(subscribe.user_id().clone(), kind)];
+            events.extend(subscribe.take_events());
+            self.events.publish_all(events).await;
+            Ok(())
+        })
     }
 
     fn delete_subscribe<'a>(
@@ -90,35 +248,402 @@ impl<T: domain::repository::subscribe_repository::SubscribeRepository> crate::se
         user_id: &'a str,
         subscribe_id: &'a str,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
-        let result = Box::pin(async move {
+        Box::pin(async move {
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
+            self.repository.delete(&subscribe_id, &user_id).await?;
+            let kind = DomainEventKind::SubscribeDeleted { subscribe_id };
+            self.events.publish(&user_id, kind).await;
+
+            Ok(())
+        })
+    }
+
+    fn pause_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(self.change_status(user_id, subscribe_id, Subscribe::pause))
+    }
+
+    fn resume_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(self.change_status(user_id, subscribe_id, Subscribe::resume))
+    }
+
+    fn cancel_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(self.change_status(user_id, subscribe_id, Subscribe::cancel))
+    }
+
+    fn reactivate_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(self.change_status(user_id, subscribe_id, Subscribe::reactivate))
+    }
+
+    fn roll_forward_payment_date<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let v = self.repository.find_all(&user_id).await?;
+            self.roll_forward(v).await
+        })
+    }
+
+    fn roll_forward_payment_date_all(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let v = self.repository.find_by_status(&SubscribeStatus::ACTIVE).await?;
+            self.roll_forward(v).await
+        })
+    }
+
+    fn find_trials_ending_within<'a>(
+        &'a self,
+        user_id: &'a str,
+        days: u32,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let now = Utc::now();
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let mut v: Vec<Subscribe> = self
+                .repository
+                .find_all(&user_id)
+                .await?
+                .into_iter()
+                .filter(|s| s.is_trial_ending_within(&now, days))
+                .collect();
+            v.sort_by_key(|s| s.trial().as_ref().map(|t| *t.end_date()));
+            let result = v.iter().map(crate::dtos::subscribe_dto::SubscribeDto::map_to_dto).collect();
+
+            Ok(result)
+        })
+    }
+
+    fn find_cancellation_windows_closing<'a>(
+        &'a self,
+        user_id: &'a str,
+        days: u32,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let now = Utc::now();
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let mut v: Vec<Subscribe> = self
+                .repository
+                .find_all(&user_id)
+                .await?
+                .into_iter()
+                .filter(|s| s.is_cancellation_window_closing(&now, days))
+                .collect();
+            v.sort_by_key(|s| s.last_cancellation_date(&now));
+            let result = v.iter().map(crate::dtos::subscribe_dto::SubscribeDto::map_to_dto).collect();
+
+            Ok(result)
+        })
+    }
+
+    fn convert_expired_trials_all(
+        &self,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let now = Utc::now();
+            let mut count = 0;
+            for mut subscribe in self.repository.find_by_status(&SubscribeStatus::TRIAL).await? {
+                if subscribe.convert_trial(&now) {
+                    subscribe.mark_updated(now);
+                    self.repository.update(&subscribe).await?;
+                    self.events.publish_all(subscribe.take_events()).await;
+                    count += 1;
+                }
+            }
+            Ok(count)
+        })
+    }
+
+    fn find_price_history<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<crate::dtos::price_change_dto::PriceChangeDto>, ApplicationError>,
+                > + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
             let user_id = domain::user::user_id::UserId::from_str(user_id)?;
             let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
-            let result = self.repository.delete(&subscribe_id, &user_id).await?;
+            let v = self.repository.find_by_id(&subscribe_id, &user_id).await?;
+            let result = v.price_history().iter().map(PriceChangeDto::map_to_dto).collect();
 
             Ok(result)
-        });
-        result
+        })
+    }
+
+    fn find_recent_price_increases<'a>(
+        &'a self,
+        user_id: &'a str,
+        days: u32,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                    Output = Result<Vec<crate::dtos::price_change_dto::PriceIncreaseDto>, ApplicationError>,
+                > + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let since = Utc::now() - chrono::Duration::days(i64::from(days));
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let v = self.repository.find_all(&user_id).await?;
+            let mut result: Vec<PriceIncreaseDto> = v
+                .iter()
+                .flat_map(|s| {
+                    s.price_increases_since(&since)
+                        .into_iter()
+                        .map(|(previous, current)| PriceIncreaseDto::new(s, previous, current))
+                })
+                .collect();
+            result.sort_by(|a, b| b.effective_date().cmp(a.effective_date()));
+
+            Ok(result)
+        })
+    }
+
+    fn find_cost_split<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<crate::dtos::cost_split_dto::CostSplitDto, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
+            let v = self.repository.find_by_id(&subscribe_id, &user_id).await?;
+
+            Ok(crate::dtos::cost_split_dto::CostSplitDto::map_to_dto(&v))
+        })
+    }
+
+    fn add_tag<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+        tag: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let tag = Tag::from_str(tag).map_err(SubscribeError::from)?;
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
+            let mut subscribe = self.repository.find_by_id(&subscribe_id, &user_id).await?;
+            if subscribe.add_tag(tag)? {
+                subscribe.mark_updated(Utc::now());
+                self.repository.update(&subscribe).await?;
+                self.events.publish_all(subscribe.take_events()).await;
+            }
+            Ok(())
+        })
+    }
+
+    fn remove_tag<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+        tag: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let tag = Tag::from_str(tag).map_err(SubscribeError::from)?;
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
+            let mut subscribe = self.repository.find_by_id(&subscribe_id, &user_id).await?;
+            if subscribe.remove_tag(&tag) {
+                subscribe.mark_updated(Utc::now());
+                self.repository.update(&subscribe).await?;
+                self.events.publish_all(subscribe.take_events()).await;
+            }
+            Ok(())
+        })
+    }
+
+    fn find_tag_counts<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::tag_dto::TagCountDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let v = self.repository.find_all(&user_id).await?;
+            let mut counts: std::collections::BTreeMap<String, usize> = std::collections::BTreeMap::new();
+            for tag in v.iter().flat_map(|s| s.tags().iter()) {
+                *counts.entry(tag.to_string()).or_default() += 1;
+            }
+            let mut result: Vec<TagCountDto> =
+                counts.into_iter().map(|(tag, count)| TagCountDto::new(tag, count)).collect();
+            // 件数の多い順、同数の場合はタグの辞書順
+            result.sort_by_key(|t| std::cmp::Reverse(t.count()));
+
+            Ok(result)
+        })
+    }
+
+    fn find_deleted_subscribes<'a>(
+        &'a self,
+        user_id: &'a str,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = Result<Vec<crate::dtos::subscribe_dto::SubscribeDto>, ApplicationError>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let v = self.repository.find_deleted(&user_id).await?;
+            let result = v.iter().map(dtos::subscribe_dto::SubscribeDto::map_to_dto).collect();
+            Ok(result)
+        })
+    }
+
+    fn restore_subscribe<'a>(
+        &'a self,
+        user_id: &'a str,
+        subscribe_id: &'a str,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let user_id = domain::user::user_id::UserId::from_str(user_id)?;
+            let subscribe_id = domain::subscribe::subscribe_id::SubscribeId::from_str(subscribe_id)?;
+            let subscribe = self
+                .repository
+                .find_deleted(&user_id)
+                .await?
+                .into_iter()
+                .find(|s| s.subscribe_id() == &subscribe_id)
+                .ok_or(SubscribeError::NotExists)?;
+
+            // ゴミ箱にある間に参照先が削除されていた場合は復元しない
+            match self.verify_references(&subscribe).await {
+                Err(ApplicationError::CategoryError(e)) | Err(ApplicationError::PaymentMethodError(e)) => {
+                    return Err(ApplicationError::Conflict(format!(
+                        "subscribe {} cannot be restored: {}",
+                        subscribe_id, e
+                    )));
+                }
+                result => result?,
+            }
+
+            self.repository.restore(&subscribe_id, &user_id).await?;
+            let kind = DomainEventKind::SubscribeRestored { subscribe_id };
+            self.events.publish(&user_id, kind).await;
+
+            Ok(())
+        })
+    }
+
+    fn purge_deleted_subscribes(
+        &self,
+        retention_days: u32,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, ApplicationError>> + Send + '_>> {
+        Box::pin(async move {
+            let before = Utc::now() - chrono::Duration::days(i64::from(retention_days));
+            let mut count = 0;
+            for subscribe in self.repository.find_deleted_before(&before).await? {
+                self.repository.purge(subscribe.subscribe_id(), subscribe.user_id()).await?;
+                count += 1;
+            }
+            Ok(count)
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::dtos::list_order::ListOrder;
     use crate::dtos::subscribe_dto::SubscribeDto;
+    use crate::dtos::DTO;
     use crate::error::ApplicationError;
+    use crate::event::PublishEvents;
+    use crate::service::subscribe_service::SubscribeServiceImpl;
     use crate::service::SubscribeService;
-    use chrono::Utc;
+    use chrono::{DateTime, Utc};
+    use domain::category::category_error::CategoryError;
     use domain::category::category_id::CategoryId;
+    use domain::category::category_name::CategoryName;
+    use domain::category::Category;
+    use domain::event::{event_publisher::EventPublisher, DomainEvent, DomainEventKind};
+    use domain::payment::payment_error::PaymentError;
     use domain::payment::payment_method_id::PaymentMethodId;
+    use domain::payment::PaymentMethod;
     use domain::payment_cycle::PaymentCycle;
-    use domain::repository::subscribe_repository::SubscribeRepository;
+    use domain::repository::{
+        category_repository::CategoryRepository, payment_repository::PaymentRepository,
+        subscribe_repository::SubscribeRepository, user_repository::UserRepository,
+    };
     use domain::subscribe::{
-        subscribe_error::SubscribeError, subscribe_id::SubscribeId, subscribe_name::SubscribeName,
-        subscribe_status::SubscribeStatus, Subscribe,
+        contract_term::ContractTerm,
+        cost_split::{CostSplit, MemberShare, Share, SplitMember},
+        subscribe_error::SubscribeError,
+        subscribe_id::SubscribeId,
+        subscribe_name::SubscribeName,
+        subscribe_status::SubscribeStatus,
+        tag::{Tag, TagSet},
+        trial::Trial,
+        Subscribe,
+    };
+    use domain::user::{
+        user_error::UserError, user_id::UserId, user_profile::UserProfile, user_settings::UserSettings, User,
     };
-    use domain::user::user_id::UserId;
-    use domain::value_object::amount::Amount;
+    use domain::value_object::{amount::Amount, currency::Currency, money::Money};
     use mockall::mock;
     use rust_decimal::Decimal;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    mock! {
+        EventPublisher {}
+        #[async_trait::async_trait]
+        impl EventPublisher for EventPublisher {
+            async fn publish(&self, events: Vec<DomainEvent>);
+        }
+    }
 
     mock! {
         SubscribeRepository {}
@@ -126,12 +651,95 @@ mod tests {
         impl SubscribeRepository for SubscribeRepository {
             async fn create(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
             async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_by_category(&self, category_id: &CategoryId, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
             async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
+            async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
             async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
             async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+            async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+            async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+        }
+    }
+
+    mock! {
+        CategoryRepository {}
+        impl CategoryRepository for CategoryRepository {
+            fn create<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_all<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_by_id<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Category, CategoryError>> + Send + 'static>>;
+            fn update<'a>(&'a self, category: &'a Category) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn reorder<'a>(&'a self, categories: &'a [Category]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn delete_with_reassignment<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId, subscribes: &'a [Subscribe]) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn restore<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+            fn find_deleted<'a>(&'a self, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn find_deleted_before<'a>(&'a self, before: &'a DateTime<Utc>) -> Pin<Box<dyn Future<Output = Result<Vec<Category>, CategoryError>> + Send + 'static>>;
+            fn purge<'a>(&'a self, category_id: &'a CategoryId, user_id: &'a UserId) -> Pin<Box<dyn Future<Output = Result<(), CategoryError>> + Send + 'static>>;
+        }
+    }
+
+    mock! {
+        PaymentRepository {}
+        #[async_trait::async_trait]
+        impl PaymentRepository for PaymentRepository {
+            async fn create(&self, payment: &PaymentMethod) -> Result<(), PaymentError>;
+            async fn find_all(&self, user_id: &UserId) -> Result<Vec<PaymentMethod>, PaymentError>;
+            async fn find_by_id(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<PaymentMethod, PaymentError>;
+            async fn update(&self, payment: &PaymentMethod) -> Result<(), PaymentError>;
+            async fn delete(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
+            async fn exists(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<bool, PaymentError>;
+            async fn restore(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
+            async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<PaymentMethod>, PaymentError>;
+            async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<PaymentMethod>, PaymentError>;
+            async fn purge(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
         }
     }
 
+    mock! {
+        UserRepository {}
+        #[async_trait::async_trait]
+        impl UserRepository for UserRepository {
+            async fn create(&self, user: &User) -> Result<(), UserError>;
+            async fn find_by_id(&self, user_id: &UserId) -> Result<User, UserError>;
+            async fn update(&self, user: &User) -> Result<(), UserError>;
+            async fn delete(&self, user_id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    type TestService = SubscribeServiceImpl<
+        MockSubscribeRepository,
+        MockCategoryRepository,
+        MockPaymentRepository,
+        MockUserRepository,
+    >;
+
+    /// 参照先のカテゴリと支払方法の存在有無を指定してサービスを作成する
+    fn create_service_with_references(
+        mock_repository: MockSubscribeRepository,
+        category_exists: bool,
+        payment_exists: bool,
+    ) -> TestService {
+        let mut category_repository = MockCategoryRepository::new();
+        category_repository.expect_find_by_id().returning(move |_, user_id| {
+            let user_id = user_id.clone();
+            Box::pin(async move {
+                match category_exists {
+                    true => Ok(Category::new(user_id, CategoryName::from_str("動画配信").unwrap())),
+                    false => Err(CategoryError::FindByIdError("not found".to_string())),
+                }
+            })
+        });
+        let mut payment_repository = MockPaymentRepository::new();
+        payment_repository.expect_exists().returning(move |_, _| Ok(payment_exists));
+        SubscribeServiceImpl::new(mock_repository, category_repository, payment_repository, MockUserRepository::new())
+    }
+
+    fn create_service(mock_repository: MockSubscribeRepository) -> TestService {
+        create_service_with_references(mock_repository, true, true)
+    }
+
     fn create_mock_dto() -> SubscribeDto {
         let now = Utc::now();
         SubscribeDto::new(
@@ -140,6 +748,7 @@ mod tests {
             "Netflix".to_string(),
             PaymentMethodId::new().to_string(),
             "1980".to_string(),
+            "JPY".to_string(),
             "MONTHLY".to_string(),
             CategoryId::new().to_string(),
             "/path/to/netflix-icon.png".to_string(),
@@ -149,69 +758,286 @@ mod tests {
             true,
             "ACTIVE".to_string(),
             Some("Test subscription".to_string()),
+            None,
+            None,
+            None,
+            None,
+            vec![],
+            vec![],
         )
     }
 
+    fn create_mock_tagged_domain(tags: &[&str]) -> Subscribe {
+        let mut subscribe = create_mock_domain();
+        for tag in tags {
+            subscribe.add_tag(Tag::new(tag).unwrap()).unwrap();
+        }
+        // 保存済みのサブスクとして扱うため、記録したイベントは捨てる
+        subscribe.take_events();
+        subscribe
+    }
+
     #[tokio::test]
-    async fn test_find_all_subscriptions_success() {
+    async fn test_create_subscribe_success() {
         let mut mock_repository = MockSubscribeRepository::new();
-        let user_id = UserId::new();
-        let subscriptions = vec![create_mock_domain()];
-
-        mock_repository
-            .expect_find_all()
-            .with(mockall::predicate::eq(user_id.clone()))
-            .return_once(move |_| Ok(subscriptions))
-            .times(1);
+        mock_repository.expect_create().return_once(move |_| Ok(())).times(1);
 
-        let subscribe_service = crate::service::subscribe_service::SubscribeServiceImpl::new(mock_repository);
-        let result = subscribe_service.find_subscribe_all(&user_id.to_string()).await;
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.create_subscribe(create_mock_dto()).await;
 
         assert!(result.is_ok());
-        let subscriptions = result.unwrap();
-        assert_eq!(subscriptions.len(), 1);
     }
 
     #[tokio::test]
-    async fn test_find_all_subscriptions_empty() {
+    async fn test_create_subscribe_uses_user_default_currency() {
+        let dto = create_mock_dto().with_currency(String::new());
+        let user_id = UserId::from_str(dto.user_id()).unwrap();
         let mut mock_repository = MockSubscribeRepository::new();
-        let user_id = UserId::new();
-
-        mock_repository
-            .expect_find_all()
-            .with(mockall::predicate::eq(user_id.clone()))
-            .return_once(move |_| Ok(vec![]))
-            .times(1);
+        mock_repository.expect_create().withf(|s| s.currency() == &Currency::USD).return_once(|_| Ok(())).times(1);
 
-        let subscribe_service = crate::service::subscribe_service::SubscribeServiceImpl::new(mock_repository);
-        let result = subscribe_service.find_subscribe_all(&user_id.to_string()).await;
+        let mut subscribe_service = create_service(mock_repository);
+        subscribe_service.user_repository.expect_find_by_id().return_once(move |_| {
+            let now = Utc::now();
+            let profile = UserProfile::new("John Smith", "john@example.com", None).unwrap();
+            Ok(User::from(user_id, profile, 2, UserSettings::default(), now, now))
+        });
+        let result = subscribe_service.create_subscribe(dto).await;
 
         assert!(result.is_ok());
-        let subscriptions = result.unwrap();
-        assert!(subscriptions.is_empty());
     }
 
     #[tokio::test]
-    async fn test_find_by_id_success() {
+    async fn test_create_subscribe_without_user_uses_default_currency() {
+        let dto = create_mock_dto().with_currency(String::new());
         let mut mock_repository = MockSubscribeRepository::new();
-        let subscribe_id = SubscribeId::new();
-        let user_id = UserId::new();
-        let subscribe = create_mock_domain();
+        mock_repository.expect_create().withf(|s| s.currency() == &Currency::JPY).return_once(|_| Ok(())).times(1);
 
-        mock_repository
-            .expect_find_by_id()
-            .with(mockall::predicate::eq(subscribe_id.clone()), mockall::predicate::eq(user_id.clone()))
-            .return_once(move |_, _| Ok(subscribe))
-            .times(1);
-
-        let subscribe_service = crate::service::subscribe_service::SubscribeServiceImpl::new(mock_repository);
-        let result = subscribe_service.find_subscribe_by_id(&user_id.to_string(), &subscribe_id.to_string()).await;
+        let mut subscribe_service = create_service(mock_repository);
+        subscribe_service.user_repository.expect_find_by_id().return_once(|_| Err(UserError::NotExists));
+        let result = subscribe_service.create_subscribe(dto).await;
 
         assert!(result.is_ok());
     }
 
     #[tokio::test]
-    async fn test_find_by_id_not_found() {
+    async fn test_create_subscribe_category_not_exists() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_create().times(0);
+
+        let subscribe_service = create_service_with_references(mock_repository, false, true);
+        let result = subscribe_service.create_subscribe(create_mock_dto()).await;
+
+        assert!(matches!(result.unwrap_err(), ApplicationError::CategoryError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_create_subscribe_payment_method_not_exists() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_create().times(0);
+
+        let subscribe_service = create_service_with_references(mock_repository, true, false);
+        let result = subscribe_service.create_subscribe(create_mock_dto()).await;
+
+        assert_eq!(result.unwrap_err(), ApplicationError::PaymentMethodError("Payment method not exist".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_find_all_subscriptions_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let subscriptions = vec![create_mock_domain()];
+
+        mock_repository
+            .expect_find_all()
+            .with(mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_| Ok(subscriptions))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_subscribe_all(&user_id.to_string(), None, None).await;
+
+        assert!(result.is_ok());
+        let subscriptions = result.unwrap();
+        assert_eq!(subscriptions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_all_subscriptions_sorted_by_recently_updated() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let now = Utc::now();
+        let mut untouched = create_mock_domain();
+        untouched.mark_created(now - chrono::Duration::days(1));
+        let mut updated = create_mock_domain();
+        updated.mark_created(now - chrono::Duration::days(10));
+        updated.mark_updated(now);
+
+        mock_repository
+            .expect_find_all()
+            .return_once(move |_| {
+                Ok(vec![
+                    untouched, updated,
+                ])
+            })
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service
+            .find_subscribe_all(&user_id.to_string(), None, Some(ListOrder::RecentlyUpdated))
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert!(result[0].updated_at().is_some());
+        assert!(result[1].updated_at().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_find_all_subscriptions_empty() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+
+        mock_repository
+            .expect_find_all()
+            .with(mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_| Ok(vec![]))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_subscribe_all(&user_id.to_string(), None, None).await;
+
+        assert!(result.is_ok());
+        let subscriptions = result.unwrap();
+        assert!(subscriptions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_all_subscriptions_filtered_by_tag() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let subscriptions = vec![
+            create_mock_tagged_domain(&[
+                "shared", "work",
+            ]),
+            create_mock_tagged_domain(&["work"]),
+            create_mock_tagged_domain(&[]),
+        ];
+
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscriptions)).times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_subscribe_all(&user_id.to_string(), Some("Shared"), None).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_find_all_subscriptions_invalid_tag() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+
+        mock_repository.expect_find_all().times(0);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_subscribe_all(&user_id.to_string(), Some("cancel soon"), None).await;
+
+        assert!(matches!(result, Err(ApplicationError::SubscribeError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_tag_updates_only_when_changed() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_tagged_domain(&["shared"]);
+        let user_id = subscribe.user_id().to_string();
+        let subscribe_id = subscribe.subscribe_id().to_string();
+
+        mock_repository.expect_find_by_id().returning(move |_, _| Ok(subscribe.clone())).times(2);
+        mock_repository
+            .expect_update()
+            .withf(|s| s.tags().len() == 2 && s.has_tag(&Tag::new("work").unwrap()))
+            .returning(|_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+
+        assert!(subscribe_service.add_tag(&user_id, &subscribe_id, "Work").await.is_ok());
+        assert!(subscribe_service.add_tag(&user_id, &subscribe_id, "shared").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_remove_tag_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_tagged_domain(&[
+            "shared", "work",
+        ]);
+        let user_id = subscribe.user_id().to_string();
+        let subscribe_id = subscribe.subscribe_id().to_string();
+
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s| s.tags().len() == 1 && !s.has_tag(&Tag::new("shared").unwrap()))
+            .returning(|_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.remove_tag(&user_id, &subscribe_id, "shared").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_find_tag_counts_sorted_by_count() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let subscriptions = vec![
+            create_mock_tagged_domain(&[
+                "shared", "work",
+            ]),
+            create_mock_tagged_domain(&[
+                "work",
+                "cancel-soon",
+            ]),
+            create_mock_tagged_domain(&["work"]),
+        ];
+
+        mock_repository.expect_find_all().return_once(move |_| Ok(subscriptions)).times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_tag_counts(&user_id.to_string()).await.unwrap();
+
+        let result: Vec<(&str, usize)> = result.iter().map(|t| (t.tag(), t.count())).collect();
+        assert_eq!(
+            result,
+            vec![
+                ("work", 3),
+                ("cancel-soon", 1),
+                ("shared", 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_by_id_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe_id = SubscribeId::new();
+        let user_id = UserId::new();
+        let subscribe = create_mock_domain();
+
+        mock_repository
+            .expect_find_by_id()
+            .with(mockall::predicate::eq(subscribe_id.clone()), mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_, _| Ok(subscribe))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_subscribe_by_id(&user_id.to_string(), &subscribe_id.to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_find_by_id_not_found() {
         let mut mock_repository = MockSubscribeRepository::new();
         let subscribe_id = SubscribeId::new();
         let user_id = UserId::new();
@@ -222,7 +1048,7 @@ mod tests {
             .return_once(move |_, _| Err(SubscribeError::FindByIdError("hoge".to_string())))
             .times(1);
 
-        let subscribe_service = crate::service::subscribe_service::SubscribeServiceImpl::new(mock_repository);
+        let subscribe_service = create_service(mock_repository);
         let result = subscribe_service.find_subscribe_by_id(&user_id.to_string(), &subscribe_id.to_string()).await;
 
         assert!(result.is_err());
@@ -244,37 +1070,745 @@ mod tests {
             .return_once(move |_, _| Ok(()))
             .times(1);
 
-        let subscribe_service = crate::service::subscribe_service::SubscribeServiceImpl::new(mock_repository);
+        let subscribe_service = create_service(mock_repository);
         let result = subscribe_service.delete_subscribe(&user_id.to_string(), &subscribe_id.to_string()).await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_restore_subscribe_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_domain();
+        let subscribe_id = subscribe.subscribe_id().clone();
+        let user_id = subscribe.user_id().clone();
+
+        mock_repository.expect_find_deleted().return_once(move |_| Ok(vec![subscribe])).times(1);
+        mock_repository
+            .expect_restore()
+            .with(mockall::predicate::eq(subscribe_id.clone()), mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_, _| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.restore_subscribe(&user_id.to_string(), &subscribe_id.to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_restore_subscribe_not_in_trash() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_deleted().return_once(move |_| Ok(vec![])).times(1);
+        mock_repository.expect_restore().times(0);
+
+        let subscribe_service = create_service(mock_repository);
+        let result =
+            subscribe_service.restore_subscribe(&UserId::new().to_string(), &SubscribeId::new().to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::SubscribeError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_restore_subscribe_category_deleted() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_domain();
+        let subscribe_id = subscribe.subscribe_id().clone();
+        let user_id = subscribe.user_id().clone();
+
+        mock_repository.expect_find_deleted().return_once(move |_| Ok(vec![subscribe])).times(1);
+        mock_repository.expect_restore().times(0);
+
+        let subscribe_service = create_service_with_references(mock_repository, false, true);
+        let result = subscribe_service.restore_subscribe(&user_id.to_string(), &subscribe_id.to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_restore_subscribe_payment_method_deleted() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_domain();
+        let subscribe_id = subscribe.subscribe_id().clone();
+        let user_id = subscribe.user_id().clone();
+
+        mock_repository.expect_find_deleted().return_once(move |_| Ok(vec![subscribe])).times(1);
+        mock_repository.expect_restore().times(0);
+
+        let subscribe_service = create_service_with_references(mock_repository, true, false);
+        let result = subscribe_service.restore_subscribe(&user_id.to_string(), &subscribe_id.to_string()).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_find_deleted_subscribes() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_deleted().return_once(move |_| Ok(vec![create_mock_domain()])).times(1);
+        mock_repository.expect_find_all().never();
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_deleted_subscribes(&UserId::new().to_string()).await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_deleted_subscribes() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let now = Utc::now();
+        mock_repository
+            .expect_find_deleted_before()
+            .withf(move |before: &DateTime<Utc>| {
+                let expected = now - chrono::Duration::days(30);
+                (*before - expected).num_seconds().abs() < 5
+            })
+            .return_once(move |_| {
+                Ok(vec![
+                    create_mock_domain(),
+                    create_mock_domain(),
+                ])
+            })
+            .times(1);
+        mock_repository.expect_purge().returning(|_, _| Ok(())).times(2);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.purge_deleted_subscribes(30).await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
     #[tokio::test]
     async fn test_update_subscribe_success() {
         let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(create_mock_domain())).times(1);
         mock_repository.expect_update().return_once(move |_| Ok(())).times(1);
 
-        let subscribe_service = crate::service::subscribe_service::SubscribeServiceImpl::new(mock_repository);
+        let subscribe_service = create_service(mock_repository);
         let dto = create_mock_dto(); // You might need to modify this to match the subscribe
         let result = subscribe_service.update_subscribe(dto).await;
 
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_update_subscribe_keeps_current_currency() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let mut current = create_mock_domain();
+        let usd = Money::new(Amount::from_str("9.99").unwrap(), Currency::USD).unwrap();
+        current.change_amount(usd, Utc::now() - chrono::Duration::days(30));
+        let dto = SubscribeDto::map_to_dto(&current).with_currency(String::new());
+
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(current)).times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| s.currency() == &Currency::USD && s.price_history().len() == 2)
+            .return_once(|_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.update_subscribe(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_paused_subscribe_keeps_billing_stop() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let now = Utc::now();
+        let stopped = now - chrono::Duration::days(90);
+        let current =
+            create_mock_domain_with_dates(SubscribeStatus::PAUSED, now - chrono::Duration::days(365), stopped);
+        let dto = SubscribeDto::map_to_dto(&current);
+
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(current)).times(1);
+        mock_repository
+            .expect_update()
+            .withf(move |s: &Subscribe| s.next_payment_date() == &stopped)
+            .return_once(|_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.update_subscribe(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_subscribe_invalid_status_transition() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository
+            .expect_find_by_id()
+            .return_once(move |_, _| Ok(create_mock_domain_with_status(SubscribeStatus::CANCELLED)))
+            .times(1);
+        mock_repository.expect_update().times(0);
+
+        let subscribe_service = create_service(mock_repository);
+        let dto = SubscribeDto::map_to_dto(&create_mock_domain_with_status(SubscribeStatus::PAUSED));
+        let result = subscribe_service.update_subscribe(dto).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ApplicationError::SubscribeError(
+                SubscribeError::InvalidStatusTransition("CANCELLED".to_string(), "PAUSED".to_string()).to_string()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pause_subscribe_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe_id = SubscribeId::new();
+        let user_id = UserId::new();
+
+        mock_repository
+            .expect_find_by_id()
+            .with(mockall::predicate::eq(subscribe_id.clone()), mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_, _| Ok(create_mock_domain()))
+            .times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| s.status() == &SubscribeStatus::PAUSED)
+            .return_once(move |_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.pause_subscribe(&user_id.to_string(), &subscribe_id.to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_resume_subscribe_failed_when_active() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(create_mock_domain())).times(1);
+        mock_repository.expect_update().times(0);
+
+        let subscribe_service = create_service(mock_repository);
+        let result =
+            subscribe_service.resume_subscribe(&UserId::new().to_string(), &SubscribeId::new().to_string()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            ApplicationError::SubscribeError(
+                SubscribeError::InvalidStatusTransition("ACTIVE".to_string(), "ACTIVE".to_string()).to_string()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cancel_subscribe_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository
+            .expect_find_by_id()
+            .return_once(move |_, _| Ok(create_mock_domain_with_status(SubscribeStatus::PAUSED)))
+            .times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| s.status() == &SubscribeStatus::CANCELLED)
+            .return_once(move |_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result =
+            subscribe_service.cancel_subscribe(&UserId::new().to_string(), &SubscribeId::new().to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_reactivate_subscribe_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository
+            .expect_find_by_id()
+            .return_once(move |_, _| Ok(create_mock_domain_with_status(SubscribeStatus::CANCELLED)))
+            .times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| s.status() == &SubscribeStatus::ACTIVE)
+            .return_once(move |_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result =
+            subscribe_service.reactivate_subscribe(&UserId::new().to_string(), &SubscribeId::new().to_string()).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_roll_forward_payment_date_updates_only_overdue_active() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let past = Utc::now() - chrono::Duration::days(70);
+
+        mock_repository
+            .expect_find_all()
+            .with(mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_| {
+                Ok(vec![
+                    create_mock_domain_with_dates(SubscribeStatus::ACTIVE, past, past),
+                    create_mock_domain_with_dates(SubscribeStatus::PAUSED, past, past),
+                    create_mock_domain(),
+                ])
+            })
+            .times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| s.next_payment_date().date_naive() >= Utc::now().date_naive())
+            .return_once(move |_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.roll_forward_payment_date(&user_id.to_string()).await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_roll_forward_payment_date_all() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let past = Utc::now() - chrono::Duration::days(400);
+
+        mock_repository
+            .expect_find_by_status()
+            .with(mockall::predicate::eq(SubscribeStatus::ACTIVE))
+            .return_once(move |_| {
+                Ok(vec![
+                    create_mock_domain_with_dates(SubscribeStatus::ACTIVE, past, past),
+                    create_mock_domain_with_dates(SubscribeStatus::ACTIVE, past, past),
+                ])
+            })
+            .times(1);
+        mock_repository.expect_update().returning(move |_| Ok(())).times(2);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.roll_forward_payment_date_all().await;
+
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_find_trials_ending_within() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let now = Utc::now();
+        let later = create_mock_trial_domain(now + chrono::Duration::days(5));
+        let sooner = create_mock_trial_domain(now + chrono::Duration::days(1));
+        let expected = vec![
+            sooner.subscribe_id().to_string(),
+            later.subscribe_id().to_string(),
+        ];
+
+        mock_repository
+            .expect_find_all()
+            .with(mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_| {
+                Ok(vec![
+                    later,
+                    create_mock_trial_domain(now + chrono::Duration::days(30)),
+                    create_mock_domain(),
+                    sooner,
+                ])
+            })
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_trials_ending_within(&user_id.to_string(), 7).await.unwrap();
+
+        let ids: Vec<String> = result
+            .into_iter()
+            .map(|d| SubscribeDto::map_to_domain_model(d).unwrap().subscribe_id().to_string())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[tokio::test]
+    async fn test_find_cancellation_windows_closing() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let now = Utc::now();
+        // 解約の最終日: 3日後と1日後が対象、20日後は対象外
+        let later = create_mock_contract_domain(30, now + chrono::Duration::days(33));
+        let sooner = create_mock_contract_domain(7, now + chrono::Duration::days(8));
+        let expected = vec![
+            sooner.subscribe_id().to_string(),
+            later.subscribe_id().to_string(),
+        ];
+
+        mock_repository
+            .expect_find_all()
+            .with(mockall::predicate::eq(user_id.clone()))
+            .return_once(move |_| {
+                Ok(vec![
+                    later,
+                    create_mock_contract_domain(10, now + chrono::Duration::days(30)),
+                    create_mock_domain(),
+                    sooner,
+                ])
+            })
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_cancellation_windows_closing(&user_id.to_string(), 7).await.unwrap();
+
+        assert!(result.iter().all(|d| d.last_cancellation_date().is_some()));
+        let ids: Vec<String> = result
+            .into_iter()
+            .map(|d| SubscribeDto::map_to_domain_model(d).unwrap().subscribe_id().to_string())
+            .collect();
+        assert_eq!(ids, expected);
+    }
+
+    #[tokio::test]
+    async fn test_convert_expired_trials_all() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let now = Utc::now();
+
+        mock_repository
+            .expect_find_by_status()
+            .with(mockall::predicate::eq(SubscribeStatus::TRIAL))
+            .return_once(move |_| {
+                Ok(vec![
+                    create_mock_trial_domain(now - chrono::Duration::days(1)),
+                    create_mock_trial_domain(now + chrono::Duration::days(3)),
+                ])
+            })
+            .times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| s.status() == &SubscribeStatus::ACTIVE && s.trial().is_none())
+            .return_once(move |_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.convert_expired_trials_all().await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_subscribe_records_price_change() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let current = create_mock_domain();
+        let dto = SubscribeDto::builder()
+            .subscribe_id(current.subscribe_id().to_string())
+            .user_id(current.user_id().to_string())
+            .name(current.name().to_string())
+            .payment_method_id(current.payment_method_id().to_string())
+            .amount("150".to_string())
+            .currency("JPY".to_string())
+            .payment_cycle(current.payment_cycle().to_string())
+            .category_id(current.category_id().to_string())
+            .icon_local_path(current.icon_local_path().to_string())
+            .first_payment_date(*current.first_payment_date())
+            .next_payment_date(*current.next_payment_date())
+            .status(current.status().to_string())
+            .build()
+            .unwrap();
+
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(current)).times(1);
+        mock_repository
+            .expect_update()
+            .withf(|s: &Subscribe| {
+                let history: Vec<String> = s.price_history().iter().map(|p| p.amount().amount().to_string()).collect();
+                history
+                    == vec![
+                        "100", "150",
+                    ]
+            })
+            .return_once(|_| Ok(()))
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.update_subscribe(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_subscribe_publishes_events() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let current = create_mock_domain();
+        let mut updated = current.clone();
+        updated.change_amount(
+            Money::new(Amount::try_from(Decimal::from(150)).unwrap(), Currency::JPY).unwrap(),
+            Utc::now(),
+        );
+        let dto = SubscribeDto::map_to_dto(&updated);
+
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(current)).times(1);
+        mock_repository.expect_update().return_once(|_| Ok(())).times(1);
+        let mut publisher = MockEventPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|events: &Vec<DomainEvent>| {
+                matches!(
+                    events.iter().map(|e| e.kind()).collect::<Vec<_>>()[..],
+                    [
+                        DomainEventKind::SubscribeUpdated { .. },
+                        DomainEventKind::AmountChanged { .. }
+                    ]
+                )
+            })
+            .return_const(())
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository).with_event_publisher(Arc::new(publisher));
+        let result = subscribe_service.update_subscribe(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_pause_subscribe_publishes_status_change() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_domain();
+        let (user_id, subscribe_id) = (subscribe.user_id().to_string(), subscribe.subscribe_id().to_string());
+
+        mock_repository.expect_find_by_id().return_once(move |_, _| Ok(subscribe)).times(1);
+        mock_repository.expect_update().return_once(|_| Ok(())).times(1);
+        let mut publisher = MockEventPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|events: &Vec<DomainEvent>| {
+                matches!(
+                    events.iter().map(|e| e.kind()).collect::<Vec<_>>()[..],
+                    [
+                        DomainEventKind::SubscribeStatusChanged {
+                            from: SubscribeStatus::ACTIVE,
+                            to: SubscribeStatus::PAUSED,
+                            ..
+                        }
+                    ]
+                )
+            })
+            .return_const(())
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository).with_event_publisher(Arc::new(publisher));
+        let result = subscribe_service.pause_subscribe(&user_id, &subscribe_id).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_tag_changes_publish_events() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_tagged_domain(&["shared"]);
+        let (user_id, subscribe_id) = (subscribe.user_id().to_string(), subscribe.subscribe_id().to_string());
+
+        mock_repository.expect_find_by_id().returning(move |_, _| Ok(subscribe.clone())).times(2);
+        mock_repository.expect_update().returning(|_| Ok(())).times(2);
+        let mut publisher = MockEventPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|events: &Vec<DomainEvent>| {
+                matches!(
+                    events.iter().map(|e| e.kind()).collect::<Vec<_>>()[..],
+                    [DomainEventKind::TagAdded { tag, .. }] if tag.to_string() == "work"
+                )
+            })
+            .return_const(())
+            .times(1);
+        publisher
+            .expect_publish()
+            .withf(|events: &Vec<DomainEvent>| {
+                matches!(
+                    events.iter().map(|e| e.kind()).collect::<Vec<_>>()[..],
+                    [DomainEventKind::TagRemoved { tag, .. }] if tag.to_string() == "shared"
+                )
+            })
+            .return_const(())
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository).with_event_publisher(Arc::new(publisher));
+
+        assert!(subscribe_service.add_tag(&user_id, &subscribe_id, "work").await.is_ok());
+        assert!(subscribe_service.remove_tag(&user_id, &subscribe_id, "shared").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_subscribe_failure_publishes_nothing() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        mock_repository.expect_create().return_once(|_| Err(SubscribeError::QueryError("error".to_string()))).times(1);
+        let mut publisher = MockEventPublisher::new();
+        publisher.expect_publish().times(0);
+
+        let subscribe_service = create_service(mock_repository).with_event_publisher(Arc::new(publisher));
+        let result = subscribe_service.create_subscribe(create_mock_dto()).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_find_recent_price_increases() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let user_id = UserId::new();
+        let now = Utc::now();
+        let first = now - chrono::Duration::days(500);
+        let jpy = |a: i32| Money::new(Amount::try_from(Decimal::from(a)).unwrap(), Currency::JPY).unwrap();
+
+        let mut old_increase = create_mock_domain_with_dates(SubscribeStatus::ACTIVE, first, now);
+        old_increase.change_amount(jpy(200), now - chrono::Duration::days(400));
+        let mut recent_increase = create_mock_domain_with_dates(SubscribeStatus::ACTIVE, first, now);
+        recent_increase.change_amount(jpy(120), now - chrono::Duration::days(30));
+        let mut decrease = create_mock_domain_with_dates(SubscribeStatus::ACTIVE, first, now);
+        decrease.change_amount(jpy(80), now - chrono::Duration::days(10));
+        let expected_id = recent_increase.subscribe_id().to_string();
+
+        mock_repository
+            .expect_find_all()
+            .return_once(move |_| {
+                Ok(vec![
+                    old_increase,
+                    recent_increase,
+                    decrease,
+                ])
+            })
+            .times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_recent_price_increases(&user_id.to_string(), 365).await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].subscribe_id(), expected_id);
+        assert_eq!(result[0].increase_rate(), "20");
+    }
+
+    #[tokio::test]
+    async fn test_find_cost_split_success() {
+        let mut mock_repository = MockSubscribeRepository::new();
+        let subscribe = create_mock_split_domain(
+            CostSplit::new(vec![
+                MemberShare::new(SplitMember::Person("hoge".to_string()), Share::Percentage(Decimal::from(30)))
+                    .unwrap(),
+                MemberShare::new(SplitMember::User(UserId::new()), Share::Percentage(Decimal::from(20))).unwrap(),
+            ])
+            .unwrap(),
+        );
+        let user_id = subscribe.user_id().to_string();
+        let subscribe_id = subscribe.subscribe_id().to_string();
+        mock_repository.expect_find_by_id().returning(move |_, _| Ok(subscribe.clone())).times(1);
+
+        let subscribe_service = create_service(mock_repository);
+        let result = subscribe_service.find_cost_split(&user_id, &subscribe_id).await.unwrap();
+
+        assert_eq!(result.own_amount(), "50");
+        assert_eq!(result.members().len(), 2);
+        assert_eq!(result.members()[0].owed(), "30");
+        assert_eq!(result.members()[1].owed(), "20");
+    }
+
+    fn create_mock_trial_domain(end_date: chrono::DateTime<Utc>) -> Subscribe {
+        let post_trial_amount = Money::new(Amount::try_from(Decimal::from(1490)).unwrap(), Currency::JPY).unwrap();
+        let now = Utc::now();
+
+        Subscribe::from(
+            SubscribeId::new(),
+            UserId::new(),
+            SubscribeName::new("hoge").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            now,
+            end_date,
+            true,
+            SubscribeStatus::TRIAL,
+            None,
+            Some(Trial::new(end_date, Some(post_trial_amount))),
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    fn create_mock_contract_domain(notice_period_days: u32, next_payment_date: chrono::DateTime<Utc>) -> Subscribe {
+        Subscribe::from(
+            SubscribeId::new(),
+            UserId::new(),
+            SubscribeName::new("hoge").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            next_payment_date - chrono::Duration::days(30),
+            next_payment_date,
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::new(None, Some(notice_period_days)).unwrap(),
+            CostSplit::default(),
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    fn create_mock_split_domain(cost_split: CostSplit) -> Subscribe {
+        let now = Utc::now();
+        Subscribe::from(
+            SubscribeId::new(),
+            UserId::new(),
+            SubscribeName::new("hoge").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap(),
+            PaymentCycle::Monthly,
+            CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now,
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            cost_split,
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
+        )
+    }
+
     fn create_mock_domain() -> Subscribe {
+        create_mock_domain_with_status(SubscribeStatus::ACTIVE)
+    }
+
+    fn create_mock_domain_with_status(status: SubscribeStatus) -> Subscribe {
+        let now = Utc::now();
+        create_mock_domain_with_dates(status, now, now)
+    }
+
+    fn create_mock_domain_with_dates(
+        status: SubscribeStatus,
+        first_payment_date: chrono::DateTime<Utc>,
+        next_payment_date: chrono::DateTime<Utc>,
+    ) -> Subscribe {
         let subscribe_id = SubscribeId::new();
         let user_id = UserId::new();
         let name = SubscribeName::new("hoge").unwrap();
         let payment_method_id = PaymentMethodId::new();
-        let amount = Amount::try_from(Decimal::ONE_HUNDRED).unwrap();
+        let amount = Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap();
         let payment_cycle = PaymentCycle::Monthly;
-        let now = Utc::now();
         let category_id = CategoryId::new();
         let icon_path = String::from("/path/to/icon");
         let notification = true;
         let auto_renewal = true;
-        let status = SubscribeStatus::ACTIVE;
         let memo = Some("テストメモ".to_owned());
 
         Subscribe::from(
@@ -287,11 +1821,19 @@ mod tests {
             category_id,
             icon_path,
             notification,
-            now,
-            now,
+            first_payment_date,
+            next_payment_date,
             auto_renewal,
             status,
             memo,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
         )
     }
 }
@@ -0,0 +1,208 @@
+use crate::dtos::user_dto::UserDto;
+use crate::dtos::DTO;
+use crate::error::ApplicationError;
+use crate::event::{EventEmitter, PublishEvents};
+use crate::service::UserService;
+use chrono::Utc;
+use domain::event::DomainEventKind;
+use domain::repository::user_repository::UserRepository;
+use domain::user::user_error::UserError;
+use domain::user::user_id::UserId;
+use std::str::FromStr;
+
+pub struct UserServiceImpl<U: UserRepository> {
+    repository: U,
+    events: EventEmitter,
+}
+
+impl<U: UserRepository> PublishEvents for UserServiceImpl<U> {
+    fn emitter_mut(&mut self) -> &mut EventEmitter {
+        &mut self.events
+    }
+}
+
+impl<U: UserRepository> UserServiceImpl<U> {
+    pub fn new(repository: U) -> UserServiceImpl<U> {
+        Self { repository, events: EventEmitter::default() }
+    }
+}
+
+#[async_trait::async_trait]
+impl<U: UserRepository> UserService for UserServiceImpl<U> {
+    async fn create_user(&self, user: UserDto) -> Result<UserDto, ApplicationError> {
+        if !user.user_id().is_empty() {
+            return Err(ApplicationError::UserError("user id is assigned by the server".to_string()));
+        }
+        let user = UserDto::map_to_domain_model(user)?;
+
+        self.repository.create(&user).await?;
+        self.events.publish(user.user_id(), DomainEventKind::UserCreated).await;
+        Ok(UserDto::map_to_dto(&user))
+    }
+
+    async fn find_user(&self, user_id: &str) -> Result<UserDto, ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+        let user = self.repository.find_by_id(&user_id).await?;
+
+        Ok(UserDto::map_to_dto(&user))
+    }
+
+    async fn update_user(&self, user: UserDto) -> Result<(), ApplicationError> {
+        if user.user_id().is_empty() {
+            return Err(UserError::MissingField("user_id".to_string()).into());
+        }
+        let changed = UserDto::map_to_domain_model(user)?;
+        // 作成日時を引き継ぐため、保存済みのユーザーに変更を反映する
+        let mut user = self.repository.find_by_id(changed.user_id()).await?;
+        user.change(changed.profile().clone(), changed.country_id(), changed.settings().clone())?;
+        user.mark_updated(Utc::now());
+
+        self.repository.update(&user).await?;
+        self.events.publish(user.user_id(), DomainEventKind::UserUpdated).await;
+        Ok(())
+    }
+
+    async fn delete_user(&self, user_id: &str) -> Result<(), ApplicationError> {
+        let user_id = UserId::from_str(user_id)?;
+
+        self.repository.delete(&user_id).await?;
+        self.events.publish(&user_id, DomainEventKind::UserDeleted).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::user_dto::UserSettingsDto;
+    use chrono::TimeZone;
+    use domain::user::user_profile::UserProfile;
+    use domain::user::user_settings::UserSettings;
+    use domain::user::User;
+    use domain::value_object::currency::Currency;
+    use mockall::mock;
+
+    mock! {
+        UserRepository {}
+        #[async_trait::async_trait]
+        impl UserRepository for UserRepository {
+            async fn create(&self, user: &User) -> Result<(), UserError>;
+            async fn find_by_id(&self, user_id: &UserId) -> Result<User, UserError>;
+            async fn update(&self, user: &User) -> Result<(), UserError>;
+            async fn delete(&self, user_id: &UserId) -> Result<(), UserError>;
+        }
+    }
+
+    fn create_mock_user() -> User {
+        let created_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        User::from(
+            UserId::new(),
+            UserProfile::new("山田 太郎", "taro@example.com", None).unwrap(),
+            1,
+            UserSettings::default(),
+            created_at,
+            created_at,
+        )
+    }
+
+    fn create_mock_dto(user_id: &str, country_id: i32) -> UserDto {
+        UserDto::new(
+            user_id.to_string(),
+            "Taro Yamada".to_string(),
+            "taro@example.com".to_string(),
+            None,
+            country_id,
+            UserSettingsDto::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_user_success() {
+        let mut mock_repository = MockUserRepository::new();
+        mock_repository.expect_create().return_once(|_| Ok(())).times(1);
+
+        let service = UserServiceImpl::new(mock_repository);
+        let result = service.create_user(create_mock_dto("", 2)).await.unwrap();
+
+        assert!(UserId::from_str(result.user_id()).is_ok());
+        assert_eq!(result.default_currency(), &Some("USD".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_unsupported_country() {
+        let mut mock_repository = MockUserRepository::new();
+        mock_repository.expect_create().times(0);
+
+        let service = UserServiceImpl::new(mock_repository);
+        let result = service.create_user(create_mock_dto("", 999)).await;
+
+        assert_eq!(result.unwrap_err(), ApplicationError::UserError(UserError::UnsupportedCountry(999).to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_create_user_already_exists() {
+        let mut mock_repository = MockUserRepository::new();
+        mock_repository.expect_create().return_once(|_| Err(UserError::AlreadyExists)).times(1);
+
+        let service = UserServiceImpl::new(mock_repository);
+        let result = service.create_user(create_mock_dto("", 1)).await;
+
+        assert!(matches!(result, Err(ApplicationError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_user_keeps_created_at() {
+        let mut mock_repository = MockUserRepository::new();
+        let current = create_mock_user();
+        let created_at = *current.created_at();
+        let dto = UserDto::new(
+            current.user_id().to_string(),
+            "Taro".to_string(),
+            "taro@example.org".to_string(),
+            Some("profile_taro".to_string()),
+            4,
+            UserSettingsDto::new(false, Some("USD".to_string())),
+        );
+
+        mock_repository.expect_find_by_id().return_once(move |_| Ok(current)).times(1);
+        mock_repository
+            .expect_update()
+            .withf(move |u: &User| {
+                u.created_at() == &created_at
+                    && u.updated_at() > &created_at
+                    && u.profile().email() == "taro@example.org"
+                    && u.country_id() == 4
+                    && !u.settings().notification()
+                    && u.default_currency() == Currency::USD
+            })
+            .return_once(|_| Ok(()))
+            .times(1);
+
+        let service = UserServiceImpl::new(mock_repository);
+        let result = service.update_user(dto).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_user_without_user_id() {
+        let mut mock_repository = MockUserRepository::new();
+        mock_repository.expect_update().times(0);
+
+        let service = UserServiceImpl::new(mock_repository);
+        let result = service.update_user(create_mock_dto("", 1)).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_delete_user_not_exists() {
+        let mut mock_repository = MockUserRepository::new();
+        mock_repository.expect_delete().return_once(|_| Err(UserError::NotExists)).times(1);
+
+        let service = UserServiceImpl::new(mock_repository);
+        let result = service.delete_user(&UserId::new().to_string()).await;
+
+        assert_eq!(result.unwrap_err(), ApplicationError::UserError(UserError::NotExists.to_string()));
+    }
+}
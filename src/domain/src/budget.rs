@@ -0,0 +1,262 @@
+use crate::budget::budget_id::BudgetId;
+use crate::category::category_id::CategoryId;
+use crate::subscribe::subscribe_status::SubscribeStatus;
+use crate::subscribe::Subscribe;
+use crate::user::user_id::UserId;
+use crate::value_object::money::Money;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+pub mod budget_error;
+pub mod budget_id;
+pub mod budget_status;
+
+/// カテゴリごと、または全体の月の予算を管理する構造体
+#[derive(Debug, Clone)]
+pub struct Budget {
+    /// 予算ID
+    budget_id: BudgetId,
+
+    /// ユーザーID
+    user_id: UserId,
+
+    /// 対象のカテゴリID ([None] の場合は全体の予算)
+    category_id: Option<CategoryId>,
+
+    /// 月の上限額(通貨付き)
+    limit: Money,
+
+    /// 作成日時
+    created_at: DateTime<Utc>,
+
+    /// 更新日時
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl Budget {
+    /// 予算を作成する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] ユーザーID
+    /// * `category_id` - [Option<CategoryId>] 対象のカテゴリID。[None] の場合は全体の予算
+    /// * `limit` - [Money] 月の上限額
+    ///
+    /// # 戻り値
+    /// - [Budget] 予算
+    pub fn new(user_id: UserId, category_id: Option<CategoryId>, limit: Money) -> Self {
+        Self { budget_id: BudgetId::new(), user_id, category_id, limit, created_at: Utc::now(), updated_at: None }
+    }
+
+    /// 既存のIDから予算を作成する
+    ///
+    /// # 引数
+    /// * `budget_id` - [BudgetId] 予算ID
+    /// * `user_id` - [UserId] ユーザーID
+    /// * `category_id` - [Option<CategoryId>] 対象のカテゴリID。[None] の場合は全体の予算
+    /// * `limit` - [Money] 月の上限額
+    /// * `created_at` - 作成日時
+    /// * `updated_at` - 更新日時。一度も更新されていない場合は[None]
+    ///
+    /// # 戻り値
+    /// - [Budget] 予算
+    pub fn from(
+        budget_id: BudgetId,
+        user_id: UserId,
+        category_id: Option<CategoryId>,
+        limit: Money,
+        created_at: DateTime<Utc>,
+        updated_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self { budget_id, user_id, category_id, limit, created_at, updated_at }
+    }
+
+    pub fn budget_id(&self) -> &BudgetId {
+        &self.budget_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn category_id(&self) -> &Option<CategoryId> {
+        &self.category_id
+    }
+
+    pub fn limit(&self) -> &Money {
+        &self.limit
+    }
+
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+
+    pub fn updated_at(&self) -> &Option<DateTime<Utc>> {
+        &self.updated_at
+    }
+
+    /// 新規登録として作成日時を記録する (更新日時はクリアする)
+    pub fn mark_created(&mut self, now: DateTime<Utc>) {
+        self.created_at = now;
+        self.updated_at = None;
+    }
+
+    /// 更新日時を記録する
+    pub fn mark_updated(&mut self, now: DateTime<Utc>) {
+        self.updated_at = Some(now);
+    }
+
+    /// 全体の予算か判定する
+    ///
+    /// # 戻り値
+    /// - [bool] カテゴリを指定していない場合はtrue
+    pub fn is_overall(&self) -> bool {
+        self.category_id.is_none()
+    }
+
+    /// 同じ対象(同じカテゴリ、または同じく全体)の予算か判定する
+    ///
+    /// # 引数
+    /// * `other` - [Budget] 比較する予算
+    ///
+    /// # 戻り値
+    /// - [bool] 対象が同じ場合はtrue
+    pub fn has_same_scope(&self, other: &Budget) -> bool {
+        self.category_id == other.category_id
+    }
+
+    /// サブスクが予算の集計対象か判定する
+    ///
+    /// ACTIVEかつゴミ箱に移動していないサブスクのうち、カテゴリの予算の場合はそのカテゴリのもの、
+    /// 全体の予算の場合は全てが対象となる。通貨は判定に含まない
+    ///
+    /// # 引数
+    /// * `subscribe` - [Subscribe] 判定するサブスク
+    ///
+    /// # 戻り値
+    /// - [bool] 集計対象の場合はtrue
+    pub fn covers(&self, subscribe: &Subscribe) -> bool {
+        if subscribe.status() != &SubscribeStatus::ACTIVE || subscribe.is_deleted() {
+            return false;
+        }
+        match &self.category_id {
+            Some(category_id) => subscribe.category_id() == category_id,
+            None => true,
+        }
+    }
+
+    /// 集計対象のサブスクの月額換算の負担額を合計する
+    ///
+    /// 為替換算は行わないため、上限額と異なる通貨のサブスクは合計に含まない
+    ///
+    /// # 引数
+    /// * `subscribes` - [Subscribe] ユーザーのサブスクのリスト
+    ///
+    /// # 戻り値
+    /// - [Decimal] 月額換算の支出額
+    pub fn monthly_spending(&self, subscribes: &[Subscribe]) -> Decimal {
+        subscribes
+            .iter()
+            .filter(|s| self.covers(s) && s.currency() == self.limit.currency())
+            .map(Subscribe::own_monthly_equivalent)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payment::payment_method_id::PaymentMethodId;
+    use crate::payment_cycle::PaymentCycle;
+    use crate::subscribe::contract_term::ContractTerm;
+    use crate::subscribe::cost_split::CostSplit;
+    use crate::subscribe::subscribe_name::SubscribeName;
+    use crate::subscribe::tag::TagSet;
+    use crate::value_object::amount::Amount;
+    use crate::value_object::currency::Currency;
+    use std::str::FromStr;
+
+    fn money(value: &str, currency: Currency) -> Money {
+        Money::new(Amount::from_str(value).unwrap(), currency).unwrap()
+    }
+
+    fn create_subscribe(
+        category_id: &CategoryId,
+        amount: Money,
+        cycle: PaymentCycle,
+        status: SubscribeStatus,
+    ) -> Subscribe {
+        let now = Utc::now();
+        Subscribe::new(
+            UserId::new(),
+            SubscribeName::new("Netflix").unwrap(),
+            PaymentMethodId::new(),
+            amount,
+            cycle,
+            category_id.clone(),
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now,
+            true,
+            status,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        )
+    }
+
+    #[test]
+    fn test_monthly_spending_category_budget() {
+        let streaming = CategoryId::new();
+        let other = CategoryId::new();
+        let budget = Budget::new(UserId::new(), Some(streaming.clone()), money("5000", Currency::JPY));
+        let subscribes = vec![
+            create_subscribe(&streaming, money("1980", Currency::JPY), PaymentCycle::Monthly, SubscribeStatus::ACTIVE),
+            create_subscribe(&streaming, money("12000", Currency::JPY), PaymentCycle::Yearly, SubscribeStatus::ACTIVE),
+            create_subscribe(&streaming, money("990", Currency::JPY), PaymentCycle::Monthly, SubscribeStatus::PAUSED),
+            create_subscribe(&streaming, money("9.99", Currency::USD), PaymentCycle::Monthly, SubscribeStatus::ACTIVE),
+            create_subscribe(&other, money("500", Currency::JPY), PaymentCycle::Monthly, SubscribeStatus::ACTIVE),
+        ];
+
+        assert_eq!(budget.monthly_spending(&subscribes), Decimal::from(2980));
+    }
+
+    #[test]
+    fn test_monthly_spending_overall_budget() {
+        let budget = Budget::new(UserId::new(), None, money("5000", Currency::JPY));
+        let subscribes = vec![
+            create_subscribe(
+                &CategoryId::new(),
+                money("1980", Currency::JPY),
+                PaymentCycle::Monthly,
+                SubscribeStatus::ACTIVE,
+            ),
+            create_subscribe(
+                &CategoryId::new(),
+                money("500", Currency::JPY),
+                PaymentCycle::Monthly,
+                SubscribeStatus::TRIAL,
+            ),
+            create_subscribe(
+                &CategoryId::new(),
+                money("300", Currency::JPY),
+                PaymentCycle::Monthly,
+                SubscribeStatus::ACTIVE,
+            ),
+        ];
+
+        assert!(budget.is_overall());
+        assert_eq!(budget.monthly_spending(&subscribes), Decimal::from(2280));
+    }
+
+    #[test]
+    fn test_has_same_scope() {
+        let category_id = CategoryId::new();
+        let budget = Budget::new(UserId::new(), Some(category_id.clone()), money("5000", Currency::JPY));
+
+        assert!(budget.has_same_scope(&Budget::new(UserId::new(), Some(category_id), money("1000", Currency::JPY))));
+        assert!(!budget.has_same_scope(&Budget::new(UserId::new(), None, money("1000", Currency::JPY))));
+    }
+}
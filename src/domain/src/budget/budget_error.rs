@@ -0,0 +1,58 @@
+use crate::{
+    value_object::{amount::AmountError, currency::CurrencyError, money::MoneyError},
+    AggregateIdError,
+};
+use thiserror::Error;
+
+/// 予算操作に関するエラー
+///
+/// # バリアント
+/// * `InvalidMoney` - 上限額または通貨が不正な場合のエラー
+/// * `NotExists` - 対象の予算が存在しない場合のエラー
+#[derive(Debug, Error)]
+pub enum BudgetError {
+    #[error("Invalid Money: {0}")]
+    InvalidMoney(#[from] MoneyError),
+
+    #[error("Failed to create budget: {0}")]
+    CreateBudgetFailed(String),
+
+    #[error("Failed to query budget: {0}")]
+    QueryError(String),
+
+    #[error("Failed to find by id budget: {0}")]
+    FindByIdError(String),
+
+    #[error("Failed to update budget: {0}")]
+    UpdateBudgetFailed(String),
+
+    #[error("Failed to delete budget: {0}")]
+    DeleteBudgetFailed(String),
+
+    #[error("Required budget field '{0}' was missing")]
+    MissingField(String),
+
+    #[error("Budget not exist")]
+    NotExists,
+
+    #[error("{0}")]
+    BudgetIdFailed(String),
+}
+
+impl From<AggregateIdError> for BudgetError {
+    fn from(value: AggregateIdError) -> Self {
+        BudgetError::BudgetIdFailed(value.to_string())
+    }
+}
+
+impl From<AmountError> for BudgetError {
+    fn from(value: AmountError) -> Self {
+        BudgetError::InvalidMoney(MoneyError::from(value))
+    }
+}
+
+impl From<CurrencyError> for BudgetError {
+    fn from(value: CurrencyError) -> Self {
+        BudgetError::InvalidMoney(MoneyError::from(value))
+    }
+}
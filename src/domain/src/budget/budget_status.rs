@@ -0,0 +1,70 @@
+use rust_decimal::Decimal;
+use std::fmt::{Display, Formatter};
+
+/// 予算に対する支出の状況
+///
+/// * `UNDER` - 上限額の80%未満
+/// * `NEAR` - 上限額の80%以上、上限額以下
+/// * `OVER` - 上限額を超えている
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum BudgetStatus {
+    UNDER,
+    NEAR,
+    OVER,
+}
+
+/// 上限額に近づいているとみなす使用率 (%)
+const NEAR_THRESHOLD_PERCENT: u32 = 80;
+
+impl BudgetStatus {
+    /// 支出額と上限額から状況を判定する
+    ///
+    /// # 引数
+    /// * `spent` - [Decimal] 月額換算の支出額
+    /// * `limit` - [Decimal] 月の上限額
+    ///
+    /// # 戻り値
+    /// - [BudgetStatus] 予算に対する支出の状況
+    pub fn evaluate(spent: Decimal, limit: Decimal) -> Self {
+        if spent > limit {
+            return BudgetStatus::OVER;
+        }
+        if spent * Decimal::from(100) >= limit * Decimal::from(NEAR_THRESHOLD_PERCENT) {
+            return BudgetStatus::NEAR;
+        }
+        BudgetStatus::UNDER
+    }
+}
+
+impl Display for BudgetStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BudgetStatus::UNDER => write!(f, "UNDER"),
+            BudgetStatus::NEAR => write!(f, "NEAR"),
+            BudgetStatus::OVER => write!(f, "OVER"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, BudgetStatus::UNDER)]
+    #[case(3999, BudgetStatus::UNDER)]
+    #[case(4000, BudgetStatus::NEAR)]
+    #[case(5000, BudgetStatus::NEAR)]
+    #[case(5001, BudgetStatus::OVER)]
+    fn test_evaluate(#[case] spent: i32, #[case] expected: BudgetStatus) {
+        assert_eq!(BudgetStatus::evaluate(Decimal::from(spent), Decimal::from(5000)), expected);
+    }
+
+    #[test]
+    fn test_budget_status_fmt() {
+        assert_eq!(BudgetStatus::UNDER.to_string(), "UNDER");
+        assert_eq!(BudgetStatus::NEAR.to_string(), "NEAR");
+        assert_eq!(BudgetStatus::OVER.to_string(), "OVER");
+    }
+}
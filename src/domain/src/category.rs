@@ -1,27 +1,82 @@
+use crate::category::category_appearance::{CategoryColor, CategoryIcon};
 use crate::category::category_id::CategoryId;
 use crate::category::category_name::CategoryName;
 use crate::user::user_id::UserId;
+use chrono::{DateTime, Utc};
 
+pub mod category_appearance;
 pub mod category_error;
 pub mod category_id;
 pub mod category_name;
+pub mod default_category;
 
 #[derive(Debug, Clone)]
 pub struct Category {
     category_id: CategoryId,
     user_id: UserId,
     category_name: CategoryName,
+    /// 表示色
+    color: Option<CategoryColor>,
+    /// アイコン
+    icon: Option<CategoryIcon>,
+    /// 並び順 (昇順に表示する)
+    sort_order: u32,
+    /// 作成日時
+    created_at: DateTime<Utc>,
+    /// 更新日時
+    updated_at: Option<DateTime<Utc>>,
+    /// ゴミ箱に移動した日時
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Category {
     /// idなしコンストラクタ
+    ///
+    /// 並び順は0で作成されるため、保存前に [Category::move_to] で位置を決める
     pub fn new(user_id: UserId, category_name: CategoryName) -> Self {
         let category_id = CategoryId::new();
-        Self { category_id, user_id, category_name }
+        Self {
+            category_id,
+            user_id,
+            category_name,
+            color: None,
+            icon: None,
+            sort_order: 0,
+            created_at: Utc::now(),
+            updated_at: None,
+            deleted_at: None,
+        }
     }
 
-    pub fn from(category_id: CategoryId, user_id: UserId, category_name: CategoryName) -> Self {
-        Self { category_id, user_id, category_name }
+    /// 付け替え先の「未分類」カテゴリを作成する
+    pub fn uncategorized(user_id: UserId) -> Self {
+        Self::new(user_id, CategoryName::uncategorized())
+    }
+
+    /// 既存のIDからカテゴリを作成する
+    ///
+    /// # 引数
+    /// * `category_id` - [CategoryId] カテゴリID
+    /// * `user_id` - [UserId] ユーザーID
+    /// * `category_name` - [CategoryName] カテゴリ名
+    /// * `color` - [CategoryColor] 表示色。未設定の場合は[None]
+    /// * `icon` - [CategoryIcon] アイコン。未設定の場合は[None]
+    /// * `sort_order` - 並び順
+    /// * `created_at` - 作成日時
+    /// * `updated_at` - 更新日時。一度も更新されていない場合は[None]
+    /// * `deleted_at` - ゴミ箱に移動した日時。削除されていない場合は[None]
+    pub fn from(
+        category_id: CategoryId,
+        user_id: UserId,
+        category_name: CategoryName,
+        color: Option<CategoryColor>,
+        icon: Option<CategoryIcon>,
+        sort_order: u32,
+        created_at: DateTime<Utc>,
+        updated_at: Option<DateTime<Utc>>,
+        deleted_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self { category_id, user_id, category_name, color, icon, sort_order, created_at, updated_at, deleted_at }
     }
 
     pub fn category_id(&self) -> &CategoryId {
@@ -33,6 +88,64 @@ impl Category {
     pub fn category_name(&self) -> &CategoryName {
         &self.category_name
     }
+    pub fn color(&self) -> &Option<CategoryColor> {
+        &self.color
+    }
+    pub fn icon(&self) -> &Option<CategoryIcon> {
+        &self.icon
+    }
+    pub fn sort_order(&self) -> u32 {
+        self.sort_order
+    }
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
+    }
+    pub fn updated_at(&self) -> &Option<DateTime<Utc>> {
+        &self.updated_at
+    }
+
+    /// 表示色とアイコンを変更する
+    pub fn change_appearance(&mut self, color: Option<CategoryColor>, icon: Option<CategoryIcon>) {
+        self.color = color;
+        self.icon = icon;
+    }
+
+    /// 並び順を変更する
+    ///
+    /// # 戻り値
+    /// - [bool] 並び順を変更した場合はtrue
+    pub fn move_to(&mut self, sort_order: u32) -> bool {
+        if self.sort_order == sort_order {
+            return false;
+        }
+        self.sort_order = sort_order;
+        true
+    }
+
+    /// 新規登録として作成日時を記録する (更新日時はクリアする)
+    pub fn mark_created(&mut self, now: DateTime<Utc>) {
+        self.created_at = now;
+        self.updated_at = None;
+    }
+
+    /// 更新日時を記録する
+    pub fn mark_updated(&mut self, now: DateTime<Utc>) {
+        self.updated_at = Some(now);
+    }
+
+    pub fn deleted_at(&self) -> &Option<DateTime<Utc>> {
+        &self.deleted_at
+    }
+
+    /// ゴミ箱に移動されているか判定する
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// 「未分類」カテゴリか判定する
+    pub fn is_uncategorized(&self) -> bool {
+        self.category_name == CategoryName::uncategorized()
+    }
 }
 
 #[cfg(test)]
@@ -56,8 +169,40 @@ mod tests {
         let user_id = UserId::new();
         let category_name = CategoryName::from_str("hoge").unwrap();
 
-        let result = Category::from(category_id.clone(), user_id, category_name);
+        let result = Category::from(
+            category_id.clone(),
+            user_id,
+            category_name,
+            Some(CategoryColor::new("#1E90FF").unwrap()),
+            None,
+            3,
+            Utc::now(),
+            None,
+            None,
+        );
+
+        assert_eq!(category_id.value(), result.category_id.value());
+        assert_eq!(result.sort_order(), 3);
+        assert_eq!(result.color().as_ref().map(|c| c.to_string()), Some("#1E90FF".to_string()));
+        assert!(result.icon().is_none())
+    }
+
+    #[test]
+    fn test_category_move_to() {
+        let mut category = Category::new(UserId::new(), CategoryName::from_str("hoge").unwrap());
+        assert_eq!(category.sort_order(), 0);
+
+        assert!(category.move_to(2));
+        assert!(!category.move_to(2));
+        assert_eq!(category.sort_order(), 2);
+    }
+
+    #[test]
+    fn test_category_uncategorized() {
+        let result = Category::uncategorized(UserId::new());
+        assert!(result.is_uncategorized());
 
-        assert_eq!(category_id.value(), result.category_id.value())
+        let result = Category::new(UserId::new(), CategoryName::from_str("hoge").unwrap());
+        assert!(!result.is_uncategorized());
     }
 }
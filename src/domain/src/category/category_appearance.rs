@@ -0,0 +1,123 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// カテゴリの表示色を表す値オブジェクト
+///
+/// `#RRGGBB` 形式の16進カラーコードを大文字に揃えて保持する
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CategoryColor(String);
+
+/// カテゴリのアイコンを表す値オブジェクト
+///
+/// アプリ側のアイコン定義を参照するキー (英小文字・数字・`_`・`-`)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CategoryIcon(String);
+
+/// カテゴリの表示色・アイコンに関するエラー
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CategoryAppearanceError {
+    /// カラーコードの形式が不正な場合のエラー
+    #[error("the category color must be a hex color like #1E90FF: {0}")]
+    InvalidColor(String),
+    /// アイコンキーの形式が不正な場合のエラー
+    #[error("the category icon key is invalid: {0}")]
+    InvalidIcon(String),
+}
+
+/// アイコンキーの最大文字数
+const ICON_MAX_LENGTH: usize = 32;
+
+impl CategoryColor {
+    pub fn new(value: &str) -> Result<Self, CategoryAppearanceError> {
+        match value.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                Ok(Self(value.to_ascii_uppercase()))
+            }
+            _ => Err(CategoryAppearanceError::InvalidColor(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for CategoryColor {
+    type Err = CategoryAppearanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for CategoryColor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl CategoryIcon {
+    pub fn new(value: &str) -> Result<Self, CategoryAppearanceError> {
+        let valid = !value.is_empty()
+            && value.len() <= ICON_MAX_LENGTH
+            && value.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+        match valid {
+            true => Ok(Self(value.to_string())),
+            false => Err(CategoryAppearanceError::InvalidIcon(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for CategoryIcon {
+    type Err = CategoryAppearanceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for CategoryIcon {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("#1e90ff", "#1E90FF")]
+    #[case("#000000", "#000000")]
+    #[case("#ABCDEF", "#ABCDEF")]
+    fn test_category_color_new_success(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(CategoryColor::new(value).unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("1E90FF")]
+    #[case("#1E90F")]
+    #[case("#1E90FFF")]
+    #[case("#GGGGGG")]
+    #[case("red")]
+    fn test_category_color_new_error(#[case] value: &str) {
+        assert_eq!(CategoryColor::new(value), Err(CategoryAppearanceError::InvalidColor(value.to_string())));
+    }
+
+    #[rstest]
+    #[case("video")]
+    #[case("cloud_storage")]
+    #[case("music-2")]
+    fn test_category_icon_new_success(#[case] value: &str) {
+        assert_eq!(CategoryIcon::new(value).unwrap().to_string(), value);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("Video")]
+    #[case("video icon")]
+    #[case("動画")]
+    #[case("abcdefghijklmnopqrstuvwxyz0123456")]
+    fn test_category_icon_new_error(#[case] value: &str) {
+        assert_eq!(CategoryIcon::new(value), Err(CategoryAppearanceError::InvalidIcon(value.to_string())));
+    }
+}
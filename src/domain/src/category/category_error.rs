@@ -2,7 +2,7 @@ use thiserror::Error;
 
 use crate::AggregateIdError;
 
-use super::category_name::CategoryNameError;
+use super::{category_appearance::CategoryAppearanceError, category_name::CategoryNameError};
 
 #[derive(Debug, Error)]
 pub enum CategoryError {
@@ -27,6 +27,12 @@ pub enum CategoryError {
     #[error("Category not exist")]
     NotExist,
 
+    #[error("Cannot reassign subscriptions to the category being deleted")]
+    SameReassignTarget,
+
+    #[error("Invalid category order: {0}")]
+    InvalidOrder(String),
+
     #[error("Required category field '{0}' was missing")]
     MissingField(String),
 
@@ -35,6 +41,12 @@ pub enum CategoryError {
 
     #[error("{0}")]
     CategoryNameFailed(String),
+
+    #[error("{0}")]
+    CategoryAppearanceFailed(String),
+
+    #[error("Unsupported country id: {0}")]
+    UnsupportedCountry(i32),
 }
 
 impl From<AggregateIdError> for CategoryError {
@@ -48,3 +60,9 @@ impl From<CategoryNameError> for CategoryError {
         CategoryError::CategoryNameFailed(value.to_string())
     }
 }
+
+impl From<CategoryAppearanceError> for CategoryError {
+    fn from(value: CategoryAppearanceError) -> Self {
+        CategoryError::CategoryAppearanceFailed(value.to_string())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{generate_id, AggregateId, AggregateIdError};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CategoryId {
     value: String,
 }
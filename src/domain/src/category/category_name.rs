@@ -5,6 +5,9 @@ use thiserror::Error;
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct CategoryName(String);
 
+/// カテゴリ削除時にサブスクの付け替え先として自動作成されるカテゴリの名前
+pub const UNCATEGORIZED_NAME: &str = "Uncategorized";
+
 /// カテゴリー名に関するエラー
 #[derive(Error, Debug, Clone)]
 pub enum CategoryNameError {
@@ -26,6 +29,11 @@ impl CategoryName {
             Ok(Self(value.to_string()))
         }
     }
+
+    /// 「未分類」カテゴリの名前
+    pub fn uncategorized() -> Self {
+        Self(UNCATEGORIZED_NAME.to_string())
+    }
 }
 
 impl FromStr for CategoryName {
@@ -0,0 +1,159 @@
+use crate::value_object::country::Country;
+
+/// 新規ユーザーに作成する既定のカテゴリ
+///
+/// 表示色・アイコンは国によらず共通で、名前のみ国ごとに翻訳する
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DefaultCategory {
+    name: &'static str,
+    color: &'static str,
+    icon: &'static str,
+}
+
+/// 既定カテゴリの表示色とアイコン (表示順)
+const APPEARANCES: [(&str, &str); 8] = [
+    ("#E50914", "video"),
+    ("#1DB954", "music"),
+    ("#4285F4", "cloud"),
+    ("#FF9800", "news"),
+    ("#9C27B0", "game"),
+    ("#607D8B", "productivity"),
+    ("#009688", "learning"),
+    ("#F44336", "fitness"),
+];
+
+const NAMES_JA: [&str; 8] = [
+    "動画配信",
+    "音楽",
+    "クラウド",
+    "ニュース",
+    "ゲーム",
+    "仕事効率化",
+    "学習",
+    "フィットネス",
+];
+const NAMES_EN: [&str; 8] = [
+    "Streaming",
+    "Music",
+    "Cloud Storage",
+    "News",
+    "Games",
+    "Productivity",
+    "Learning",
+    "Fitness",
+];
+const NAMES_DE: [&str; 8] = [
+    "Streaming",
+    "Musik",
+    "Cloud-Speicher",
+    "Nachrichten",
+    "Spiele",
+    "Produktivität",
+    "Lernen",
+    "Fitness",
+];
+const NAMES_FR: [&str; 8] = [
+    "Streaming",
+    "Musique",
+    "Stockage cloud",
+    "Actualités",
+    "Jeux",
+    "Productivité",
+    "Apprentissage",
+    "Fitness",
+];
+const NAMES_KO: [&str; 8] = [
+    "동영상",
+    "음악",
+    "클라우드",
+    "뉴스",
+    "게임",
+    "업무 효율",
+    "학습",
+    "피트니스",
+];
+const NAMES_ZH: [&str; 8] = [
+    "视频",
+    "音乐",
+    "云存储",
+    "新闻",
+    "游戏",
+    "效率工具",
+    "学习",
+    "健身",
+];
+
+impl DefaultCategory {
+    /// 国に応じた既定カテゴリを表示順に取得する
+    ///
+    /// # 引数
+    /// * `country` - [Country] ユーザーの国
+    ///
+    /// # 戻り値
+    /// - Vec<[DefaultCategory]> 既定カテゴリのリスト
+    pub fn for_country(country: Country) -> Vec<Self> {
+        let names = match country {
+            Country::JP => NAMES_JA,
+            Country::US | Country::GB | Country::AU | Country::CA => NAMES_EN,
+            Country::DE => NAMES_DE,
+            Country::FR => NAMES_FR,
+            Country::KR => NAMES_KO,
+            Country::CN => NAMES_ZH,
+        };
+        names.into_iter().zip(APPEARANCES).map(|(name, (color, icon))| Self { name, color, icon }).collect()
+    }
+
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    pub fn color(&self) -> &str {
+        self.color
+    }
+
+    pub fn icon(&self) -> &str {
+        self.icon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::category::category_appearance::{CategoryColor, CategoryIcon};
+    use crate::category::category_name::CategoryName;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Country::JP, "動画配信")]
+    #[case(Country::US, "Streaming")]
+    #[case(Country::DE, "Streaming")]
+    #[case(Country::KR, "동영상")]
+    #[case(Country::CN, "视频")]
+    fn test_for_country(#[case] country: Country, #[case] first: &str) {
+        let result = DefaultCategory::for_country(country);
+        assert_eq!(result.len(), APPEARANCES.len());
+        assert_eq!(result[0].name(), first);
+        assert_eq!(result[0].icon(), "video");
+    }
+
+    #[test]
+    fn test_default_categories_are_valid() {
+        for country in [
+            Country::JP,
+            Country::US,
+            Country::GB,
+            Country::DE,
+            Country::FR,
+            Country::KR,
+            Country::CN,
+            Country::AU,
+            Country::CA,
+        ] {
+            for category in DefaultCategory::for_country(country) {
+                assert!(CategoryName::new(category.name()).is_ok(), "{}", category.name());
+                assert!(CategoryColor::new(category.color()).is_ok());
+                assert!(CategoryIcon::new(category.icon()).is_ok());
+            }
+        }
+    }
+}
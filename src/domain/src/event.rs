@@ -0,0 +1,133 @@
+use crate::budget::budget_id::BudgetId;
+use crate::category::category_id::CategoryId;
+use crate::ledger::payment_id::PaymentId;
+use crate::ledger::payment_status::PaymentStatus;
+use crate::payment::payment_method_id::PaymentMethodId;
+use crate::subscribe::subscribe_id::SubscribeId;
+use crate::subscribe::subscribe_status::SubscribeStatus;
+use crate::subscribe::tag::Tag;
+use crate::user::user_id::UserId;
+use crate::value_object::money::Money;
+use chrono::{DateTime, Utc};
+
+pub mod event_publisher;
+
+/// 集約の変更によって発生したドメインイベント
+///
+/// リポジトリへの書き込みが成功した後に [event_publisher::EventPublisher] から発行する
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainEvent {
+    /// 対象のデータを所有するユーザーID
+    user_id: UserId,
+
+    /// 発生日時
+    occurred_at: DateTime<Utc>,
+
+    /// イベントの種類と内容
+    kind: DomainEventKind,
+}
+
+/// ドメインイベントの種類
+#[derive(Debug, Clone, PartialEq)]
+pub enum DomainEventKind {
+    SubscribeCreated {
+        subscribe_id: SubscribeId,
+    },
+    SubscribeUpdated {
+        subscribe_id: SubscribeId,
+    },
+    SubscribeStatusChanged {
+        subscribe_id: SubscribeId,
+        from: SubscribeStatus,
+        to: SubscribeStatus,
+    },
+    AmountChanged {
+        subscribe_id: SubscribeId,
+        before: Money,
+        after: Money,
+    },
+    SubscribeDeleted {
+        subscribe_id: SubscribeId,
+    },
+    SubscribeRestored {
+        subscribe_id: SubscribeId,
+    },
+    TagAdded {
+        subscribe_id: SubscribeId,
+        tag: Tag,
+    },
+    TagRemoved {
+        subscribe_id: SubscribeId,
+        tag: Tag,
+    },
+    CategoryCreated {
+        category_id: CategoryId,
+    },
+    CategoryUpdated {
+        category_id: CategoryId,
+    },
+    /// `reassigned` は削除前に別のカテゴリへ付け替えたサブスクの件数
+    CategoryDeleted {
+        category_id: CategoryId,
+        reassigned: usize,
+    },
+    CategoryRestored {
+        category_id: CategoryId,
+    },
+    PaymentMethodCreated {
+        payment_method_id: PaymentMethodId,
+    },
+    PaymentMethodUpdated {
+        payment_method_id: PaymentMethodId,
+    },
+    PaymentMethodDeleted {
+        payment_method_id: PaymentMethodId,
+    },
+    PaymentMethodRestored {
+        payment_method_id: PaymentMethodId,
+    },
+    PaymentRecorded {
+        payment_id: PaymentId,
+        subscribe_id: SubscribeId,
+        status: PaymentStatus,
+    },
+    BudgetCreated {
+        budget_id: BudgetId,
+    },
+    BudgetUpdated {
+        budget_id: BudgetId,
+    },
+    BudgetDeleted {
+        budget_id: BudgetId,
+    },
+    /// ユーザーのイベントは [DomainEvent::user_id] が対象のユーザーを表す
+    UserCreated,
+    UserUpdated,
+    UserDeleted,
+}
+
+impl DomainEvent {
+    /// 現在日時で発生したドメインイベントを作成する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 対象のデータを所有するユーザーID
+    /// * `kind` - [DomainEventKind] イベントの種類と内容
+    ///
+    /// # 戻り値
+    /// - [DomainEvent] ドメインイベント
+    pub fn new(user_id: UserId, kind: DomainEventKind) -> Self {
+        Self { user_id, occurred_at: Utc::now(), kind }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn occurred_at(&self) -> &DateTime<Utc> {
+        &self.occurred_at
+    }
+
+    pub fn kind(&self) -> &DomainEventKind {
+        &self.kind
+    }
+}
@@ -0,0 +1,15 @@
+use crate::event::DomainEvent;
+use async_trait::async_trait;
+
+/// ドメインイベントを発行するトレイト定義
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    /// ドメインイベントを発生順に発行する
+    ///
+    /// リポジトリへの書き込みが成功した後に呼び出す。
+    /// 購読側の処理が失敗しても書き込み結果には影響させないため、エラーは返さない
+    ///
+    /// # 引数
+    /// * `events` - Vec<[DomainEvent]> 発行するドメインイベント
+    async fn publish(&self, events: Vec<DomainEvent>);
+}
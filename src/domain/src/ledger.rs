@@ -0,0 +1,312 @@
+use crate::ledger::ledger_error::LedgerError;
+use crate::ledger::payment_id::PaymentId;
+use crate::ledger::payment_status::PaymentStatus;
+use crate::subscribe::subscribe_id::SubscribeId;
+use crate::user::user_id::UserId;
+use crate::value_object::money::Money;
+use chrono::{DateTime, Utc};
+
+pub mod ledger_error;
+pub mod payment_id;
+pub mod payment_status;
+
+/// サブスクの1回分の支払実績を管理する構造体
+#[derive(Debug, Clone)]
+pub struct Payment {
+    /// 支払記録ID
+    payment_id: PaymentId,
+
+    /// ユーザーID
+    user_id: UserId,
+
+    /// 対象のサブスクID
+    subscribe_id: SubscribeId,
+
+    /// 支払予定日
+    due_date: DateTime<Utc>,
+
+    /// 実際の支払日
+    paid_date: Option<DateTime<Utc>>,
+
+    /// 実際に請求された金額(通貨付き)
+    amount: Money,
+
+    /// ステータス
+    status: PaymentStatus,
+
+    /// メモ欄(失敗理由など)
+    note: Option<String>,
+}
+
+impl Payment {
+    /// 未確認の支払記録を作成する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] ユーザーID
+    /// * `subscribe_id` - [SubscribeId] 対象のサブスクID
+    /// * `due_date` - [DateTime<Utc>] 支払予定日
+    /// * `amount` - [Money] 請求予定の金額
+    ///
+    /// # 戻り値
+    /// - [Payment] ステータスがPENDINGの支払記録
+    pub fn new(user_id: UserId, subscribe_id: SubscribeId, due_date: DateTime<Utc>, amount: Money) -> Self {
+        Self {
+            payment_id: PaymentId::new(),
+            user_id,
+            subscribe_id,
+            due_date,
+            paid_date: None,
+            amount,
+            status: PaymentStatus::PENDING,
+            note: None,
+        }
+    }
+
+    /// 既存のIDから支払記録を作成する
+    ///
+    /// # 引数
+    /// * `payment_id` - [PaymentId] 支払記録ID
+    /// * `user_id` - [UserId] ユーザーID
+    /// * `subscribe_id` - [SubscribeId] 対象のサブスクID
+    /// * `due_date` - [DateTime<Utc>] 支払予定日
+    /// * `paid_date` - [Option<DateTime<Utc>>] 実際の支払日
+    /// * `amount` - [Money] 実際に請求された金額
+    /// * `status` - [PaymentStatus] ステータス
+    /// * `note` - メモ欄
+    ///
+    /// # 戻り値
+    /// - [Payment] 支払記録
+    pub fn from(
+        payment_id: PaymentId,
+        user_id: UserId,
+        subscribe_id: SubscribeId,
+        due_date: DateTime<Utc>,
+        paid_date: Option<DateTime<Utc>>,
+        amount: Money,
+        status: PaymentStatus,
+        note: Option<String>,
+    ) -> Self {
+        Self { payment_id, user_id, subscribe_id, due_date, paid_date, amount, status, note }
+    }
+
+    /// 支払記録IDを取得する
+    ///
+    /// # 戻り値
+    /// - [&PaymentId] 支払記録IDへの参照
+    pub fn payment_id(&self) -> &PaymentId {
+        &self.payment_id
+    }
+
+    /// ユーザーIDを取得する
+    ///
+    /// # 戻り値
+    /// - [&UserId] ユーザーIDへの参照
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    /// 対象のサブスクIDを取得する
+    ///
+    /// # 戻り値
+    /// - [&SubscribeId] サブスクIDへの参照
+    pub fn subscribe_id(&self) -> &SubscribeId {
+        &self.subscribe_id
+    }
+
+    /// 支払予定日を取得する
+    ///
+    /// # 戻り値
+    /// - [&DateTime<Utc>] 支払予定日への参照
+    pub fn due_date(&self) -> &DateTime<Utc> {
+        &self.due_date
+    }
+
+    /// 実際の支払日を取得する
+    ///
+    /// # 戻り値
+    /// - [&Option<DateTime<Utc>>] 支払日。未払いの場合は [None]
+    pub fn paid_date(&self) -> &Option<DateTime<Utc>> {
+        &self.paid_date
+    }
+
+    /// 請求された金額を取得する
+    ///
+    /// # 戻り値
+    /// - [&Money] 金額への参照
+    pub fn amount(&self) -> &Money {
+        &self.amount
+    }
+
+    /// ステータスを取得する
+    ///
+    /// # 戻り値
+    /// - [&PaymentStatus] ステータスへの参照
+    pub fn status(&self) -> &PaymentStatus {
+        &self.status
+    }
+
+    /// メモ欄を取得する
+    ///
+    /// # 戻り値
+    /// - [&Option<String>] メモ。未記入の場合は [None]
+    pub fn note(&self) -> &Option<String> {
+        &self.note
+    }
+
+    /// 支払予定日が指定日と同じ日か判定する
+    ///
+    /// # 引数
+    /// * `date` - [DateTime<Utc>] 比較する日時
+    ///
+    /// # 戻り値
+    /// - [bool] 同じ日付の場合はtrue
+    pub fn is_due_on(&self, date: &DateTime<Utc>) -> bool {
+        self.due_date.date_naive() == date.date_naive()
+    }
+
+    /// 実際に支出が発生した金額を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<Money>] 支払済みの場合は請求金額、それ以外は[None]
+    pub fn spent_amount(&self) -> Option<&Money> {
+        self.status.is_spent().then_some(&self.amount)
+    }
+
+    /// 支払が行われたことを確定する
+    ///
+    /// # 引数
+    /// * `paid_date` - [DateTime<Utc>] 実際の支払日
+    /// * `amount` - [Option<Money>] 実際に請求された金額。[None] の場合は予定金額のまま
+    ///
+    /// # エラー
+    /// - [LedgerError::InvalidStatusTransition] PENDING、FAILED、SKIPPED以外の場合
+    pub fn confirm(&mut self, paid_date: DateTime<Utc>, amount: Option<Money>) -> Result<(), LedgerError> {
+        self.status.validate_transition(&PaymentStatus::PAID)?;
+        self.status = PaymentStatus::PAID;
+        self.paid_date = Some(paid_date);
+        if let Some(amount) = amount {
+            self.amount = amount;
+        }
+        Ok(())
+    }
+
+    /// 支払について異議を申し立て、SKIPPED、FAILEDまたはREFUNDEDに変更する
+    ///
+    /// # 引数
+    /// * `status` - [PaymentStatus] 変更後のステータス
+    /// * `note` - [Option<String>] 理由などのメモ
+    ///
+    /// # エラー
+    /// - [LedgerError::InvalidStatusTransition] 許可されていない遷移の場合
+    pub fn dispute(&mut self, status: PaymentStatus, note: Option<String>) -> Result<(), LedgerError> {
+        if status == PaymentStatus::PAID || status == PaymentStatus::PENDING {
+            return Err(LedgerError::InvalidStatusTransition(self.status.to_string(), status.to_string()));
+        }
+        self.status.validate_transition(&status)?;
+        if status != PaymentStatus::REFUNDED {
+            self.paid_date = None;
+        }
+        self.status = status;
+        self.note = note;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_object::amount::Amount;
+    use crate::value_object::currency::Currency;
+    use chrono::TimeZone;
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+
+    fn jpy(value: i32) -> Money {
+        Money::new(Amount::try_from(Decimal::from(value)).unwrap(), Currency::JPY).unwrap()
+    }
+
+    fn create_payment() -> Payment {
+        Payment::new(UserId::new(), SubscribeId::new(), Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap(), jpy(1000))
+    }
+
+    #[test]
+    fn test_payment_new_success() {
+        let payment = create_payment();
+
+        assert_eq!(payment.status(), &PaymentStatus::PENDING);
+        assert!(payment.paid_date().is_none());
+        assert!(payment.spent_amount().is_none());
+    }
+
+    #[test]
+    fn test_confirm_with_actual_amount() {
+        let mut payment = create_payment();
+        let paid_date = Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap();
+
+        let result = payment.confirm(paid_date, Some(jpy(1100)));
+
+        assert!(result.is_ok());
+        assert_eq!(payment.status(), &PaymentStatus::PAID);
+        assert_eq!(payment.paid_date(), &Some(paid_date));
+        assert_eq!(payment.spent_amount(), Some(&jpy(1100)));
+    }
+
+    #[test]
+    fn test_confirm_keeps_scheduled_amount() {
+        let mut payment = create_payment();
+
+        payment.confirm(Utc::now(), None).unwrap();
+
+        assert_eq!(payment.amount(), &jpy(1000));
+    }
+
+    #[test]
+    fn test_confirm_failed_when_refunded() {
+        let mut payment = create_payment();
+        payment.confirm(Utc::now(), None).unwrap();
+        payment.dispute(PaymentStatus::REFUNDED, None).unwrap();
+
+        let result = payment.confirm(Utc::now(), None);
+
+        assert!(matches!(result, Err(LedgerError::InvalidStatusTransition(_, _))));
+    }
+
+    #[rstest]
+    #[case(PaymentStatus::SKIPPED, true)]
+    #[case(PaymentStatus::FAILED, true)]
+    #[case(PaymentStatus::REFUNDED, false)]
+    #[case(PaymentStatus::PAID, false)]
+    #[case(PaymentStatus::PENDING, false)]
+    fn test_dispute_pending(#[case] status: PaymentStatus, #[case] expected: bool) {
+        let mut payment = create_payment();
+
+        let result = payment.dispute(status.clone(), Some("理由".to_owned()));
+
+        assert_eq!(result.is_ok(), expected);
+        if expected {
+            assert_eq!(payment.status(), &status);
+            assert_eq!(payment.note(), &Some("理由".to_owned()));
+        }
+    }
+
+    #[test]
+    fn test_dispute_refund_keeps_paid_date() {
+        let mut payment = create_payment();
+        let paid_date = Utc::now();
+        payment.confirm(paid_date, None).unwrap();
+
+        payment.dispute(PaymentStatus::REFUNDED, None).unwrap();
+
+        assert_eq!(payment.status(), &PaymentStatus::REFUNDED);
+        assert_eq!(payment.paid_date(), &Some(paid_date));
+        assert!(payment.spent_amount().is_none());
+    }
+
+    #[test]
+    fn test_is_due_on() {
+        let payment = create_payment();
+
+        assert!(payment.is_due_on(&Utc.with_ymd_and_hms(2024, 1, 10, 18, 0, 0).unwrap()));
+        assert!(!payment.is_due_on(&Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap()));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    value_object::{amount::AmountError, currency::CurrencyError, money::MoneyError},
+    AggregateIdError,
+};
+use thiserror::Error;
+
+/// 支払記録操作に関するエラー
+///
+/// # バリアント
+/// * `InvalidMoney` - 金額または通貨が不正な場合のエラー
+/// * `InvalidStatusTransition` - 許可されていないステータス遷移の場合のエラー
+/// * `InvalidPaymentStatus` - 存在しないステータス文字列の場合のエラー
+#[derive(Debug, Error)]
+pub enum LedgerError {
+    #[error("Invalid Money: {0}")]
+    InvalidMoney(#[from] MoneyError),
+
+    #[error("Not match Payment Status: {0}")]
+    InvalidPaymentStatus(String),
+
+    #[error("Invalid payment status transition: {0} -> {1}")]
+    InvalidStatusTransition(String, String),
+
+    #[error("Failed to query payment ledger: {0}")]
+    QueryError(String),
+
+    #[error("Failed to find by id payment ledger: {0}")]
+    FindByIdError(String),
+
+    #[error("Failed to save payment ledger: {0}")]
+    SaveFailed(String),
+
+    #[error("Required payment ledger field '{0}' was missing")]
+    MissingField(String),
+
+    #[error("Failed to parse field '{0}'")]
+    ParseFailed(String),
+
+    #[error("Payment ledger not exist")]
+    NotExists,
+
+    #[error("{0}")]
+    PaymentIdFailed(String),
+}
+
+impl From<AggregateIdError> for LedgerError {
+    fn from(value: AggregateIdError) -> Self {
+        LedgerError::PaymentIdFailed(value.to_string())
+    }
+}
+
+impl From<AmountError> for LedgerError {
+    fn from(value: AmountError) -> Self {
+        LedgerError::InvalidMoney(MoneyError::from(value))
+    }
+}
+
+impl From<CurrencyError> for LedgerError {
+    fn from(value: CurrencyError) -> Self {
+        LedgerError::InvalidMoney(MoneyError::from(value))
+    }
+}
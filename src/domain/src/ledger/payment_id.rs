@@ -0,0 +1,197 @@
+use crate::{generate_id, AggregateId, AggregateIdError};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use uuid::Uuid;
+
+/// 支払記録の一意識別子
+///
+/// フォーマット: "pmt_<uuid>"
+/// 例: "pmt_550e8400-e29b-41d4-a716-446655440000"
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PaymentId {
+    /// UUIDの値
+    value: String,
+}
+
+/// プレフィックス文字列
+/// 支払記録IDの先頭に付与される識別子
+const PAYMENT_PREFIX: &str = "pmt";
+
+impl PaymentId {
+    /// 新しい支払記録IDを生成する
+    ///
+    /// # 戻り値
+    /// - [PaymentId] 生成された支払記録ID
+    pub fn new() -> Self {
+        let value = generate_id(PAYMENT_PREFIX, None);
+        Self { value }
+    }
+}
+
+impl AggregateId for PaymentId {
+    /// プレフィックスを取得する
+    ///
+    /// # 戻り値
+    /// - [String] "pmt"という文字列
+    fn type_name(&self) -> String {
+        PAYMENT_PREFIX.to_string()
+    }
+
+    /// IDの値を取得する
+    ///
+    /// # 戻り値
+    /// - [String] UUID文字列への参照
+    fn value(&self) -> &String {
+        &self.value
+    }
+}
+
+impl From<Uuid> for PaymentId {
+    /// UUIDから支払記録IDを生成する
+    ///
+    /// # 引数
+    /// * `value` - [Uuid] 変換元のUUID
+    ///
+    /// # 戻り値
+    /// - [PaymentId] 生成された支払記録ID
+    fn from(value: Uuid) -> Self {
+        Self { value: generate_id(PAYMENT_PREFIX, Some(value)) }
+    }
+}
+
+impl Display for PaymentId {
+    /// 文字列表現を取得する
+    ///
+    /// # 引数
+    /// * `f` - [Formatter] フォーマッター
+    ///
+    /// # 戻り値
+    /// - [std::fmt::Result] フォーマット結果
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl FromStr for PaymentId {
+    type Err = AggregateIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: Vec<&str> = s.split("_").collect();
+        if value.len() != 2 {
+            return Err(AggregateIdError::InvalidFormat);
+        }
+        if value[0] != PAYMENT_PREFIX {
+            return Err(AggregateIdError::InvalidFormat);
+        }
+        let uuid = Uuid::parse_str(value[1]).map_err(|_| AggregateIdError::InvalidUuid)?;
+        Ok(Self::from(uuid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_new_success() {
+        let id = PaymentId::new();
+        assert!(!id.value.is_empty());
+        assert!(id.value.starts_with("pmt"))
+    }
+
+    #[test]
+    fn test_type_name_success() {
+        let id = PaymentId::new();
+        assert!(!id.type_name().is_empty());
+        assert_eq!(PAYMENT_PREFIX, id.type_name())
+    }
+
+    #[test]
+    fn test_from_success() {
+        let uuid = Uuid::new_v4();
+        let format = format!("pmt_{}", uuid);
+        let result = PaymentId::from(uuid);
+        assert!(result.value.starts_with("pmt"));
+        assert_eq!(format, result.value)
+    }
+
+    #[test]
+    fn test_from_str_success() {
+        let uuid = Uuid::new_v4();
+        let format = format!("pmt_{}", uuid);
+        let result = PaymentId::from_str(&format);
+        assert!(result.is_ok());
+        assert_eq!(format, result.unwrap().value);
+    }
+
+    #[test]
+    fn test_from_str_falied_invalid_format() {
+        let result = PaymentId::from_str("Invalid");
+        assert!(matches!(result, Err(AggregateIdError::InvalidFormat)))
+    }
+
+    #[test]
+    fn test_from_str_falied_invali_uuid() {
+        let format = format!("pmt_{}", "Invalid");
+        let result = PaymentId::from_str(&format);
+        assert!(matches!(result, Err(AggregateIdError::InvalidUuid)))
+    }
+
+    #[test]
+    fn test_display_format() {
+        let id = PaymentId::new();
+        let display_string = id.to_string();
+        assert_eq!(display_string, id.value);
+    }
+
+    #[test]
+    fn test_clone_equality() {
+        let id1 = PaymentId::new();
+        let id2 = id1.clone();
+        assert_eq!(id1, id2);
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let id = PaymentId::new();
+        let debug_string = format!("{:?}", id);
+        assert!(!debug_string.is_empty());
+    }
+
+    #[test]
+    fn test_from_str_falied_wrong_prefix() {
+        let uuid = Uuid::new_v4();
+        let format = format!("wrong_{}", uuid);
+        let result = PaymentId::from_str(&format);
+        assert!(matches!(result, Err(AggregateIdError::InvalidFormat)));
+    }
+
+    #[test]
+    fn test_value_reference() {
+        let id = PaymentId::new();
+        let value_ref = id.value();
+        assert_eq!(&id.value, value_ref);
+    }
+
+    #[test]
+    fn test_multiple_instances_unique() {
+        let id1 = PaymentId::new();
+        let id2 = PaymentId::new();
+        assert_ne!(id1, id2);
+    }
+
+    #[test]
+    fn test_from_str_empty_string() {
+        let result = PaymentId::from_str("");
+        assert!(matches!(result, Err(AggregateIdError::InvalidFormat)));
+    }
+
+    #[test]
+    fn test_from_str_too_many_parts() {
+        let result = PaymentId::from_str("pmt_uuid_extra");
+        assert!(matches!(result, Err(AggregateIdError::InvalidFormat)));
+    }
+}
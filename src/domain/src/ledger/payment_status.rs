@@ -0,0 +1,137 @@
+use crate::ledger::ledger_error::LedgerError;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// 支払記録のステータス
+///
+/// * `PENDING` - 支払予定日を迎えたが未確認
+/// * `PAID` - 支払済み
+/// * `SKIPPED` - 支払が発生しなかった(請求のスキップなど)
+/// * `FAILED` - 支払に失敗した
+/// * `REFUNDED` - 支払済みの金額が返金された
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PaymentStatus {
+    PENDING,
+    PAID,
+    SKIPPED,
+    FAILED,
+    REFUNDED,
+}
+
+impl PaymentStatus {
+    /// 指定したステータスへ遷移可能か判定する
+    ///
+    /// # 引数
+    /// * `next` - [PaymentStatus] 遷移先のステータス
+    ///
+    /// # 戻り値
+    /// - [bool] 遷移可能な場合はtrue
+    pub fn can_transition_to(&self, next: &PaymentStatus) -> bool {
+        matches!(
+            (self, next),
+            (PaymentStatus::PENDING, PaymentStatus::PAID)
+                | (PaymentStatus::PENDING, PaymentStatus::SKIPPED)
+                | (PaymentStatus::PENDING, PaymentStatus::FAILED)
+                | (PaymentStatus::FAILED, PaymentStatus::PAID)
+                | (PaymentStatus::FAILED, PaymentStatus::SKIPPED)
+                | (PaymentStatus::SKIPPED, PaymentStatus::PAID)
+                | (PaymentStatus::PAID, PaymentStatus::REFUNDED)
+        )
+    }
+
+    /// 指定したステータスへの遷移を検証する
+    ///
+    /// # 引数
+    /// * `next` - [PaymentStatus] 遷移先のステータス
+    ///
+    /// # エラー
+    /// - [LedgerError::InvalidStatusTransition] 許可されていない遷移の場合
+    pub fn validate_transition(&self, next: &PaymentStatus) -> Result<(), LedgerError> {
+        if self.can_transition_to(next) {
+            return Ok(());
+        }
+        Err(LedgerError::InvalidStatusTransition(self.to_string(), next.to_string()))
+    }
+
+    /// 実際に支出が発生したステータスか判定する
+    ///
+    /// # 戻り値
+    /// - [bool] PAIDの場合はtrue
+    pub fn is_spent(&self) -> bool {
+        self == &PaymentStatus::PAID
+    }
+}
+
+impl Display for PaymentStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PaymentStatus::PENDING => write!(f, "PENDING"),
+            PaymentStatus::PAID => write!(f, "PAID"),
+            PaymentStatus::SKIPPED => write!(f, "SKIPPED"),
+            PaymentStatus::FAILED => write!(f, "FAILED"),
+            PaymentStatus::REFUNDED => write!(f, "REFUNDED"),
+        }
+    }
+}
+
+impl FromStr for PaymentStatus {
+    type Err = LedgerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "PENDING" => Ok(PaymentStatus::PENDING),
+            "PAID" => Ok(PaymentStatus::PAID),
+            "SKIPPED" => Ok(PaymentStatus::SKIPPED),
+            "FAILED" => Ok(PaymentStatus::FAILED),
+            "REFUNDED" => Ok(PaymentStatus::REFUNDED),
+            _ => Err(LedgerError::InvalidPaymentStatus(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_status_fmt_and_from_str() {
+        let test_case = vec![
+            (PaymentStatus::PENDING, "PENDING"),
+            (PaymentStatus::PAID, "PAID"),
+            (PaymentStatus::SKIPPED, "SKIPPED"),
+            (PaymentStatus::FAILED, "FAILED"),
+            (PaymentStatus::REFUNDED, "REFUNDED"),
+        ];
+
+        for (status, str) in test_case {
+            assert_eq!(status.to_string(), str);
+            assert_eq!(PaymentStatus::from_str(str).unwrap(), status)
+        }
+    }
+
+    #[test]
+    fn test_payment_status_from_str_failed() {
+        let result = PaymentStatus::from_str("paid");
+        assert!(matches!(result, Err(LedgerError::InvalidPaymentStatus(_))))
+    }
+
+    #[test]
+    fn test_can_transition_to() {
+        let test_case = vec![
+            (PaymentStatus::PENDING, PaymentStatus::PAID, true),
+            (PaymentStatus::PENDING, PaymentStatus::SKIPPED, true),
+            (PaymentStatus::PENDING, PaymentStatus::FAILED, true),
+            (PaymentStatus::PENDING, PaymentStatus::REFUNDED, false),
+            (PaymentStatus::FAILED, PaymentStatus::PAID, true),
+            (PaymentStatus::SKIPPED, PaymentStatus::PAID, true),
+            (PaymentStatus::PAID, PaymentStatus::REFUNDED, true),
+            (PaymentStatus::PAID, PaymentStatus::FAILED, false),
+            (PaymentStatus::PAID, PaymentStatus::PAID, false),
+            (PaymentStatus::REFUNDED, PaymentStatus::PAID, false),
+        ];
+
+        for (from, to, expected) in test_case {
+            assert_eq!(from.can_transition_to(&to), expected, "{} -> {}", from, to)
+        }
+    }
+}
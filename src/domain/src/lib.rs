@@ -5,7 +5,10 @@ use std::str;
 use thiserror::Error;
 use uuid::Uuid;
 
+pub mod budget;
 pub mod category;
+pub mod event;
+pub mod ledger;
 pub mod payment;
 pub mod payment_cycle;
 pub mod repository;
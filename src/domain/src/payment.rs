@@ -19,6 +19,7 @@ use payment_method_name::PaymentMethodKindName;
 /// * `additional_name` - 追加の支払方法名（任意）
 /// * `created_at` - 作成日時
 /// * `updated_at` - 更新日時
+/// * `deleted_at` - ゴミ箱に移動した日時
 ///
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -30,6 +31,7 @@ pub struct PaymentMethod {
     additional_name: String,
     created_at: DateTime<Utc>,
     updated_at: Option<DateTime<Utc>>,
+    deleted_at: Option<DateTime<Utc>>,
 }
 
 impl PaymentMethod {
@@ -43,6 +45,7 @@ impl PaymentMethod {
     /// * `additional_name` - 追加の支払方法名（オプション）
     /// * `created_at` - 作成日時
     /// * `updated_at` - 更新日時（オプション）
+    /// * `deleted_at` - ゴミ箱に移動した日時（削除されていない場合は[None]）
     ///
     /// # 戻り値
     /// - [PaymentMethod] 作成された支払方法情報
@@ -54,6 +57,7 @@ impl PaymentMethod {
         additional_name: &str,
         created_at: DateTime<Utc>,
         updated_at: Option<DateTime<Utc>>,
+        deleted_at: Option<DateTime<Utc>>,
     ) -> Self {
         Self {
             payment_method_id,
@@ -63,6 +67,7 @@ impl PaymentMethod {
             additional_name: additional_name.to_string(),
             created_at,
             updated_at,
+            deleted_at,
         }
     }
 
@@ -94,6 +99,15 @@ impl PaymentMethod {
         &self.updated_at
     }
 
+    pub fn deleted_at(&self) -> &Option<DateTime<Utc>> {
+        &self.deleted_at
+    }
+
+    /// ゴミ箱に移動されているか判定する
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     fn _is_valid_method_combination(
         method_name: &PaymentMethodCategoryName,
         kind_name: &PaymentMethodKindName,
@@ -127,29 +141,30 @@ impl PaymentMethod {
         }
     }
 
-    pub fn make_updated_at(created_at: &Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
-        match created_at {
-            Some(_) => Some(Utc::now()),
-            None => None,
-        }
+    /// 新規登録として作成日時を記録する
+    ///
+    /// 更新日時はクリアする
+    ///
+    /// # 引数
+    /// * `now` - 現在日時
+    pub fn mark_created(&mut self, now: DateTime<Utc>) {
+        self.created_at = now;
+        self.updated_at = None;
     }
 
-    pub fn make_created_at(payment_id: &str, created_at: Option<DateTime<Utc>>) -> DateTime<Utc> {
-        if payment_id.is_empty() || created_at.is_none() {
-            return Utc::now();
-        }
-
-        created_at.unwrap_or_else(|| Utc::now())
+    /// 更新日時を記録する
+    ///
+    /// # 引数
+    /// * `now` - 現在日時
+    pub fn mark_updated(&mut self, now: DateTime<Utc>) {
+        self.updated_at = Some(now);
     }
 }
 #[cfg(test)]
 mod tests {
-    use chrono::Datelike;
-
     use super::*;
-    use crate::{
-        payment::payment_method_name::{BankTransfer, CreditCard, DigitalMoney, DigitalWallet, MobilePayment, BNPL},
-        AggregateId,
+    use crate::payment::payment_method_name::{
+        BankTransfer, CreditCard, DigitalMoney, DigitalWallet, MobilePayment, BNPL,
     };
 
     #[test]
@@ -170,6 +185,7 @@ mod tests {
             additional_name,
             created_at,
             updated_at,
+            None,
         );
 
         assert_eq!(payment_method_id, result.payment_method_id);
@@ -180,6 +196,7 @@ mod tests {
         assert_eq!(created_at, result.created_at);
         assert!(updated_at.is_some());
         assert_eq!(updated_at, result.updated_at);
+        assert!(!result.is_deleted());
     }
 
     #[test]
@@ -255,35 +272,26 @@ mod tests {
     }
 
     #[test]
-    fn test_make_updated_at_some() {
-        let result = PaymentMethod::make_updated_at(&Some(Utc::now()));
-        assert!(result.is_some())
-    }
-
-    #[test]
-    fn test_make_updated_at_none() {
-        let result = PaymentMethod::make_updated_at(&None);
-        assert!(result.is_none())
-    }
+    fn test_mark_created_and_updated() {
+        let created_at = Utc::now() - chrono::Duration::days(1);
+        let mut payment = PaymentMethod::new(
+            PaymentMethodId::new(),
+            UserId::new(),
+            PaymentMethodCategoryName::CreditCard,
+            PaymentMethodKindName::CreditCard(CreditCard::JCB),
+            "hoge",
+            Utc::now(),
+            Some(Utc::now()),
+            None,
+        );
 
-    #[test]
-    fn test_make_created_at_some_datetime() {
-        let datetime = Utc::now();
-        let id = PaymentMethodId::new();
-        let result = PaymentMethod::make_created_at(id.value(), Some(datetime));
+        payment.mark_created(created_at);
+        assert_eq!(payment.created_at(), &created_at);
+        assert!(payment.updated_at().is_none());
 
-        assert_eq!(datetime, result)
-    }
-
-    #[test]
-    fn test_make_created_at_none_datetime() {
-        let id = PaymentMethodId::new();
-        let result = PaymentMethod::make_created_at(id.value(), None);
-        let datetime = Utc::now();
-
-        assert!(!result.to_rfc3339().is_empty());
-        assert_eq!(result.year(), datetime.year());
-        assert_eq!(result.month(), datetime.month());
-        assert_eq!(result.day(), datetime.day())
+        let updated_at = Utc::now();
+        payment.mark_updated(updated_at);
+        assert_eq!(payment.created_at(), &created_at);
+        assert_eq!(payment.updated_at(), &Some(updated_at));
     }
 }
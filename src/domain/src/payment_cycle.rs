@@ -1,11 +1,30 @@
 use std::str::FromStr;
 
+use chrono::{DateTime, Months, TimeDelta, Utc};
+use rust_decimal::Decimal;
 use thiserror::Error;
 
+/// 支払周期
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum PaymentCycle {
+    Weekly,
     Monthly,
+    Quarterly,
+    SemiAnnually,
     Yearly,
+    /// N日・N週・Nヶ月ごとの任意の周期
+    Custom {
+        interval: u32,
+        unit: IntervalUnit,
+    },
+}
+
+/// 任意周期の単位
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum IntervalUnit {
+    Day,
+    Week,
+    Month,
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -14,46 +33,182 @@ pub enum PaymentCycleError {
     InvalidVariantStr(String),
 }
 
-impl PaymentCycle {
-    /// 支払いサイクルを文字列に変換する
+const CUSTOM_PREFIX: &str = "every";
+const CUSTOM_SEPARATOR: char = '_';
+
+impl IntervalUnit {
+    /// 単位を文字列に変換する
     ///
     /// # 戻り値
-    /// - [&str] 支払いサイクルを表す文字列
+    /// - [&str] 単位を表す複数形の文字列
     pub fn as_str(&self) -> &str {
         match self {
-            PaymentCycle::Monthly => "monthly",
-            PaymentCycle::Yearly => "yearly",
+            IntervalUnit::Day => "days",
+            IntervalUnit::Week => "weeks",
+            IntervalUnit::Month => "months",
+        }
+    }
+}
+
+impl FromStr for IntervalUnit {
+    type Err = PaymentCycleError;
+
+    /// 文字列から任意周期の単位を生成する
+    ///
+    /// # 引数
+    /// * `s` - [&str] "days", "weeks", "months" のいずれか(単数形も可)
+    ///
+    /// # 戻り値
+    /// - [Result<IntervalUnit, PaymentCycleError>] 生成された単位
+    ///
+    /// # エラー
+    /// - [PaymentCycleError::InvalidVariantStr] 該当しない文字列の場合
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "day" | "days" => Ok(Self::Day),
+            "week" | "weeks" => Ok(Self::Week),
+            "month" | "months" => Ok(Self::Month),
+            _ => Err(PaymentCycleError::InvalidVariantStr(s.to_owned())),
+        }
+    }
+}
+
+impl PaymentCycle {
+    /// 任意周期の支払いサイクルを生成する
+    ///
+    /// # 引数
+    /// * `interval` - [u32] 周期の間隔(1以上)
+    /// * `unit` - [IntervalUnit] 周期の単位
+    ///
+    /// # 戻り値
+    /// - [Result<PaymentCycle, PaymentCycleError>] 生成された支払いサイクル
+    ///
+    /// # エラー
+    /// - [PaymentCycleError::InvalidVariantStr] 間隔が0の場合
+    pub fn custom(interval: u32, unit: IntervalUnit) -> Result<Self, PaymentCycleError> {
+        if interval == 0 {
+            return Err(PaymentCycleError::InvalidVariantStr(format!(
+                "{CUSTOM_PREFIX}{CUSTOM_SEPARATOR}{interval}{CUSTOM_SEPARATOR}{}",
+                unit.as_str()
+            )));
+        }
+        Ok(Self::Custom { interval, unit })
+    }
+
+    /// 1年あたりの支払回数を取得する
+    ///
+    /// 週単位・日単位はどちらも365日を1年、7日を1週として計算するため、毎週と7日ごとは同じ回数になる
+    ///
+    /// # 戻り値
+    /// - [Decimal] 1年あたりの支払回数
+    pub fn payments_per_year(&self) -> Decimal {
+        let days_per_year = Decimal::from(365);
+        match self {
+            PaymentCycle::Weekly => days_per_year / Decimal::from(7),
+            PaymentCycle::Monthly => Decimal::from(12),
+            PaymentCycle::Quarterly => Decimal::from(4),
+            PaymentCycle::SemiAnnually => Decimal::from(2),
+            PaymentCycle::Yearly => Decimal::ONE,
+            PaymentCycle::Custom { interval, unit } => match unit {
+                IntervalUnit::Day => days_per_year / Decimal::from(*interval),
+                IntervalUnit::Week => days_per_year / Decimal::from(u64::from(*interval) * 7),
+                IntervalUnit::Month => Decimal::from(12) / Decimal::from(*interval),
+            },
+        }
+    }
+
+    /// 初回支払日からn回目の支払日を計算する
+    ///
+    /// 月単位の周期は初回支払日の日付を基準に計算し、存在しない日付は月末に丸める
+    /// (例: 1/31 → 2/28(閏年は2/29) → 3/31)
+    ///
+    /// # 引数
+    /// * `first` - [DateTime<Utc>] 初回支払日
+    /// * `n` - [u32] 初回を0とした支払回数
+    ///
+    /// # 戻り値
+    /// - [DateTime<Utc>] n回目の支払日
+    pub fn nth_payment_date(&self, first: &DateTime<Utc>, n: u32) -> DateTime<Utc> {
+        match self {
+            PaymentCycle::Weekly => add_days(first, n.saturating_mul(7)),
+            PaymentCycle::Monthly => add_months(first, n),
+            PaymentCycle::Quarterly => add_months(first, n.saturating_mul(3)),
+            PaymentCycle::SemiAnnually => add_months(first, n.saturating_mul(6)),
+            PaymentCycle::Yearly => add_months(first, n.saturating_mul(12)),
+            PaymentCycle::Custom { interval, unit } => match unit {
+                IntervalUnit::Day => add_days(first, n.saturating_mul(*interval)),
+                IntervalUnit::Week => add_days(first, n.saturating_mul(*interval).saturating_mul(7)),
+                IntervalUnit::Month => add_months(first, n.saturating_mul(*interval)),
+            },
         }
     }
 }
 
+fn add_months(date: &DateTime<Utc>, months: u32) -> DateTime<Utc> {
+    date.checked_add_months(Months::new(months)).unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+fn add_days(date: &DateTime<Utc>, days: u32) -> DateTime<Utc> {
+    TimeDelta::try_days(i64::from(days)).and_then(|d| date.checked_add_signed(d)).unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
 impl FromStr for PaymentCycle {
     type Err = PaymentCycleError;
 
     /// 文字列から支払いサイクルを生成する
     ///
     /// # 引数
-    /// * `s` - [&str] "monthly" または "yearly" の文字列
+    /// * `s` - [&str] "weekly", "monthly", "quarterly", "semi_annually", "yearly"
+    ///   または "every_{N}_{days|weeks|months}" 形式の文字列
     ///
     /// # 戻り値
-    /// - [Option<PaymentCycle>] 生成された支払いサイクル。該当しない文字列の場合は [PaymentCycle::Monthly]
+    /// - [Result<PaymentCycle, PaymentCycleError>] 生成された支払いサイクル
+    ///
+    /// # エラー
+    /// - [PaymentCycleError::InvalidVariantStr] 該当しない文字列の場合
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.to_lowercase().as_str() {
+        let invalid = || PaymentCycleError::InvalidVariantStr(s.to_owned());
+        let lower = s.to_lowercase();
+        match lower.as_str() {
+            "weekly" => Ok(Self::Weekly),
             "monthly" => Ok(Self::Monthly),
+            "quarterly" => Ok(Self::Quarterly),
+            "semi_annually" => Ok(Self::SemiAnnually),
             "yearly" => Ok(Self::Yearly),
-            _ => Ok(Self::Monthly),
+            _ => {
+                let mut parts = lower.split(CUSTOM_SEPARATOR);
+                let (Some(CUSTOM_PREFIX), Some(interval), Some(unit), None) =
+                    (parts.next(), parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(invalid());
+                };
+                let interval = interval.parse::<u32>().map_err(|_| invalid())?;
+                let unit = IntervalUnit::from_str(unit).map_err(|_| invalid())?;
+                Self::custom(interval, unit).map_err(|_| invalid())
+            }
         }
     }
 }
 
 impl std::fmt::Display for PaymentCycle {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.as_str())
+        match self {
+            PaymentCycle::Weekly => write!(f, "weekly"),
+            PaymentCycle::Monthly => write!(f, "monthly"),
+            PaymentCycle::Quarterly => write!(f, "quarterly"),
+            PaymentCycle::SemiAnnually => write!(f, "semi_annually"),
+            PaymentCycle::Yearly => write!(f, "yearly"),
+            PaymentCycle::Custom { interval, unit } => {
+                write!(f, "{CUSTOM_PREFIX}{CUSTOM_SEPARATOR}{interval}{CUSTOM_SEPARATOR}{}", unit.as_str())
+            }
+        }
     }
 }
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_payment_cycle_from_str_monthly() {
@@ -71,9 +226,85 @@ mod tests {
 
     #[test]
     fn test_payment_cycle_from_str_invalid() {
-        let result = PaymentCycle::from_str("invalid");
-        assert!(result.is_ok());
-        assert!(matches!(result.unwrap(), PaymentCycle::Monthly))
+        let test_case = vec![
+            "invalid",
+            "",
+            "every_0_days",
+            "every_3_years",
+            "every_x_weeks",
+            "every_3_weeks_1",
+        ];
+
+        for s in test_case {
+            let result = PaymentCycle::from_str(s);
+            assert_eq!(result, Err(PaymentCycleError::InvalidVariantStr(s.to_owned())), "input: {}", s)
+        }
+    }
+
+    #[test]
+    fn test_payment_cycle_from_str_round_trip() {
+        let test_case = vec![
+            (PaymentCycle::Weekly, "weekly"),
+            (PaymentCycle::Monthly, "monthly"),
+            (PaymentCycle::Quarterly, "quarterly"),
+            (PaymentCycle::SemiAnnually, "semi_annually"),
+            (PaymentCycle::Yearly, "yearly"),
+            (PaymentCycle::Custom { interval: 4, unit: IntervalUnit::Week }, "every_4_weeks"),
+            (PaymentCycle::Custom { interval: 10, unit: IntervalUnit::Day }, "every_10_days"),
+            (PaymentCycle::Custom { interval: 2, unit: IntervalUnit::Month }, "every_2_months"),
+        ];
+
+        for (cycle, s) in test_case {
+            assert_eq!(cycle.to_string(), s);
+            assert_eq!(PaymentCycle::from_str(s), Ok(cycle));
+        }
+    }
+
+    #[test]
+    fn test_payment_cycle_from_str_custom_singular_unit() {
+        let result = PaymentCycle::from_str("Every_1_Week");
+        assert_eq!(result, Ok(PaymentCycle::Custom { interval: 1, unit: IntervalUnit::Week }));
+    }
+
+    #[test]
+    fn test_payment_cycle_custom_zero_interval() {
+        let result = PaymentCycle::custom(0, IntervalUnit::Day);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_payments_per_year() {
+        let test_case = vec![
+            (PaymentCycle::Weekly, Decimal::from(365) / Decimal::from(7)),
+            (PaymentCycle::Monthly, Decimal::from(12)),
+            (PaymentCycle::Quarterly, Decimal::from(4)),
+            (PaymentCycle::SemiAnnually, Decimal::from(2)),
+            (PaymentCycle::Yearly, Decimal::ONE),
+            (PaymentCycle::Custom { interval: 4, unit: IntervalUnit::Week }, Decimal::from(365) / Decimal::from(28)),
+            (PaymentCycle::Custom { interval: 5, unit: IntervalUnit::Day }, Decimal::from(73)),
+            (PaymentCycle::Custom { interval: 3, unit: IntervalUnit::Month }, Decimal::from(4)),
+        ];
+
+        for (cycle, expected) in test_case {
+            assert_eq!(cycle.payments_per_year(), expected, "cycle: {}", cycle)
+        }
+    }
+
+    #[test]
+    fn test_payments_per_year_same_for_equivalent_cycles() {
+        let test_case = vec![
+            (PaymentCycle::Weekly, PaymentCycle::Custom { interval: 7, unit: IntervalUnit::Day }),
+            (PaymentCycle::Weekly, PaymentCycle::Custom { interval: 1, unit: IntervalUnit::Week }),
+            (
+                PaymentCycle::Custom { interval: 2, unit: IntervalUnit::Week },
+                PaymentCycle::Custom { interval: 14, unit: IntervalUnit::Day },
+            ),
+            (PaymentCycle::Monthly, PaymentCycle::Custom { interval: 1, unit: IntervalUnit::Month }),
+        ];
+
+        for (cycle, equivalent) in test_case {
+            assert_eq!(cycle.payments_per_year(), equivalent.payments_per_year(), "cycle: {}", cycle)
+        }
     }
 
     #[test]
@@ -84,14 +315,83 @@ mod tests {
     }
 
     #[test]
-    fn test_payment_cycle_as_str_monthly() {
+    fn test_payment_cycle_to_string_monthly() {
         let cycle = PaymentCycle::Monthly;
-        assert_eq!("monthly", cycle.as_str());
+        assert_eq!("monthly", cycle.to_string());
     }
 
     #[test]
-    fn test_payment_cycle_as_str_yearly() {
+    fn test_payment_cycle_to_string_yearly() {
         let cycle = PaymentCycle::Yearly;
-        assert_eq!("yearly", cycle.as_str());
+        assert_eq!("yearly", cycle.to_string());
+    }
+
+    #[test]
+    fn test_nth_payment_date_monthly_clamps_month_end() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let test_case = vec![
+            (0, Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap()),
+            (1, Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()),
+            (2, Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap()),
+            (3, Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap()),
+            (13, Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()),
+        ];
+
+        for (n, expected) in test_case {
+            assert_eq!(PaymentCycle::Monthly.nth_payment_date(&first, n), expected, "n: {}", n)
+        }
+    }
+
+    #[test]
+    fn test_nth_payment_date_yearly_leap_day() {
+        let first = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+        let test_case = vec![
+            (1, Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()),
+            (4, Utc.with_ymd_and_hms(2028, 2, 29, 0, 0, 0).unwrap()),
+        ];
+
+        for (n, expected) in test_case {
+            assert_eq!(PaymentCycle::Yearly.nth_payment_date(&first, n), expected, "n: {}", n)
+        }
+    }
+
+    #[test]
+    fn test_nth_payment_date_day_and_week_cycles() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let test_case = vec![
+            (PaymentCycle::Weekly, 2, Utc.with_ymd_and_hms(2024, 2, 14, 0, 0, 0).unwrap()),
+            (
+                PaymentCycle::Custom { interval: 4, unit: IntervalUnit::Week },
+                1,
+                Utc.with_ymd_and_hms(2024, 2, 28, 0, 0, 0).unwrap(),
+            ),
+            (
+                PaymentCycle::Custom { interval: 10, unit: IntervalUnit::Day },
+                3,
+                Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+            ),
+        ];
+
+        for (cycle, n, expected) in test_case {
+            assert_eq!(cycle.nth_payment_date(&first, n), expected, "cycle: {}", cycle)
+        }
+    }
+
+    #[test]
+    fn test_nth_payment_date_month_based_cycles() {
+        let first = Utc.with_ymd_and_hms(2024, 8, 31, 0, 0, 0).unwrap();
+        let test_case = vec![
+            (PaymentCycle::Quarterly, 1, Utc.with_ymd_and_hms(2024, 11, 30, 0, 0, 0).unwrap()),
+            (PaymentCycle::SemiAnnually, 1, Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap()),
+            (
+                PaymentCycle::Custom { interval: 2, unit: IntervalUnit::Month },
+                3,
+                Utc.with_ymd_and_hms(2025, 2, 28, 0, 0, 0).unwrap(),
+            ),
+        ];
+
+        for (cycle, n, expected) in test_case {
+            assert_eq!(cycle.nth_payment_date(&first, n), expected, "cycle: {}", cycle)
+        }
     }
 }
@@ -1,3 +1,6 @@
+pub mod budget_repository;
 pub mod category_repository;
+pub mod ledger_repository;
 pub mod payment_repository;
 pub mod subscribe_repository;
+pub mod user_repository;
@@ -0,0 +1,59 @@
+use crate::budget::budget_error::BudgetError;
+use crate::budget::budget_id::BudgetId;
+use crate::budget::Budget;
+use crate::user::user_id::UserId;
+use async_trait::async_trait;
+
+/// 予算を管理するリポジトリのトレイト定義
+#[async_trait]
+pub trait BudgetRepository: Send + Sync {
+    /// 予算を新規作成する
+    ///
+    /// # 引数
+    /// * `budget` - [Budget] 作成する予算
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(BudgetError)` - 作成処理が失敗した場合のエラー
+    async fn create(&self, budget: &Budget) -> Result<(), BudgetError>;
+
+    /// ユーザーの全ての予算を取得する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    ///
+    /// # 戻り値
+    /// - Vec<[Budget]> 予算のリスト
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<Budget>, BudgetError>;
+
+    /// 指定された予算を取得する
+    ///
+    /// # 引数
+    /// * `budget_id` - [BudgetId] 取得対象の予算ID
+    /// * `user_id` - [UserId] 予算の所有者ID
+    ///
+    /// # 戻り値
+    /// - [Budget] 予算
+    async fn find_by_id(&self, budget_id: &BudgetId, user_id: &UserId) -> Result<Budget, BudgetError>;
+
+    /// 予算の対象と上限額を更新する
+    ///
+    /// # 引数
+    /// * `budget` - [Budget] 更新する予算
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(BudgetError)` - 存在しない、または更新処理が失敗した場合のエラー
+    async fn update(&self, budget: &Budget) -> Result<(), BudgetError>;
+
+    /// 予算を削除する
+    ///
+    /// # 引数
+    /// * `budget_id` - [BudgetId] 削除する予算ID
+    /// * `user_id` - [UserId] 予算の所有者ID
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(BudgetError)` - 存在しない、または削除処理が失敗した場合のエラー
+    async fn delete(&self, budget_id: &BudgetId, user_id: &UserId) -> Result<(), BudgetError>;
+}
@@ -1,7 +1,9 @@
 use crate::category::category_error::CategoryError;
 use crate::category::category_id::CategoryId;
 use crate::category::Category;
+use crate::subscribe::Subscribe;
 use crate::user::user_id::UserId;
+use chrono::{DateTime, Utc};
 
 pub trait CategoryRepository: Send + Sync {
     fn create<'a>(
@@ -25,9 +27,54 @@ pub trait CategoryRepository: Send + Sync {
         category: &'a Category,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>>;
 
+    /// カテゴリの並び順をまとめて保存する
+    ///
+    /// 全てのカテゴリの並び順を1つのトランザクションで更新し、いずれかが失敗した場合は何も更新しない
+    fn reorder<'a>(
+        &'a self,
+        categories: &'a [Category],
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>>;
+
+    /// カテゴリをゴミ箱に移動する (データは削除せず削除日時を記録する)
     fn delete<'a>(
         &'a self,
         category_id: &'a CategoryId,
         user_id: &'a UserId,
     ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>>;
+
+    /// カテゴリをゴミ箱に移動し、所属していたサブスクの付け替えを同じトランザクションで保存する
+    ///
+    /// いずれかの書き込みが失敗した場合は何も更新しない
+    fn delete_with_reassignment<'a>(
+        &'a self,
+        category_id: &'a CategoryId,
+        user_id: &'a UserId,
+        subscribes: &'a [Subscribe],
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>>;
+
+    /// ゴミ箱に移動したカテゴリを元に戻す
+    fn restore<'a>(
+        &'a self,
+        category_id: &'a CategoryId,
+        user_id: &'a UserId,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>>;
+
+    /// ユーザーのゴミ箱にあるカテゴリを取得する
+    fn find_deleted<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Category>, CategoryError>> + Send + '_>>;
+
+    /// 全ユーザーから指定日時より前にゴミ箱に移動したカテゴリを取得する
+    fn find_deleted_before<'a>(
+        &'a self,
+        before: &'a DateTime<Utc>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Category>, CategoryError>> + Send + '_>>;
+
+    /// カテゴリを完全に削除する
+    fn purge<'a>(
+        &'a self,
+        category_id: &'a CategoryId,
+        user_id: &'a UserId,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>>;
 }
@@ -0,0 +1,54 @@
+use crate::ledger::ledger_error::LedgerError;
+use crate::ledger::payment_id::PaymentId;
+use crate::ledger::Payment;
+use crate::subscribe::subscribe_id::SubscribeId;
+use crate::user::user_id::UserId;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait LedgerRepository: Send + Sync {
+    /// 支払記録を保存する
+    ///
+    /// 同じ支払記録IDが存在する場合は上書きする
+    ///
+    /// # 引数
+    /// * `payment` - [Payment] 保存する支払記録
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(LedgerError)` - 保存処理が失敗した場合のエラー
+    async fn save(&self, payment: &Payment) -> Result<(), LedgerError>;
+
+    /// 指定された支払記録を取得する
+    ///
+    /// # 引数
+    /// * `payment_id` - [PaymentId] 取得対象の支払記録ID
+    /// * `user_id` - [UserId] 支払記録の所有者ID
+    ///
+    /// # 戻り値
+    /// - [Payment] 支払記録
+    async fn find_by_id(&self, payment_id: &PaymentId, user_id: &UserId) -> Result<Payment, LedgerError>;
+
+    /// ユーザーの全ての支払記録を取得する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    ///
+    /// # 戻り値
+    /// - Vec<[Payment]> 支払記録のリスト
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<Payment>, LedgerError>;
+
+    /// 指定したサブスクの支払記録を取得する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    /// * `subscribe_id` - [SubscribeId] 取得対象のサブスクID
+    ///
+    /// # 戻り値
+    /// - Vec<[Payment]> 支払記録のリスト
+    async fn find_by_subscribe(
+        &self,
+        user_id: &UserId,
+        subscribe_id: &SubscribeId,
+    ) -> Result<Vec<Payment>, LedgerError>;
+}
@@ -3,6 +3,7 @@ use crate::payment::payment_method_id::PaymentMethodId;
 use crate::payment::PaymentMethod;
 use crate::user::user_id::UserId;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 /// 支払い方法を管理するリポジトリのトレイト定義
 #[async_trait]
@@ -19,6 +20,8 @@ pub trait PaymentRepository: Send + Sync {
 
     /// ユーザーIDに紐づく全ての支払い方法を取得する
     ///
+    /// ゴミ箱に移動した支払い方法は含まない
+    ///
     /// # Arguments
     /// * `user_id` - 取得対象のユーザーID
     ///
@@ -29,6 +32,8 @@ pub trait PaymentRepository: Send + Sync {
 
     /// 指定されたIDの支払い方法を取得する
     ///
+    /// ゴミ箱に移動した支払い方法は存在しないものとして扱う
+    ///
     /// # Arguments
     /// * `payment_id` - 取得する支払い方法のID
     /// * `user_id` - ユーザーID
@@ -48,7 +53,9 @@ pub trait PaymentRepository: Send + Sync {
     /// * `Err(PaymentError)` - 更新処理が失敗した場合のエラー
     async fn update(&self, payment: &PaymentMethod) -> Result<(), PaymentError>;
 
-    /// 支払い方法を削除する
+    /// 支払い方法をゴミ箱に移動する
+    ///
+    /// データは削除せず、削除日時を記録する
     ///
     /// # Arguments
     /// * `payment_id` - 削除する支払い方法のID
@@ -59,7 +66,7 @@ pub trait PaymentRepository: Send + Sync {
     /// * `Err(PaymentError)` - 削除処理が失敗した場合のエラー
     async fn delete(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
 
-    /// データが存在するかチェック
+    /// データが存在するかチェック (ゴミ箱に移動したデータは存在しないものとして扱う)
     ///
     /// # Arguments
     /// * `payment_id` - 削除する支払い方法のID
@@ -69,4 +76,46 @@ pub trait PaymentRepository: Send + Sync {
     /// * `Ok(true)` -
     /// * `Err(PaymentError)` - データを取得できなかった場合のエラー
     async fn exists(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<bool, PaymentError>;
+
+    /// ゴミ箱に移動した支払い方法を元に戻す
+    ///
+    /// # Arguments
+    /// * `payment_id` - 対象の支払い方法のID
+    /// * `user_id` - ユーザーID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 元に戻した場合
+    /// * `Err(PaymentError)` - ゴミ箱に存在しない、または更新処理が失敗した場合のエラー
+    async fn restore(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
+
+    /// ユーザーIDに紐づくゴミ箱の支払い方法を取得する
+    ///
+    /// # Arguments
+    /// * `user_id` - 取得対象のユーザーID
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PaymentMethod>)` - ゴミ箱にある支払い方法のリスト
+    /// * `Err(PaymentError)` - 取得処理が失敗した場合のエラー
+    async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<PaymentMethod>, PaymentError>;
+
+    /// 全ユーザーから指定日時より前にゴミ箱に移動した支払い方法を取得する
+    ///
+    /// # Arguments
+    /// * `before` - 基準日時
+    ///
+    /// # Returns
+    /// * `Ok(Vec<PaymentMethod>)` - 支払い方法のリスト
+    /// * `Err(PaymentError)` - 取得処理が失敗した場合のエラー
+    async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<PaymentMethod>, PaymentError>;
+
+    /// 支払い方法を完全に削除する
+    ///
+    /// # Arguments
+    /// * `payment_id` - 削除する支払い方法のID
+    /// * `user_id` - ユーザーID
+    ///
+    /// # Returns
+    /// * `Ok(())` - 削除処理が成功した場合
+    /// * `Err(PaymentError)` - 削除処理が失敗した場合のエラー
+    async fn purge(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError>;
 }
@@ -1,8 +1,11 @@
+use crate::category::category_id::CategoryId;
 use crate::subscribe::subscribe_error::SubscribeError;
 use crate::subscribe::subscribe_id::SubscribeId;
+use crate::subscribe::subscribe_status::SubscribeStatus;
 use crate::subscribe::Subscribe;
 use crate::user::user_id::UserId;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 #[async_trait]
 pub trait SubscribeRepository: Send + Sync {
@@ -18,6 +21,8 @@ pub trait SubscribeRepository: Send + Sync {
 
     /// ユーザーの全てのサブスクを取得する
     ///
+    /// ゴミ箱に移動したサブスクは含まない
+    ///
     /// # 引数
     /// * `user_id` - [UserId] 取得対象のユーザーID
     ///
@@ -25,8 +30,26 @@ pub trait SubscribeRepository: Send + Sync {
     /// - Vec<[Subscribe]> サブスク情報のリスト
     async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
 
+    /// ユーザーのサブスクのうち指定したカテゴリのものを取得する
+    ///
+    /// ゴミ箱に移動したサブスクは含まない
+    ///
+    /// # 引数
+    /// * `category_id` - [CategoryId] 取得対象のカテゴリID
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    ///
+    /// # 戻り値
+    /// - Vec<[Subscribe]> サブスク情報のリスト
+    async fn find_by_category(
+        &self,
+        category_id: &CategoryId,
+        user_id: &UserId,
+    ) -> Result<Vec<Subscribe>, SubscribeError>;
+
     /// 指定されたサブスクを取得する
     ///
+    /// ゴミ箱に移動したサブスクは存在しないものとして扱う
+    ///
     /// # 引数
     /// * `subscribe_id` - [SubscribeId] 取得対象のサブスクID
     /// * `user_id` - [UserId] サブスクの所有者ID
@@ -35,6 +58,17 @@ pub trait SubscribeRepository: Send + Sync {
     /// - [Subscribe] サブスク情報（存在しない場合はNone）
     async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError>;
 
+    /// 全ユーザーから指定したステータスのサブスクを取得する
+    ///
+    /// バッチ処理向けのため、ユーザーを横断して取得する。ゴミ箱に移動したサブスクは含まない
+    ///
+    /// # 引数
+    /// * `status` - [SubscribeStatus] 取得対象のステータス
+    ///
+    /// # 戻り値
+    /// - Vec<[Subscribe]> サブスク情報のリスト
+    async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError>;
+
     /// サブスク情報を更新する
     ///
     /// # 引数
@@ -45,7 +79,9 @@ pub trait SubscribeRepository: Send + Sync {
     /// * `Err(SubscribeError)` - 更新処理が失敗した場合のエラー
     async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError>;
 
-    /// サブスクを削除する
+    /// サブスクをゴミ箱に移動する
+    ///
+    /// データは削除せず、削除日時を記録する
     ///
     /// # 引数
     /// * `subscribe_id` - [SubscribeId] 削除対象のサブスクID
@@ -53,6 +89,46 @@ pub trait SubscribeRepository: Send + Sync {
     ///
     /// # 戻り値                                                 
     /// * `Ok(())` - void                                        
-    /// * `Err(SubscribeError)` - 更新処理が失敗した場合のエラー
+    /// * `Err(SubscribeError)` - 存在しない、または更新処理が失敗した場合のエラー
     async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+
+    /// ゴミ箱に移動したサブスクを元に戻す
+    ///
+    /// # 引数
+    /// * `subscribe_id` - [SubscribeId] 対象のサブスクID
+    /// * `user_id` - [UserId] 対象のユーザーID
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(SubscribeError)` - ゴミ箱に存在しない、または更新処理が失敗した場合のエラー
+    async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
+
+    /// ユーザーのゴミ箱にあるサブスクを取得する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    ///
+    /// # 戻り値
+    /// - Vec<[Subscribe]> ゴミ箱にあるサブスク情報のリスト
+    async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError>;
+
+    /// 全ユーザーから指定日時より前にゴミ箱に移動したサブスクを取得する
+    ///
+    /// # 引数
+    /// * `before` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - Vec<[Subscribe]> サブスク情報のリスト
+    async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError>;
+
+    /// サブスクを完全に削除する
+    ///
+    /// # 引数
+    /// * `subscribe_id` - [SubscribeId] 削除対象のサブスクID
+    /// * `user_id` - [UserId] 削除対象のユーザーID
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(SubscribeError)` - 削除処理が失敗した場合のエラー
+    async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError>;
 }
@@ -1,8 +1,47 @@
 use crate::user::user_error::UserError;
+use crate::user::user_id::UserId;
 use crate::user::User;
 use async_trait::async_trait;
 
+/// ユーザーを管理するリポジトリのトレイト定義
 #[async_trait]
-pub trait UserRepository {
-    async fn create(&self, user: &User) -> Result<User, UserError>;
+pub trait UserRepository: Send + Sync {
+    /// ユーザーを新規作成する
+    ///
+    /// # 引数
+    /// * `user` - [User] 作成するユーザー
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(UserError)` - 同じユーザーIDが既に存在する、または作成処理が失敗した場合のエラー
+    async fn create(&self, user: &User) -> Result<(), UserError>;
+
+    /// 指定されたユーザーを取得する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    ///
+    /// # 戻り値
+    /// - [User] ユーザー
+    async fn find_by_id(&self, user_id: &UserId) -> Result<User, UserError>;
+
+    /// ユーザーのプロフィール、国、設定を更新する
+    ///
+    /// # 引数
+    /// * `user` - [User] 更新するユーザー
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(UserError)` - 存在しない、または更新処理が失敗した場合のエラー
+    async fn update(&self, user: &User) -> Result<(), UserError>;
+
+    /// ユーザーを削除する
+    ///
+    /// # 引数
+    /// * `user_id` - [UserId] 削除するユーザーID
+    ///
+    /// # 戻り値
+    /// * `Ok(())` - void
+    /// * `Err(UserError)` - 存在しない、または削除処理が失敗した場合のエラー
+    async fn delete(&self, user_id: &UserId) -> Result<(), UserError>;
 }
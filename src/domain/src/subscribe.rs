@@ -1,17 +1,33 @@
 use crate::category::category_id;
+use crate::event::{DomainEvent, DomainEventKind};
+use crate::subscribe::contract_term::ContractTerm;
+use crate::subscribe::cost_split::{CostSplit, MemberShare};
+use crate::subscribe::price_change::PriceChange;
+use crate::subscribe::scheduled_payment::ScheduledPayment;
+use crate::subscribe::subscribe_error::SubscribeError;
 use crate::subscribe::subscribe_id::SubscribeId;
 use crate::subscribe::subscribe_name::SubscribeName;
 use crate::subscribe::subscribe_status::SubscribeStatus;
+use crate::subscribe::tag::{Tag, TagSet};
+use crate::subscribe::trial::Trial;
 use crate::user::user_id::UserId;
 use crate::value_object::amount::Amount;
+use crate::value_object::currency::Currency;
+use crate::value_object::money::Money;
 use crate::{payment::payment_method_id::PaymentMethodId, payment_cycle::PaymentCycle};
 use chrono::{DateTime, Utc};
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 
+pub mod contract_term;
+pub mod cost_split;
+pub mod price_change;
+pub mod scheduled_payment;
 pub mod subscribe_error;
 pub mod subscribe_id;
 pub mod subscribe_name;
 pub mod subscribe_status;
+pub mod tag;
+pub mod trial;
 
 /// サブスク情報を管理する構造体
 #[derive(Debug, Clone)]
@@ -28,8 +44,8 @@ pub struct Subscribe {
     /// 支払方法ID
     payment_method_id: PaymentMethodId,
 
-    /// 金額
-    amount: Amount,
+    /// 1回の支払いで請求される金額(通貨付き)
+    amount: Money,
 
     /// 支払周期
     payment_cycle: PaymentCycle,
@@ -57,6 +73,38 @@ pub struct Subscribe {
 
     /// メモ欄
     memo: Option<String>,
+
+    /// 無料トライアル情報
+    trial: Option<Trial>,
+
+    /// 最低契約期間と解約の事前通知期間
+    contract_term: ContractTerm,
+
+    /// 費用の分担設定
+    cost_split: CostSplit,
+
+    /// カテゴリとは別に付けるタグ
+    tags: TagSet,
+
+    /// 作成日時
+    created_at: DateTime<Utc>,
+
+    /// 更新日時
+    updated_at: Option<DateTime<Utc>>,
+
+    /// ゴミ箱に移動した日時
+    deleted_at: Option<DateTime<Utc>>,
+
+    /// 金額の変更履歴(適用日時の昇順)
+    price_history: Vec<PriceChange>,
+
+    /// 発行待ちのドメインイベント (永続化しない)
+    events: Vec<DomainEvent>,
+}
+
+/// 換算金額を通貨の補助単位の桁数で四捨五入する
+fn round_equivalent(value: Decimal, currency: &Currency) -> Decimal {
+    value.round_dp_with_strategy(currency.minor_units(), RoundingStrategy::MidpointAwayFromZero).normalize()
 }
 
 impl Subscribe {
@@ -66,7 +114,7 @@ impl Subscribe {
     /// * `user_id` - [UserId] サブスクを作成するユーザーのID
     /// * `name` - [SubscribeName] サブスク名
     /// * `payment_method_id` - [PaymentMethodId] 支払方法ID
-    /// * `amount` - [Amount] 金額
+    /// * `amount` - [Money] 1回の支払いで請求される通貨付きの金額
     /// * `payment_cycle` - [PaymentCycle] 支払周期
     /// * `category_id` - カテゴリID
     /// * `icon_local_path` - アイコンのローカルパス
@@ -76,6 +124,10 @@ impl Subscribe {
     /// * `auto_renewal` - 自動更新フラグ
     /// * `status` - [SubscribeStatus] ステータス
     /// * `memo` - メモ欄
+    /// * `trial` - [Trial] 無料トライアル情報
+    /// * `contract_term` - [ContractTerm] 最低契約期間と解約の事前通知期間
+    /// * `cost_split` - [CostSplit] 費用の分担設定
+    /// * `tags` - [TagSet] タグ
     ///
     /// # 戻り値
     /// - [Subscribe] 作成されたサブスク情報
@@ -83,7 +135,7 @@ impl Subscribe {
         user_id: UserId,
         name: SubscribeName,
         payment_method_id: PaymentMethodId,
-        amount: Amount,
+        amount: Money,
         payment_cycle: PaymentCycle,
         category_id: category_id::CategoryId,
         icon_local_path: String,
@@ -93,9 +145,13 @@ impl Subscribe {
         auto_renewal: bool,
         status: SubscribeStatus,
         memo: Option<String>,
+        trial: Option<Trial>,
+        contract_term: ContractTerm,
+        cost_split: CostSplit,
+        tags: TagSet,
     ) -> Self {
         let id = SubscribeId::new();
-        let amount = Self::yearly_amount_per_monthly(amount, &payment_cycle);
+        let price_history = vec![PriceChange::new(first_payment_date, amount.clone())];
         Self {
             subscribe_id: id,
             user_id,
@@ -111,6 +167,15 @@ impl Subscribe {
             auto_renewal,
             status,
             memo,
+            trial,
+            contract_term,
+            cost_split,
+            tags,
+            created_at: Utc::now(),
+            updated_at: None,
+            deleted_at: None,
+            price_history,
+            events: vec![],
         }
     }
 
@@ -121,7 +186,7 @@ impl Subscribe {
     /// * `user_id` - [UserId] サブスクに紐づくユーザーID
     /// * `payment_method_id` - [PaymentMethodId] 支払方法ID
     /// * `name` - [SubscribeName] サブスク名
-    /// * `amount` - [Amount] 金額
+    /// * `amount` - [Money] 1回の支払いで請求される通貨付きの金額
     /// * `payment_cycle` - [PaymentCycle] 支払周期
     /// * `category_id` - カテゴリID
     /// * `icon_local_path` - アイコンのローカルパス
@@ -131,6 +196,14 @@ impl Subscribe {
     /// * `auto_renewal` - 自動更新フラグ
     /// * `status` - [SubscribeStatus] ステータス
     /// * `memo` - メモ欄
+    /// * `trial` - [Trial] 無料トライアル情報
+    /// * `contract_term` - [ContractTerm] 最低契約期間と解約の事前通知期間
+    /// * `cost_split` - [CostSplit] 費用の分担設定
+    /// * `tags` - [TagSet] タグ
+    /// * `created_at` - 作成日時
+    /// * `updated_at` - 更新日時。一度も更新されていない場合は[None]
+    /// * `deleted_at` - ゴミ箱に移動した日時。削除されていない場合は[None]
+    /// * `price_history` - [PriceChange] 金額の変更履歴。空の場合は初回支払日時点の金額で補完する
     ///
     /// # 戻り値
     /// - [Subscribe] 作成されたサブスク情報
@@ -139,7 +212,7 @@ impl Subscribe {
         user_id: UserId,
         name: SubscribeName,
         payment_method_id: PaymentMethodId,
-        amount: Amount,
+        amount: Money,
         payment_cycle: PaymentCycle,
         category_id: category_id::CategoryId,
         icon_local_path: String,
@@ -149,8 +222,23 @@ impl Subscribe {
         auto_renewal: bool,
         status: SubscribeStatus,
         memo: Option<String>,
+        trial: Option<Trial>,
+        contract_term: ContractTerm,
+        cost_split: CostSplit,
+        tags: TagSet,
+        created_at: DateTime<Utc>,
+        updated_at: Option<DateTime<Utc>>,
+        deleted_at: Option<DateTime<Utc>>,
+        price_history: Vec<PriceChange>,
     ) -> Self {
-        let amount = Self::yearly_amount_per_monthly(amount, &payment_cycle);
+        // 履歴を持たない既存データは初回支払日から現在の金額が適用されていたものとして扱う
+        let price_history = match price_history {
+            v if v.is_empty() => vec![PriceChange::new(first_payment_date, amount.clone())],
+            mut v => {
+                v.sort_by_key(|p| *p.effective_date());
+                v
+            }
+        };
         Self {
             subscribe_id,
             user_id,
@@ -166,19 +254,85 @@ impl Subscribe {
             auto_renewal,
             status,
             memo,
+            trial,
+            contract_term,
+            cost_split,
+            tags,
+            created_at,
+            updated_at,
+            deleted_at,
+            price_history,
+            events: vec![],
         }
     }
 
-    fn yearly_amount_per_monthly(amount: Amount, cycle: &PaymentCycle) -> Amount {
-        match cycle {
-            PaymentCycle::Yearly => {
-                let value = amount.value() / Decimal::from(12);
-                Amount::try_from(value.floor()).unwrap()
-            }
-            _ => amount,
+    /// 月額換算の金額を取得する
+    ///
+    /// 1年あたりの支払額を12で割った値を、通貨の補助単位の桁数で四捨五入する (JPYは整数、USDは小数点以下2桁)
+    ///
+    /// # 戻り値
+    /// - [Decimal] 月額換算の金額
+    pub fn monthly_equivalent(&self) -> Decimal {
+        self.monthly_of(*self.amount().value(), self.currency())
+    }
+
+    /// 年額換算の金額を取得する
+    ///
+    /// 1回の請求額に1年あたりの支払回数を掛けた値を、通貨の補助単位の桁数で四捨五入する
+    ///
+    /// # 戻り値
+    /// - [Decimal] 年額換算の金額
+    pub fn yearly_equivalent(&self) -> Decimal {
+        self.yearly_of(*self.amount().value(), self.currency())
+    }
+
+    /// 費用を分担している場合の、所有者自身の1回の請求あたりの負担額を取得する
+    ///
+    /// # 戻り値
+    /// - [Decimal] 分担していない場合は請求額そのもの
+    pub fn own_amount(&self) -> Decimal {
+        self.cost_split.own_share(&self.amount)
+    }
+
+    /// 所有者自身の負担額の月額換算を取得する
+    ///
+    /// # 戻り値
+    /// - [Decimal] 月額換算の負担額
+    pub fn own_monthly_equivalent(&self) -> Decimal {
+        self.monthly_of(self.own_amount(), self.currency())
+    }
+
+    /// 所有者自身の負担額の年額換算を取得する
+    ///
+    /// # 戻り値
+    /// - [Decimal] 年額換算の負担額
+    pub fn own_yearly_equivalent(&self) -> Decimal {
+        self.yearly_of(self.own_amount(), self.currency())
+    }
+
+    /// 所有者以外のメンバーごとの1回の請求あたりの負担額を取得する
+    ///
+    /// # 戻り値
+    /// - Vec<([MemberShare], [Decimal])> メンバーと負担額の組
+    pub fn owed_per_member(&self) -> Vec<(&MemberShare, Decimal)> {
+        self.cost_split.members().iter().map(|m| (m, m.owed(&self.amount))).collect()
+    }
+
+    fn monthly_of(&self, value: Decimal, currency: &Currency) -> Decimal {
+        match self.payment_cycle {
+            PaymentCycle::Monthly => value,
+            _ => round_equivalent(self.annual_total_of(value) / Decimal::from(12), currency),
         }
     }
 
+    fn yearly_of(&self, value: Decimal, currency: &Currency) -> Decimal {
+        round_equivalent(self.annual_total_of(value), currency)
+    }
+
+    fn annual_total_of(&self, value: Decimal) -> Decimal {
+        value * self.payment_cycle.payments_per_year()
+    }
+
     /// サブスクIDを取得する
     ///
     /// # 戻り値
@@ -216,6 +370,22 @@ impl Subscribe {
     /// # 戻り値
     /// - [Amount] 金額への参照
     pub fn amount(&self) -> &Amount {
+        self.amount.amount()
+    }
+
+    /// 通貨を取得する
+    ///
+    /// # 戻り値
+    /// - [Currency] 通貨への参照
+    pub fn currency(&self) -> &Currency {
+        self.amount.currency()
+    }
+
+    /// 通貨付きの金額を取得する
+    ///
+    /// # 戻り値
+    /// - [Money] 通貨付きの金額への参照
+    pub fn money(&self) -> &Money {
         &self.amount
     }
 
@@ -290,141 +460,1479 @@ impl Subscribe {
     pub fn memo(&self) -> &Option<String> {
         &self.memo
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::AggregateId;
-    use chrono::Utc;
-    use rstest::rstest;
-    use rust_decimal::Decimal;
+    /// 無料トライアル情報を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<Trial>] 無料トライアル情報への参照
+    pub fn trial(&self) -> &Option<Trial> {
+        &self.trial
+    }
 
-    #[test]
-    fn test_subscribe_new_success() {
-        let user_id = UserId::new();
-        let name = SubscribeName::new("hoge").unwrap();
-        let payment_method_id = PaymentMethodId::new();
-        let category_id = category_id::CategoryId::new();
-        let amount = Amount::try_from(Decimal::ONE_HUNDRED).unwrap();
-        let now = Utc::now();
+    /// 最低契約期間と解約の事前通知期間を取得する
+    ///
+    /// # 戻り値
+    /// - [ContractTerm] 契約条件への参照
+    pub fn contract_term(&self) -> &ContractTerm {
+        &self.contract_term
+    }
 
-        let result = Subscribe::new(
-            user_id,
-            name,
-            payment_method_id,
-            amount,
-            PaymentCycle::Monthly,
-            category_id,
-            String::from("/path/to/icon"),
-            true,
-            now,
-            now,
-            true,
-            SubscribeStatus::ACTIVE,
-            Some("テストメモ".to_owned()),
-        );
+    /// 費用の分担設定を取得する
+    ///
+    /// # 戻り値
+    /// - [CostSplit] 分担設定への参照
+    pub fn cost_split(&self) -> &CostSplit {
+        &self.cost_split
+    }
 
-        assert!(!result.subscribe_id.to_string().is_empty());
+    /// 作成日時を取得する
+    pub fn created_at(&self) -> &DateTime<Utc> {
+        &self.created_at
     }
 
-    #[test]
-    fn test_subscribe_from_success() {
-        let id = SubscribeId::new();
-        let user_id = UserId::new();
-        let name = SubscribeName::new("hoge").unwrap();
-        let payment_method_id = PaymentMethodId::new();
-        let category_id = category_id::CategoryId::new();
-        let amount = Amount::try_from(Decimal::ONE_HUNDRED).unwrap();
-        let now = Utc::now();
+    /// 更新日時を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<DateTime<Utc>>] 一度も更新されていない場合は[None]
+    pub fn updated_at(&self) -> &Option<DateTime<Utc>> {
+        &self.updated_at
+    }
 
-        let result = Subscribe::from(
-            id.clone(),
-            user_id,
-            name,
-            payment_method_id,
-            amount,
-            PaymentCycle::Yearly,
-            category_id,
-            String::from("/path/to/icon"),
-            true,
-            now,
-            now,
-            true,
-            SubscribeStatus::ACTIVE,
-            Some("テストメモ".to_owned()),
-        );
+    /// 新規登録として作成日時を記録する
+    ///
+    /// 更新日時はクリアする
+    ///
+    /// # 引数
+    /// * `now` - 現在日時
+    pub fn mark_created(&mut self, now: DateTime<Utc>) {
+        self.created_at = now;
+        self.updated_at = None;
+    }
 
-        assert!(!result.subscribe_id.to_string().is_empty());
-        assert_eq!(id.to_string(), result.subscribe_id.to_string())
+    /// 更新日時を記録する
+    ///
+    /// # 引数
+    /// * `now` - 現在日時
+    pub fn mark_updated(&mut self, now: DateTime<Utc>) {
+        self.updated_at = Some(now);
     }
 
-    #[test]
-    fn test_getters() {
-        let subscribe_id = SubscribeId::new();
-        let user_id = UserId::new();
-        let name = SubscribeName::new("hoge").unwrap();
-        let payment_method_id = PaymentMethodId::new();
-        let amount = Amount::try_from(Decimal::ONE_HUNDRED).unwrap();
-        let payment_cycle = PaymentCycle::Monthly;
-        let now = Utc::now();
-        let category_id = category_id::CategoryId::new();
-        let icon_path = String::from("/path/to/icon");
-        let notification = true;
-        let auto_renewal = true;
-        let status = SubscribeStatus::ACTIVE;
-        let memo = Some("テストメモ".to_owned());
+    /// ゴミ箱に移動した日時を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<DateTime<Utc>>] 削除されていない場合は[None]
+    pub fn deleted_at(&self) -> &Option<DateTime<Utc>> {
+        &self.deleted_at
+    }
 
-        let subscribe = Subscribe::from(
-            subscribe_id.clone(),
-            user_id.clone(),
-            name.clone(),
-            payment_method_id.clone(),
-            amount.clone(),
-            payment_cycle.clone(),
-            category_id.clone(),
-            icon_path.clone(),
-            notification,
-            now,
-            now,
-            auto_renewal,
-            status.clone(),
-            memo.clone(),
-        );
+    /// ゴミ箱に移動されているか判定する
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
 
-        assert_eq!(subscribe.subscribe_id().value().as_str(), &subscribe_id.to_string());
-        assert_eq!(subscribe.user_id(), &user_id);
-        assert_eq!(subscribe.name(), &name);
-        assert_eq!(subscribe.amount(), &amount);
-        assert_eq!(subscribe.payment_method_id(), &payment_method_id);
-        assert_eq!(subscribe.payment_cycle(), &payment_cycle);
-        assert_eq!(subscribe.category_id(), &category_id);
-        assert_eq!(subscribe.icon_local_path(), &icon_path);
-        assert_eq!(subscribe.notification(), notification);
-        assert_eq!(subscribe.first_payment_date(), &now);
-        assert_eq!(subscribe.next_payment_date(), &now);
-        assert_eq!(subscribe.auto_renewal(), auto_renewal);
-        assert_eq!(subscribe.status(), &status);
-        assert_eq!(subscribe.memo(), &memo);
+    /// タグを取得する
+    ///
+    /// # 戻り値
+    /// - [TagSet] タグの集合への参照
+    pub fn tags(&self) -> &TagSet {
+        &self.tags
     }
 
-    #[rstest]
-    #[case(1000, PaymentCycle::Yearly)]
-    #[case(1000, PaymentCycle::Monthly)]
-    #[case(5555, PaymentCycle::Yearly)]
-    fn test_yearly_amount_per_monthly(#[case] a: i32, #[case] b: PaymentCycle) {
-        let dec = Decimal::from(a);
-        let amount = Amount::try_from(dec).unwrap();
-        let result = Subscribe::yearly_amount_per_monthly(amount, &b);
-
-        match b {
-            PaymentCycle::Monthly => {
-                assert_eq!(result.value(), &dec)
-            }
-            PaymentCycle::Yearly => {
-                let u = dec / Decimal::from(12);
-                assert_eq!(result.value(), &u.floor())
-            }
+    /// タグを付け、新たに付けた場合はドメインイベントを記録する
+    ///
+    /// # 引数
+    /// * `tag` - [Tag] 付けるタグ
+    ///
+    /// # 戻り値
+    /// - [bool] 新たに付けた場合はtrue、既に付いていた場合はfalse
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidTag] タグの数が上限を超える場合
+    pub fn add_tag(&mut self, tag: Tag) -> Result<bool, SubscribeError> {
+        let added = self.tags.add(tag.clone())?;
+        if added {
+            self.record(DomainEventKind::TagAdded { subscribe_id: self.subscribe_id.clone(), tag });
+        }
+        Ok(added)
+    }
+
+    /// タグを外し、外した場合はドメインイベントを記録する
+    ///
+    /// # 引数
+    /// * `tag` - [Tag] 外すタグ
+    ///
+    /// # 戻り値
+    /// - [bool] 外した場合はtrue、付いていなかった場合はfalse
+    pub fn remove_tag(&mut self, tag: &Tag) -> bool {
+        let removed = self.tags.remove(tag);
+        if removed {
+            self.record(DomainEventKind::TagRemoved { subscribe_id: self.subscribe_id.clone(), tag: tag.clone() });
+        }
+        removed
+    }
+
+    /// 指定したタグが付いているか判定する
+    pub fn has_tag(&self, tag: &Tag) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// 金額の変更履歴を取得する
+    ///
+    /// # 戻り値
+    /// - [Vec<PriceChange>] 適用日時の昇順に並んだ変更履歴への参照
+    pub fn price_history(&self) -> &Vec<PriceChange> {
+        &self.price_history
+    }
+
+    /// 金額を変更し、変更履歴に記録する
+    ///
+    /// 現在の金額と同じ場合は何もしない
+    ///
+    /// # 引数
+    /// * `amount` - [Money] 変更後の金額
+    /// * `effective_date` - [DateTime<Utc>] 変更後の金額が適用される日時
+    ///
+    /// # 戻り値
+    /// - [bool] 金額を変更した場合はtrue
+    pub fn change_amount(&mut self, amount: Money, effective_date: DateTime<Utc>) -> bool {
+        if self.amount == amount {
+            return false;
+        }
+        self.price_history.push(PriceChange::new(effective_date, amount.clone()));
+        self.price_history.sort_by_key(|p| *p.effective_date());
+        let before = std::mem::replace(&mut self.amount, amount);
+        self.record(DomainEventKind::AmountChanged {
+            subscribe_id: self.subscribe_id.clone(),
+            before,
+            after: self.amount.clone(),
+        });
+        true
+    }
+
+    /// カテゴリを付け替える
+    ///
+    /// # 引数
+    /// * `category_id` - [category_id::CategoryId] 付け替え先のカテゴリID
+    ///
+    /// # 戻り値
+    /// - [bool] カテゴリを変更した場合はtrue
+    pub fn reassign_category(&mut self, category_id: category_id::CategoryId) -> bool {
+        if self.category_id == category_id {
+            return false;
+        }
+        self.category_id = category_id;
+        true
+    }
+
+    /// 保存済みのサブスクから変更履歴を引き継ぎ、金額が変わっていれば履歴に追加する
+    ///
+    /// # 引数
+    /// * `previous` - [Subscribe] 更新前のサブスク
+    /// * `effective_date` - [DateTime<Utc>] 金額が変わった場合の適用日時
+    pub fn inherit_price_history(&mut self, previous: &Subscribe, effective_date: DateTime<Utc>) {
+        let amount = std::mem::replace(&mut self.amount, previous.amount.clone());
+        self.price_history = previous.price_history.clone();
+        self.change_amount(amount, effective_date);
+    }
+
+    /// 一時停止・解約したままのサブスクは、保存済みのサブスクから課金が止まった日を引き継ぐ
+    ///
+    /// # 引数
+    /// * `previous` - [Subscribe] 更新前のサブスク
+    pub fn inherit_billing_stop(&mut self, previous: &Subscribe) {
+        if matches!(self.status, SubscribeStatus::PAUSED | SubscribeStatus::CANCELLED) && self.status == previous.status
+        {
+            self.next_payment_date = previous.next_payment_date;
+        }
+    }
+
+    /// 保存済みのサブスクからステータスが変わっていれば、変更を表すドメインイベントを記録する
+    ///
+    /// # 引数
+    /// * `previous` - [Subscribe] 更新前のサブスク
+    pub fn track_status_change(&mut self, previous: &Subscribe) {
+        if previous.status != self.status {
+            self.record(DomainEventKind::SubscribeStatusChanged {
+                subscribe_id: self.subscribe_id.clone(),
+                from: previous.status.clone(),
+                to: self.status.clone(),
+            });
         }
     }
+
+    /// 記録されたドメインイベントを取り出す
+    ///
+    /// # 戻り値
+    /// - Vec<[DomainEvent]> 発生順のドメインイベント。取り出した後は空になる
+    pub fn take_events(&mut self) -> Vec<DomainEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// 指定日時以降に適用された値上げを取得する
+    ///
+    /// # 引数
+    /// * `since` - [DateTime<Utc>] 対象とする期間の開始日時
+    ///
+    /// # 戻り値
+    /// - Vec<([PriceChange], [PriceChange])> 値上げ前と値上げ後の変更履歴の組
+    pub fn price_increases_since(&self, since: &DateTime<Utc>) -> Vec<(&PriceChange, &PriceChange)> {
+        self.price_history
+            .windows(2)
+            .filter(|w| {
+                // 通貨が変わった場合は金額を比較できないため値上げとして扱わない
+                w[1].effective_date() >= since
+                    && w[0].amount().currency() == w[1].amount().currency()
+                    && w[1].amount().amount().value() > w[0].amount().amount().value()
+            })
+            .map(|w| (&w[0], &w[1]))
+            .collect()
+    }
+
+    /// 基準日からN日以内に無料トライアルが終了するか判定する
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    /// * `days` - [u32] 日数
+    ///
+    /// # 戻り値
+    /// - [bool] TRIALのサブスクで、トライアルがN日以内に終了する場合はtrue
+    pub fn is_trial_ending_within(&self, base: &DateTime<Utc>, days: u32) -> bool {
+        match (&self.status, &self.trial) {
+            (SubscribeStatus::TRIAL, Some(trial)) => trial.ends_within(base, days),
+            _ => false,
+        }
+    }
+
+    /// 違約金なしで解約できる最終日を取得する
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [Option<DateTime<Utc>>] 解約の最終日。解約済み、または期限のない契約の場合は[None]
+    pub fn last_cancellation_date(&self, base: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if self.status == SubscribeStatus::CANCELLED {
+            return None;
+        }
+        self.contract_term.last_cancellation_date(base, &self.next_payment_date)
+    }
+
+    /// 基準日からN日以内に解約の最終日を迎えるか判定する
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    /// * `days` - [u32] 日数
+    ///
+    /// # 戻り値
+    /// - [bool] 解約の最終日が基準日当日からN日後までの場合はtrue
+    pub fn is_cancellation_window_closing(&self, base: &DateTime<Utc>, days: u32) -> bool {
+        let Some(deadline) = self.last_cancellation_date(base) else {
+            return false;
+        };
+        let deadline = deadline.date_naive();
+        let from = base.date_naive();
+        deadline >= from && (deadline - from).num_days() <= i64::from(days)
+    }
+
+    /// 終了した無料トライアルを有料契約に切り替える
+    ///
+    /// トライアル終了日を初回支払日とし、トライアル終了後の金額が設定されている場合は金額を置き換える
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [bool] 有料契約に切り替えた場合はtrue
+    pub fn convert_trial(&mut self, base: &DateTime<Utc>) -> bool {
+        if self.status != SubscribeStatus::TRIAL {
+            return false;
+        }
+        let Some(trial) = self.trial.take_if(|t| t.is_ended(base)) else {
+            return false;
+        };
+        if let Some(amount) = trial.post_trial_amount() {
+            self.change_amount(amount.clone(), *trial.end_date());
+        }
+        self.first_payment_date = *trial.end_date();
+        self.next_payment_date = *trial.end_date();
+        self.change_status(SubscribeStatus::ACTIVE);
+        true
+    }
+
+    /// サブスクを一時停止する
+    ///
+    /// ACTIVEのサブスクのみ一時停止できる
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidStatusTransition] ACTIVE以外の場合
+    pub fn pause(&mut self) -> Result<(), SubscribeError> {
+        self.transition(&[SubscribeStatus::ACTIVE], SubscribeStatus::PAUSED)
+    }
+
+    /// 一時停止中のサブスクを再開する
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidStatusTransition] PAUSED以外の場合
+    pub fn resume(&mut self) -> Result<(), SubscribeError> {
+        self.transition(&[SubscribeStatus::PAUSED], SubscribeStatus::ACTIVE)
+    }
+
+    /// サブスクを解約する
+    ///
+    /// TRIAL、ACTIVEまたはPAUSEDのサブスクのみ解約できる
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidStatusTransition] 既に解約済みの場合
+    pub fn cancel(&mut self) -> Result<(), SubscribeError> {
+        self.transition(
+            &[
+                SubscribeStatus::TRIAL,
+                SubscribeStatus::ACTIVE,
+                SubscribeStatus::PAUSED,
+            ],
+            SubscribeStatus::CANCELLED,
+        )
+    }
+
+    /// 解約済みのサブスクを再契約する
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidStatusTransition] CANCELLED以外の場合
+    pub fn reactivate(&mut self) -> Result<(), SubscribeError> {
+        self.transition(&[SubscribeStatus::CANCELLED], SubscribeStatus::ACTIVE)
+    }
+
+    /// 基準日時点での次回支払予定日を計算する
+    ///
+    /// 初回支払日と支払周期から、基準日以降で最初に到来する支払日を求める
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [DateTime<Utc>] 次回支払予定日
+    pub fn calculate_next_payment_date(&self, base: &DateTime<Utc>) -> DateTime<Utc> {
+        let mut n = 0;
+        loop {
+            let date = self.payment_cycle.nth_payment_date(&self.first_payment_date, n);
+            if date.date_naive() >= base.date_naive() || date == DateTime::<Utc>::MAX_UTC {
+                return date;
+            }
+            n += 1;
+        }
+    }
+
+    /// 次回支払予定日を初回支払日と支払周期から再計算する
+    ///
+    /// 無料トライアル中の場合はトライアル終了日を次回支払予定日とする。
+    /// 一時停止・解約したサブスクは次回支払予定日を課金が止まった日として扱うため再計算しない
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    pub fn refresh_next_payment_date(&mut self, base: &DateTime<Utc>) {
+        self.next_payment_date = match (&self.status, &self.trial) {
+            (SubscribeStatus::PAUSED | SubscribeStatus::CANCELLED, _) => return,
+            (SubscribeStatus::TRIAL, Some(trial)) => *trial.end_date(),
+            _ => self.calculate_next_payment_date(base),
+        };
+    }
+
+    /// 次回支払予定日が基準日より前になっているか判定する
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [bool] 次回支払予定日を過ぎている場合はtrue
+    pub fn is_payment_overdue(&self, base: &DateTime<Utc>) -> bool {
+        self.next_payment_date.date_naive() < base.date_naive()
+    }
+
+    /// 基準日以降で最初の支払予定日を取得する
+    ///
+    /// 次回支払予定日の繰り越しが済んでいない場合も、支払周期から基準日以降の支払日を求める
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [DateTime<Utc>] 基準日以降の支払予定日
+    pub fn upcoming_payment_date(&self, base: &DateTime<Utc>) -> DateTime<Utc> {
+        match self.is_payment_overdue(base) {
+            true => self.calculate_next_payment_date(base),
+            false => self.next_payment_date,
+        }
+    }
+
+    /// 基準日以前で最後に課金された支払予定日を取得する
+    ///
+    /// 次回支払予定日の繰り越し後も、支払周期から基準日時点で既に到来した支払日を求める
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - Option<[DateTime<Utc>]> 基準日以前の支払予定日。まだ課金されていない場合は [None]
+    pub fn latest_payment_date(&self, base: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let mut latest = None;
+        for n in 0.. {
+            let date = self.payment_cycle.nth_payment_date(&self.first_payment_date, n);
+            if date.date_naive() > base.date_naive() || date == DateTime::<Utc>::MAX_UTC || !self.is_billing_at(&date) {
+                break;
+            }
+            latest = Some(date);
+        }
+        latest
+    }
+
+    /// 指定日時に課金が続いていたか判定する
+    ///
+    /// 一時停止・解約したサブスクは、繰り越されずに残った次回支払予定日の前日まで課金されていたものとして扱う。
+    /// 無料トライアル中のサブスクは課金されていないものとする
+    ///
+    /// # 引数
+    /// * `date` - [DateTime<Utc>] 判定する日時
+    ///
+    /// # 戻り値
+    /// - [bool] 課金が続いていた場合はtrue
+    pub fn is_billing_at(&self, date: &DateTime<Utc>) -> bool {
+        match self.status {
+            SubscribeStatus::TRIAL => false,
+            SubscribeStatus::ACTIVE => &self.first_payment_date <= date,
+            SubscribeStatus::PAUSED | SubscribeStatus::CANCELLED => {
+                &self.first_payment_date <= date && date < &self.next_payment_date
+            }
+        }
+    }
+
+    /// 指定日時に適用されていた金額を取得する
+    ///
+    /// # 引数
+    /// * `date` - [DateTime<Utc>] 対象の日時
+    ///
+    /// # 戻り値
+    /// - [Money] 変更履歴から求めた金額。最初の変更より前の場合は最初の金額
+    pub fn price_at(&self, date: &DateTime<Utc>) -> &Money {
+        self.price_history
+            .iter()
+            .rev()
+            .find(|p| p.effective_date() <= date)
+            .or(self.price_history.first())
+            .map(|p| p.amount())
+            .unwrap_or(&self.amount)
+    }
+
+    /// 指定日時に適用されていた金額での、所有者自身の負担額の月額換算を取得する
+    ///
+    /// # 引数
+    /// * `date` - [DateTime<Utc>] 対象の日時
+    ///
+    /// # 戻り値
+    /// - Option<[Decimal]> 月額換算の負担額。課金されていなかった場合は [None]
+    pub fn own_monthly_equivalent_at(&self, date: &DateTime<Utc>) -> Option<Decimal> {
+        let price = self.price_at(date);
+        match self.is_billing_at(date) {
+            true => Some(self.monthly_of(self.cost_split.own_share(price), price.currency())),
+            false => None,
+        }
+    }
+
+    /// 指定した期間に発生する請求を支払予定日の順に列挙する
+    ///
+    /// 基準日以降で最初の支払予定日から支払周期に従って展開し、各支払予定日時点で適用される金額を請求額とする。
+    /// 一時停止・解約したサブスクは請求されない。無料トライアル中のサブスクはトライアル終了日に有料契約へ切り替わるものとする。
+    /// 自動更新しないサブスクは、最低契約期間の終了日より前の請求のみ発生する
+    ///
+    /// # 引数
+    /// * `from` - [DateTime<Utc>] 期間の開始日時
+    /// * `to` - [DateTime<Utc>] 期間の終了日時
+    ///
+    /// # 戻り値
+    /// - Vec<[ScheduledPayment]> 期間内の請求
+    pub fn scheduled_payments(&self, from: &DateTime<Utc>, to: &DateTime<Utc>) -> Vec<ScheduledPayment> {
+        match (&self.status, &self.trial) {
+            (SubscribeStatus::ACTIVE, _) => {}
+            (SubscribeStatus::TRIAL, Some(trial)) => {
+                let mut converted = self.clone();
+                return match converted.convert_trial(trial.end_date()) {
+                    true => converted.scheduled_payments(from, to),
+                    false => vec![],
+                };
+            }
+            _ => return vec![],
+        }
+
+        let renews_until = match self.auto_renewal {
+            true => DateTime::<Utc>::MAX_UTC,
+            false => self.contract_term.commitment_end_date().unwrap_or(DateTime::<Utc>::MIN_UTC),
+        };
+        let start = self.upcoming_payment_date(from);
+        let mut dates = vec![start];
+        for n in 0.. {
+            let date = self.payment_cycle.nth_payment_date(&self.first_payment_date, n);
+            if date > *to || date == DateTime::<Utc>::MAX_UTC {
+                break;
+            }
+            if date.date_naive() > start.date_naive() {
+                dates.push(date);
+            }
+        }
+
+        dates
+            .into_iter()
+            .filter(|d| d <= to && d < &renews_until)
+            .map(|d| {
+                let amount = self.price_at(&d).clone();
+                let own_amount = self.cost_split.own_share(&amount);
+                ScheduledPayment::new(d, amount, own_amount)
+            })
+            .collect()
+    }
+
+    /// 基準日以降で最初に発生する請求を取得する
+    ///
+    /// [Subscribe::scheduled_payments] と同じ規則で、期間を区切らずに直近の1件を求める
+    ///
+    /// # 引数
+    /// * `from` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - Option<[ScheduledPayment]> 直近の請求。今後請求が発生しない場合は [None]
+    pub fn next_scheduled_payment(&self, from: &DateTime<Utc>) -> Option<ScheduledPayment> {
+        if let (SubscribeStatus::TRIAL, Some(trial)) = (&self.status, &self.trial) {
+            let mut converted = self.clone();
+            return match converted.convert_trial(trial.end_date()) {
+                true => converted.next_scheduled_payment(from),
+                false => None,
+            };
+        }
+        let start = self.upcoming_payment_date(from);
+        self.scheduled_payments(from, &start).into_iter().next()
+    }
+
+    /// 支払予定日を過ぎたACTIVEのサブスクの次回支払予定日を繰り越す
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [bool] 次回支払予定日を更新した場合はtrue
+    pub fn roll_forward_next_payment_date(&mut self, base: &DateTime<Utc>) -> bool {
+        if self.status != SubscribeStatus::ACTIVE || !self.is_payment_overdue(base) {
+            return false;
+        }
+        self.refresh_next_payment_date(base);
+        true
+    }
+
+    fn transition(&mut self, from: &[SubscribeStatus], next: SubscribeStatus) -> Result<(), SubscribeError> {
+        if !from.contains(&self.status) {
+            return Err(SubscribeError::InvalidStatusTransition(self.status.to_string(), next.to_string()));
+        }
+        self.change_status(next);
+        Ok(())
+    }
+
+    /// ステータスを変更し、変更を表すドメインイベントを記録する
+    fn change_status(&mut self, next: SubscribeStatus) {
+        let from = std::mem::replace(&mut self.status, next);
+        self.record(DomainEventKind::SubscribeStatusChanged {
+            subscribe_id: self.subscribe_id.clone(),
+            from,
+            to: self.status.clone(),
+        });
+    }
+
+    /// 発行待ちのドメインイベントを記録する
+    fn record(&mut self, kind: DomainEventKind) {
+        self.events.push(DomainEvent::new(self.user_id.clone(), kind));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::payment_cycle::IntervalUnit;
+    use crate::AggregateId;
+    use chrono::{TimeZone, Utc};
+    use rstest::rstest;
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_subscribe_new_success() {
+        let user_id = UserId::new();
+        let name = SubscribeName::new("hoge").unwrap();
+        let payment_method_id = PaymentMethodId::new();
+        let category_id = category_id::CategoryId::new();
+        let amount = Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap();
+        let now = Utc::now();
+
+        let result = Subscribe::new(
+            user_id,
+            name,
+            payment_method_id,
+            amount,
+            PaymentCycle::Monthly,
+            category_id,
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now,
+            true,
+            SubscribeStatus::ACTIVE,
+            Some("テストメモ".to_owned()),
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        );
+
+        assert!(!result.subscribe_id.to_string().is_empty());
+        assert_eq!(result.price_history().len(), 1);
+        assert_eq!(result.price_history()[0].effective_date(), &now);
+    }
+
+    #[test]
+    fn test_subscribe_from_success() {
+        let id = SubscribeId::new();
+        let user_id = UserId::new();
+        let name = SubscribeName::new("hoge").unwrap();
+        let payment_method_id = PaymentMethodId::new();
+        let category_id = category_id::CategoryId::new();
+        let amount = Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap();
+        let now = Utc::now();
+
+        let result = Subscribe::from(
+            id.clone(),
+            user_id,
+            name,
+            payment_method_id,
+            amount,
+            PaymentCycle::Yearly,
+            category_id,
+            String::from("/path/to/icon"),
+            true,
+            now,
+            now,
+            true,
+            SubscribeStatus::ACTIVE,
+            Some("テストメモ".to_owned()),
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
+        );
+
+        assert!(!result.subscribe_id.to_string().is_empty());
+        assert_eq!(id.to_string(), result.subscribe_id.to_string())
+    }
+
+    #[test]
+    fn test_getters() {
+        let subscribe_id = SubscribeId::new();
+        let user_id = UserId::new();
+        let name = SubscribeName::new("hoge").unwrap();
+        let payment_method_id = PaymentMethodId::new();
+        let amount = Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap();
+        let payment_cycle = PaymentCycle::Monthly;
+        let now = Utc::now();
+        let category_id = category_id::CategoryId::new();
+        let icon_path = String::from("/path/to/icon");
+        let notification = true;
+        let auto_renewal = true;
+        let status = SubscribeStatus::ACTIVE;
+        let memo = Some("テストメモ".to_owned());
+
+        let subscribe = Subscribe::from(
+            subscribe_id.clone(),
+            user_id.clone(),
+            name.clone(),
+            payment_method_id.clone(),
+            amount.clone(),
+            payment_cycle.clone(),
+            category_id.clone(),
+            icon_path.clone(),
+            notification,
+            now,
+            now,
+            auto_renewal,
+            status.clone(),
+            memo.clone(),
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
+        );
+
+        assert_eq!(subscribe.subscribe_id().value().as_str(), &subscribe_id.to_string());
+        assert_eq!(subscribe.user_id(), &user_id);
+        assert_eq!(subscribe.name(), &name);
+        assert_eq!(subscribe.money(), &amount);
+        assert_eq!(subscribe.amount(), amount.amount());
+        assert_eq!(subscribe.currency(), &Currency::JPY);
+        assert_eq!(subscribe.payment_method_id(), &payment_method_id);
+        assert_eq!(subscribe.payment_cycle(), &payment_cycle);
+        assert_eq!(subscribe.category_id(), &category_id);
+        assert_eq!(subscribe.icon_local_path(), &icon_path);
+        assert_eq!(subscribe.notification(), notification);
+        assert_eq!(subscribe.first_payment_date(), &now);
+        assert_eq!(subscribe.next_payment_date(), &now);
+        assert_eq!(subscribe.auto_renewal(), auto_renewal);
+        assert_eq!(subscribe.status(), &status);
+        assert_eq!(subscribe.memo(), &memo);
+    }
+
+    fn create_subscribe_with_status(status: SubscribeStatus) -> Subscribe {
+        let now = Utc::now();
+        create_subscribe(status, PaymentCycle::Monthly, now, now)
+    }
+
+    fn create_subscribe(
+        status: SubscribeStatus,
+        payment_cycle: PaymentCycle,
+        first_payment_date: DateTime<Utc>,
+        next_payment_date: DateTime<Utc>,
+    ) -> Subscribe {
+        Subscribe::from(
+            SubscribeId::new(),
+            UserId::new(),
+            SubscribeName::new("hoge").unwrap(),
+            PaymentMethodId::new(),
+            Money::new(Amount::try_from(Decimal::ONE_HUNDRED).unwrap(), Currency::JPY).unwrap(),
+            payment_cycle,
+            category_id::CategoryId::new(),
+            String::from("/path/to/icon"),
+            true,
+            first_payment_date,
+            next_payment_date,
+            true,
+            status,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+            Utc::now(),
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    fn create_trial_subscribe(end_date: DateTime<Utc>, post_trial_amount: Option<i32>) -> Subscribe {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::TRIAL);
+        let post_trial_amount =
+            post_trial_amount.map(|a| Money::new(Amount::try_from(Decimal::from(a)).unwrap(), Currency::JPY).unwrap());
+        subscribe.trial = Some(Trial::new(end_date, post_trial_amount));
+        subscribe
+    }
+
+    #[test]
+    fn test_convert_trial_success() {
+        let end_date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_trial_subscribe(end_date, Some(1490));
+
+        let result = subscribe.convert_trial(&Utc.with_ymd_and_hms(2024, 3, 10, 9, 0, 0).unwrap());
+
+        assert!(result);
+        assert_eq!(subscribe.status(), &SubscribeStatus::ACTIVE);
+        assert_eq!(subscribe.amount().value(), &Decimal::from(1490));
+        assert_eq!(subscribe.first_payment_date(), &end_date);
+        assert_eq!(subscribe.next_payment_date(), &end_date);
+        assert!(subscribe.trial().is_none());
+    }
+
+    #[test]
+    fn test_convert_trial_keeps_amount_without_post_trial_amount() {
+        let end_date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_trial_subscribe(end_date, None);
+
+        assert!(subscribe.convert_trial(&Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap()));
+        assert_eq!(subscribe.amount().value(), &Decimal::ONE_HUNDRED);
+    }
+
+    #[test]
+    fn test_convert_trial_not_ended() {
+        let end_date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_trial_subscribe(end_date, Some(1490));
+
+        assert!(!subscribe.convert_trial(&Utc.with_ymd_and_hms(2024, 3, 9, 23, 0, 0).unwrap()));
+        assert_eq!(subscribe.status(), &SubscribeStatus::TRIAL);
+        assert!(subscribe.trial().is_some());
+    }
+
+    #[test]
+    fn test_convert_trial_ignores_non_trial_status() {
+        let end_date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_trial_subscribe(end_date, Some(1490));
+        subscribe.cancel().unwrap();
+
+        assert!(!subscribe.convert_trial(&Utc.with_ymd_and_hms(2024, 3, 20, 0, 0, 0).unwrap()));
+        assert_eq!(subscribe.status(), &SubscribeStatus::CANCELLED);
+    }
+
+    #[test]
+    fn test_is_trial_ending_within() {
+        let end_date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let subscribe = create_trial_subscribe(end_date, None);
+        let active = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        let base = Utc.with_ymd_and_hms(2024, 3, 7, 0, 0, 0).unwrap();
+
+        assert!(subscribe.is_trial_ending_within(&base, 3));
+        assert!(!subscribe.is_trial_ending_within(&base, 2));
+        assert!(!active.is_trial_ending_within(&base, 30));
+    }
+
+    fn jpy(amount: i32) -> Money {
+        Money::new(Amount::try_from(Decimal::from(amount)).unwrap(), Currency::JPY).unwrap()
+    }
+
+    #[test]
+    fn test_from_seeds_price_history() {
+        let subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+
+        assert_eq!(subscribe.price_history().len(), 1);
+        assert_eq!(subscribe.price_history()[0].amount(), subscribe.money());
+        assert_eq!(subscribe.price_history()[0].effective_date(), subscribe.first_payment_date());
+    }
+
+    #[test]
+    fn test_change_amount() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        let effective_date = Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+
+        assert!(!subscribe.change_amount(jpy(100), effective_date));
+        assert!(subscribe.change_amount(jpy(120), effective_date));
+        assert_eq!(subscribe.money(), &jpy(120));
+        assert_eq!(subscribe.price_history().len(), 2);
+        assert_eq!(subscribe.price_history()[1], PriceChange::new(effective_date, jpy(120)));
+    }
+
+    #[test]
+    fn test_change_amount_records_event() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+
+        subscribe.change_amount(jpy(120), Utc::now());
+
+        let events = subscribe.take_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].kind(),
+            &DomainEventKind::AmountChanged {
+                subscribe_id: subscribe.subscribe_id().clone(),
+                before: jpy(100),
+                after: jpy(120),
+            }
+        );
+        assert!(subscribe.take_events().is_empty());
+    }
+
+    #[test]
+    fn test_tag_change_records_event() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        let tag = Tag::from_str("仕事").unwrap();
+
+        assert!(subscribe.add_tag(tag.clone()).unwrap());
+        assert!(!subscribe.add_tag(tag.clone()).unwrap());
+        assert!(subscribe.remove_tag(&tag));
+        assert!(!subscribe.remove_tag(&tag));
+
+        let subscribe_id = subscribe.subscribe_id().clone();
+        let kinds: Vec<_> = subscribe.take_events().into_iter().map(|e| e.kind().clone()).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DomainEventKind::TagAdded { subscribe_id: subscribe_id.clone(), tag: tag.clone() },
+                DomainEventKind::TagRemoved { subscribe_id, tag },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_change_records_event() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        subscribe.pause().unwrap();
+        subscribe.cancel().unwrap();
+        assert!(subscribe.reactivate().is_ok());
+        assert!(subscribe.resume().is_err());
+
+        let transitions: Vec<_> = subscribe
+            .take_events()
+            .into_iter()
+            .map(|e| match e.kind() {
+                DomainEventKind::SubscribeStatusChanged { from, to, .. } => (from.clone(), to.clone()),
+                other => panic!("{:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            transitions,
+            vec![
+                (SubscribeStatus::ACTIVE, SubscribeStatus::PAUSED),
+                (SubscribeStatus::PAUSED, SubscribeStatus::CANCELLED),
+                (SubscribeStatus::CANCELLED, SubscribeStatus::ACTIVE),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_inherit_price_history() {
+        let mut previous = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        previous.change_amount(jpy(120), Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+        let effective_date = Utc.with_ymd_and_hms(2031, 1, 1, 0, 0, 0).unwrap();
+
+        let mut updated = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        updated.amount = jpy(150);
+        updated.inherit_price_history(&previous, effective_date);
+        assert_eq!(updated.money(), &jpy(150));
+        assert_eq!(updated.price_history().len(), 3);
+
+        let mut unchanged = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        unchanged.amount = jpy(120);
+        unchanged.inherit_price_history(&previous, effective_date);
+        assert_eq!(unchanged.price_history(), previous.price_history());
+    }
+
+    #[test]
+    fn test_price_increases_since() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        subscribe.change_amount(jpy(120), Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+        subscribe.change_amount(jpy(90), Utc.with_ymd_and_hms(2030, 6, 1, 0, 0, 0).unwrap());
+        subscribe.change_amount(jpy(150), Utc.with_ymd_and_hms(2031, 1, 1, 0, 0, 0).unwrap());
+
+        let all = subscribe.price_increases_since(&Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(all.len(), 2);
+
+        let recent = subscribe.price_increases_since(&Utc.with_ymd_and_hms(2030, 7, 1, 0, 0, 0).unwrap());
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].0.amount(), &jpy(90));
+        assert_eq!(recent[0].1.amount(), &jpy(150));
+    }
+
+    #[test]
+    fn test_price_increases_since_ignores_currency_change() {
+        let usd = |value: &str| Money::new(Amount::from_str(value).unwrap(), Currency::USD).unwrap();
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        subscribe.change_amount(usd("9.99"), Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+        subscribe.change_amount(usd("12.99"), Utc.with_ymd_and_hms(2030, 6, 1, 0, 0, 0).unwrap());
+        subscribe.change_amount(jpy(1500), Utc.with_ymd_and_hms(2031, 1, 1, 0, 0, 0).unwrap());
+
+        let result = subscribe.price_increases_since(&Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap());
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0.amount(), &usd("9.99"));
+        assert_eq!(result[0].1.amount(), &usd("12.99"));
+    }
+
+    #[test]
+    fn test_convert_trial_records_price_change() {
+        let end_date = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_trial_subscribe(end_date, Some(1490));
+
+        subscribe.convert_trial(&end_date);
+
+        assert!(subscribe.price_history().contains(&PriceChange::new(end_date, jpy(1490))));
+    }
+
+    #[test]
+    fn test_refresh_next_payment_date_trial() {
+        let end_date = Utc.with_ymd_and_hms(2030, 3, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_trial_subscribe(end_date, None);
+
+        subscribe.refresh_next_payment_date(&Utc.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap());
+
+        assert_eq!(subscribe.next_payment_date(), &end_date);
+    }
+
+    #[rstest]
+    #[case(PaymentCycle::Monthly, (2024, 1, 31), (2024, 2, 15), (2024, 2, 29))]
+    #[case(PaymentCycle::Monthly, (2024, 1, 31), (2024, 3, 31), (2024, 3, 31))]
+    #[case(PaymentCycle::Monthly, (2024, 1, 31), (2023, 12, 1), (2024, 1, 31))]
+    #[case(PaymentCycle::Monthly, (2023, 1, 31), (2023, 2, 28), (2023, 2, 28))]
+    #[case(PaymentCycle::Yearly, (2020, 5, 10), (2024, 5, 11), (2025, 5, 10))]
+    fn test_calculate_next_payment_date(
+        #[case] cycle: PaymentCycle,
+        #[case] first: (i32, u32, u32),
+        #[case] base: (i32, u32, u32),
+        #[case] expected: (i32, u32, u32),
+    ) {
+        let first = Utc.with_ymd_and_hms(first.0, first.1, first.2, 0, 0, 0).unwrap();
+        let base = Utc.with_ymd_and_hms(base.0, base.1, base.2, 12, 0, 0).unwrap();
+        let expected = Utc.with_ymd_and_hms(expected.0, expected.1, expected.2, 0, 0, 0).unwrap();
+        let subscribe = create_subscribe(SubscribeStatus::ACTIVE, cycle, first, first);
+
+        assert_eq!(subscribe.calculate_next_payment_date(&base), expected);
+    }
+
+    #[test]
+    fn test_roll_forward_next_payment_date_overdue_active() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let base = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, first);
+
+        assert!(subscribe.roll_forward_next_payment_date(&base));
+        assert_eq!(subscribe.next_payment_date(), &Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap());
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::PAUSED)]
+    #[case(SubscribeStatus::CANCELLED)]
+    fn test_roll_forward_next_payment_date_skips_inactive(#[case] status: SubscribeStatus) {
+        let first = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let base = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(status, PaymentCycle::Monthly, first, first);
+
+        assert!(!subscribe.roll_forward_next_payment_date(&base));
+        assert_eq!(subscribe.next_payment_date(), &first);
+    }
+
+    #[test]
+    fn test_roll_forward_next_payment_date_not_overdue() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap();
+        let base = Utc.with_ymd_and_hms(2024, 4, 30, 9, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, next);
+
+        assert!(!subscribe.roll_forward_next_payment_date(&base));
+        assert_eq!(subscribe.next_payment_date(), &next);
+    }
+
+    #[test]
+    fn test_upcoming_payment_date() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap();
+        let subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, first);
+        let scheduled = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, next);
+        let base = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(subscribe.upcoming_payment_date(&base), next);
+        assert_eq!(scheduled.upcoming_payment_date(&base), next);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, (2024, 1, 1), false)]
+    #[case(SubscribeStatus::ACTIVE, (2024, 2, 1), true)]
+    #[case(SubscribeStatus::ACTIVE, (2024, 6, 1), true)]
+    #[case(SubscribeStatus::CANCELLED, (2024, 3, 31), true)]
+    #[case(SubscribeStatus::CANCELLED, (2024, 4, 1), false)]
+    #[case(SubscribeStatus::PAUSED, (2024, 4, 1), false)]
+    #[case(SubscribeStatus::TRIAL, (2024, 3, 1), false)]
+    fn test_is_billing_at(#[case] status: SubscribeStatus, #[case] date: (i32, u32, u32), #[case] expected: bool) {
+        let first = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let subscribe = create_subscribe(status, PaymentCycle::Monthly, first, next);
+        let date = Utc.with_ymd_and_hms(date.0, date.1, date.2, 0, 0, 0).unwrap();
+
+        assert_eq!(subscribe.is_billing_at(&date), expected);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, (2024, 1, 15), None)]
+    #[case(SubscribeStatus::ACTIVE, (2024, 2, 1), Some((2024, 2, 1)))]
+    #[case(SubscribeStatus::ACTIVE, (2024, 4, 15), Some((2024, 4, 1)))]
+    #[case(SubscribeStatus::CANCELLED, (2024, 4, 15), Some((2024, 3, 1)))]
+    #[case(SubscribeStatus::TRIAL, (2024, 3, 15), None)]
+    fn test_latest_payment_date(
+        #[case] status: SubscribeStatus,
+        #[case] base: (i32, u32, u32),
+        #[case] expected: Option<(i32, u32, u32)>,
+    ) {
+        let first = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+        let subscribe = create_subscribe(status, PaymentCycle::Monthly, first, next);
+        let base = Utc.with_ymd_and_hms(base.0, base.1, base.2, 0, 0, 0).unwrap();
+        let expected = expected.map(|d| Utc.with_ymd_and_hms(d.0, d.1, d.2, 0, 0, 0).unwrap());
+
+        assert_eq!(subscribe.latest_payment_date(&base), expected);
+    }
+
+    fn payment_dates(payments: &[ScheduledPayment]) -> Vec<(u32, u32, Decimal)> {
+        use chrono::Datelike;
+        payments
+            .iter()
+            .map(|p| (p.payment_date().month(), p.payment_date().day(), *p.amount().amount().value()))
+            .collect()
+    }
+
+    #[test]
+    fn test_scheduled_payments_monthly() {
+        let first = Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, next);
+        subscribe.change_amount(jpy(120), Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 4, 15, 0, 0, 0).unwrap();
+
+        let result = subscribe.scheduled_payments(&from, &to);
+
+        assert_eq!(
+            payment_dates(&result),
+            vec![
+                (1, 31, Decimal::from(100)),
+                (2, 29, Decimal::from(100)),
+                (3, 31, Decimal::from(120))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scheduled_payments_yearly() {
+        let first = Utc.with_ymd_and_hms(2023, 5, 10, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 5, 10, 0, 0, 0).unwrap();
+        let subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Yearly, first, next);
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 12, 31, 0, 0, 0).unwrap();
+
+        assert_eq!(payment_dates(&subscribe.scheduled_payments(&from, &to)), vec![(5, 10, Decimal::from(100))]);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::PAUSED, true, None, 0)]
+    #[case(SubscribeStatus::CANCELLED, true, None, 0)]
+    #[case(SubscribeStatus::ACTIVE, false, None, 0)]
+    #[case(SubscribeStatus::ACTIVE, false, Some((2024, 3, 15)), 2)]
+    #[case(SubscribeStatus::ACTIVE, true, Some((2024, 3, 15)), 3)]
+    fn test_scheduled_payments_stopped(
+        #[case] status: SubscribeStatus,
+        #[case] auto_renewal: bool,
+        #[case] commitment_end_date: Option<(i32, u32, u32)>,
+        #[case] expected: usize,
+    ) {
+        let first = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(status, PaymentCycle::Monthly, first, first);
+        subscribe.auto_renewal = auto_renewal;
+        let commitment_end_date = commitment_end_date.map(|(y, m, d)| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap());
+        subscribe.contract_term = ContractTerm::new(commitment_end_date, None).unwrap();
+        let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+
+        assert_eq!(subscribe.scheduled_payments(&from, &to).len(), expected);
+    }
+
+    #[test]
+    fn test_scheduled_payments_trial() {
+        let end_date = Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap();
+        let subscribe = create_trial_subscribe(end_date, Some(500));
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+        let to = Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap();
+
+        let result = subscribe.scheduled_payments(&from, &to);
+
+        assert_eq!(
+            payment_dates(&result),
+            vec![
+                (2, 10, Decimal::from(500)),
+                (3, 10, Decimal::from(500)),
+                (4, 10, Decimal::from(500))
+            ]
+        );
+        assert_eq!(subscribe.status(), &SubscribeStatus::TRIAL);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, true, None, Some((2, 20)))]
+    #[case(SubscribeStatus::ACTIVE, false, Some((2024, 2, 25)), Some((2, 20)))]
+    #[case(SubscribeStatus::ACTIVE, false, Some((2024, 2, 15)), None)]
+    #[case(SubscribeStatus::PAUSED, true, None, None)]
+    fn test_next_scheduled_payment(
+        #[case] status: SubscribeStatus,
+        #[case] auto_renewal: bool,
+        #[case] commitment_end_date: Option<(i32, u32, u32)>,
+        #[case] expected: Option<(u32, u32)>,
+    ) {
+        use chrono::Datelike;
+        let first = Utc.with_ymd_and_hms(2024, 1, 20, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(status, PaymentCycle::Monthly, first, first);
+        subscribe.auto_renewal = auto_renewal;
+        let commitment_end_date = commitment_end_date.map(|(y, m, d)| Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap());
+        subscribe.contract_term = ContractTerm::new(commitment_end_date, None).unwrap();
+        let from = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+
+        let result = subscribe.next_scheduled_payment(&from);
+
+        assert_eq!(result.map(|p| (p.payment_date().month(), p.payment_date().day())), expected);
+    }
+
+    #[test]
+    fn test_next_scheduled_payment_trial() {
+        let end_date = Utc.with_ymd_and_hms(2024, 2, 10, 0, 0, 0).unwrap();
+        let subscribe = create_trial_subscribe(end_date, Some(500));
+        let from = Utc.with_ymd_and_hms(2024, 1, 15, 0, 0, 0).unwrap();
+
+        let result = subscribe.next_scheduled_payment(&from).unwrap();
+
+        assert_eq!(result.payment_date(), &end_date);
+        assert_eq!(result.amount(), &jpy(500));
+    }
+
+    #[test]
+    fn test_own_monthly_equivalent_at() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, first);
+        subscribe.change_amount(jpy(120), Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap());
+
+        let before = Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap();
+        let february = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+        let march = Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+        assert_eq!(subscribe.own_monthly_equivalent_at(&before), None);
+        assert_eq!(subscribe.price_at(&february), &jpy(100));
+        assert_eq!(subscribe.own_monthly_equivalent_at(&february), Some(Decimal::from(100)));
+        assert_eq!(subscribe.own_monthly_equivalent_at(&march), Some(Decimal::from(120)));
+    }
+
+    #[test]
+    fn test_own_monthly_equivalent_at_after_editing_paused() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let stopped = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let mut current = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, stopped);
+        current.pause().unwrap();
+
+        let mut edited = current.clone();
+        edited.memo = Some("メモを変更".to_owned());
+        edited.next_payment_date = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        edited.refresh_next_payment_date(&Utc.with_ymd_and_hms(2024, 6, 15, 0, 0, 0).unwrap());
+        edited.inherit_billing_stop(&current);
+
+        assert_eq!(edited.next_payment_date(), &stopped);
+        let february = Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap();
+        let april = Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap();
+        assert_eq!(edited.own_monthly_equivalent_at(&february), Some(Decimal::from(100)));
+        assert_eq!(edited.own_monthly_equivalent_at(&april), None);
+    }
+
+    #[test]
+    fn test_own_monthly_equivalent_at_rounds_with_historical_currency() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Yearly, first, first);
+        let usd = Money::new(Amount::from_str("100").unwrap(), Currency::USD).unwrap();
+        subscribe.change_amount(usd, Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap());
+        subscribe.change_amount(jpy(1500), Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+
+        let june = Utc.with_ymd_and_hms(2024, 6, 30, 0, 0, 0).unwrap();
+        assert_eq!(subscribe.own_monthly_equivalent_at(&june), Some(Decimal::from_str("8.33").unwrap()));
+        assert_eq!(subscribe.own_monthly_equivalent_at(&first), Some(Decimal::from(8)));
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, true)]
+    #[case(SubscribeStatus::PAUSED, false)]
+    #[case(SubscribeStatus::CANCELLED, false)]
+    fn test_pause(#[case] status: SubscribeStatus, #[case] ok: bool) {
+        let mut subscribe = create_subscribe_with_status(status.clone());
+        let result = subscribe.pause();
+
+        assert_eq!(result.is_ok(), ok);
+        let expected = if ok { SubscribeStatus::PAUSED } else { status };
+        assert_eq!(subscribe.status(), &expected);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, false)]
+    #[case(SubscribeStatus::PAUSED, true)]
+    #[case(SubscribeStatus::CANCELLED, false)]
+    fn test_resume(#[case] status: SubscribeStatus, #[case] ok: bool) {
+        let mut subscribe = create_subscribe_with_status(status.clone());
+        let result = subscribe.resume();
+
+        assert_eq!(result.is_ok(), ok);
+        let expected = if ok { SubscribeStatus::ACTIVE } else { status };
+        assert_eq!(subscribe.status(), &expected);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, true)]
+    #[case(SubscribeStatus::PAUSED, true)]
+    #[case(SubscribeStatus::CANCELLED, false)]
+    fn test_cancel(#[case] status: SubscribeStatus, #[case] ok: bool) {
+        let mut subscribe = create_subscribe_with_status(status.clone());
+        let result = subscribe.cancel();
+
+        assert_eq!(result.is_ok(), ok);
+        let expected = if ok { SubscribeStatus::CANCELLED } else { status };
+        assert_eq!(subscribe.status(), &expected);
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, false)]
+    #[case(SubscribeStatus::PAUSED, false)]
+    #[case(SubscribeStatus::CANCELLED, true)]
+    fn test_reactivate(#[case] status: SubscribeStatus, #[case] ok: bool) {
+        let mut subscribe = create_subscribe_with_status(status.clone());
+        let result = subscribe.reactivate();
+
+        assert_eq!(result.is_ok(), ok);
+        let expected = if ok { SubscribeStatus::ACTIVE } else { status };
+        assert_eq!(subscribe.status(), &expected);
+    }
+
+    #[test]
+    fn test_transition_error() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::CANCELLED);
+        let result = subscribe.pause();
+
+        assert!(matches!(
+            result,
+            Err(SubscribeError::InvalidStatusTransition(from, to)) if from == "CANCELLED" && to == "PAUSED"
+        ));
+    }
+
+    #[rstest]
+    #[case(5555, Currency::JPY, PaymentCycle::Yearly, "463", "5555")]
+    #[case(5555, Currency::USD, PaymentCycle::Yearly, "462.92", "5555")]
+    #[case(1000, Currency::JPY, PaymentCycle::Monthly, "1000", "12000")]
+    #[case(1000, Currency::USD, PaymentCycle::Weekly, "4345.24", "52142.86")]
+    #[case(1200, Currency::JPY, PaymentCycle::Quarterly, "400", "4800")]
+    #[case(1200, Currency::JPY, PaymentCycle::SemiAnnually, "200", "2400")]
+    #[case(1200, Currency::JPY, PaymentCycle::Custom { interval: 4, unit: IntervalUnit::Week }, "1304", "15643")]
+    #[case(100, Currency::USD, PaymentCycle::Custom { interval: 10, unit: IntervalUnit::Day }, "304.17", "3650")]
+    #[case(1000, Currency::JPY, PaymentCycle::Custom { interval: 3, unit: IntervalUnit::Day }, "10139", "121667")]
+    #[case(1000, Currency::USD, PaymentCycle::Custom { interval: 3, unit: IntervalUnit::Day }, "10138.89", "121666.67")]
+    fn test_monthly_and_yearly_equivalent(
+        #[case] a: i32,
+        #[case] currency: Currency,
+        #[case] cycle: PaymentCycle,
+        #[case] monthly: &str,
+        #[case] yearly: &str,
+    ) {
+        let now = Utc::now();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, cycle, now, now);
+        subscribe.amount = Money::new(Amount::try_from(Decimal::from(a)).unwrap(), currency).unwrap();
+
+        assert_eq!(subscribe.monthly_equivalent(), Decimal::from_str(monthly).unwrap());
+        assert_eq!(subscribe.yearly_equivalent(), Decimal::from_str(yearly).unwrap());
+    }
+
+    #[rstest]
+    #[case(SubscribeStatus::ACTIVE, (2024, 6, 5), 7, true)]
+    #[case(SubscribeStatus::ACTIVE, (2024, 6, 1), 7, false)]
+    #[case(SubscribeStatus::ACTIVE, (2024, 6, 11), 7, false)]
+    #[case(SubscribeStatus::CANCELLED, (2024, 6, 5), 7, false)]
+    fn test_is_cancellation_window_closing(
+        #[case] status: SubscribeStatus,
+        #[case] base: (i32, u32, u32),
+        #[case] days: u32,
+        #[case] expected: bool,
+    ) {
+        let first = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let next = Utc.with_ymd_and_hms(2024, 7, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(status, PaymentCycle::Monthly, first, next);
+        subscribe.contract_term = ContractTerm::new(None, Some(30)).unwrap();
+        let base = Utc.with_ymd_and_hms(base.0, base.1, base.2, 0, 0, 0).unwrap();
+
+        assert_eq!(subscribe.is_cancellation_window_closing(&base, days), expected);
+    }
+
+    #[test]
+    fn test_last_cancellation_date_during_commitment() {
+        let first = Utc.with_ymd_and_hms(2024, 1, 10, 0, 0, 0).unwrap();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, first, first);
+        let commitment_end = Utc.with_ymd_and_hms(2026, 1, 9, 0, 0, 0).unwrap();
+        subscribe.contract_term = ContractTerm::new(Some(commitment_end), Some(14)).unwrap();
+
+        let result = subscribe.last_cancellation_date(&first);
+
+        assert_eq!(result, Some(Utc.with_ymd_and_hms(2025, 12, 26, 0, 0, 0).unwrap()));
+    }
+
+    #[rstest]
+    #[case(2800, PaymentCycle::Monthly, "700", "700", "8400")]
+    #[case(33600, PaymentCycle::Yearly, "8400", "700", "8400")]
+    fn test_own_equivalent_with_cost_split(
+        #[case] amount: i32,
+        #[case] cycle: PaymentCycle,
+        #[case] own_amount: &str,
+        #[case] monthly: &str,
+        #[case] yearly: &str,
+    ) {
+        use crate::subscribe::cost_split::{Share, SplitMember};
+
+        let now = Utc::now();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, cycle, now, now);
+        subscribe.amount = Money::new(Amount::try_from(Decimal::from(amount)).unwrap(), Currency::JPY).unwrap();
+        subscribe.cost_split = CostSplit::new(vec![
+            MemberShare::new(SplitMember::Person("hoge".to_string()), Share::Percentage(Decimal::from(50))).unwrap(),
+            MemberShare::new(SplitMember::User(UserId::new()), Share::Percentage(Decimal::from(25))).unwrap(),
+        ])
+        .unwrap();
+
+        assert_eq!(subscribe.own_amount(), Decimal::from_str(own_amount).unwrap());
+        assert_eq!(subscribe.own_monthly_equivalent(), Decimal::from_str(monthly).unwrap());
+        assert_eq!(subscribe.own_yearly_equivalent(), Decimal::from_str(yearly).unwrap());
+        assert_eq!(subscribe.owed_per_member().len(), 2);
+    }
+
+    #[test]
+    fn test_add_and_remove_tag() {
+        let now = Utc::now();
+        let mut subscribe = create_subscribe(SubscribeStatus::ACTIVE, PaymentCycle::Monthly, now, now);
+        let tag = Tag::new("Shared").unwrap();
+
+        assert!(subscribe.add_tag(tag.clone()).unwrap());
+        assert!(!subscribe.add_tag(Tag::new("shared").unwrap()).unwrap());
+        assert!(subscribe.has_tag(&tag));
+        assert_eq!(subscribe.tags().len(), 1);
+        assert!(subscribe.remove_tag(&tag));
+        assert!(!subscribe.has_tag(&tag));
+    }
+
+    #[test]
+    fn test_mark_created_and_updated() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        let created = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+        let updated = Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+
+        subscribe.mark_updated(updated);
+        subscribe.mark_created(created);
+        assert_eq!(subscribe.created_at(), &created);
+        assert!(subscribe.updated_at().is_none());
+
+        subscribe.mark_updated(updated);
+        assert_eq!(subscribe.created_at(), &created);
+        assert_eq!(subscribe.updated_at(), &Some(updated));
+    }
+
+    #[test]
+    fn test_reassign_category() {
+        let mut subscribe = create_subscribe_with_status(SubscribeStatus::ACTIVE);
+        let current = subscribe.category_id().clone();
+        let target = category_id::CategoryId::new();
+
+        assert!(!subscribe.reassign_category(current));
+        assert!(subscribe.reassign_category(target.clone()));
+        assert_eq!(subscribe.category_id(), &target);
+    }
+
+    #[test]
+    fn test_amount_is_stored_as_billed() {
+        let amount = Money::new(Amount::try_from(Decimal::from(5555)).unwrap(), Currency::JPY).unwrap();
+        let subscribe = Subscribe::new(
+            UserId::new(),
+            SubscribeName::new("hoge").unwrap(),
+            PaymentMethodId::new(),
+            amount.clone(),
+            PaymentCycle::Yearly,
+            category_id::CategoryId::new(),
+            String::new(),
+            true,
+            Utc::now(),
+            Utc::now(),
+            true,
+            SubscribeStatus::ACTIVE,
+            None,
+            None,
+            ContractTerm::default(),
+            CostSplit::default(),
+            TagSet::default(),
+        );
+
+        assert_eq!(subscribe.money(), &amount);
+    }
 }
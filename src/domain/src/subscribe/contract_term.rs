@@ -0,0 +1,118 @@
+use crate::subscribe::subscribe_error::SubscribeError;
+use chrono::{DateTime, Duration, Utc};
+
+/// 解約の事前通知期間として指定できる最大日数
+pub const MAX_NOTICE_PERIOD_DAYS: u32 = 365;
+
+/// 最低契約期間と解約の事前通知期間を表す値オブジェクト
+///
+/// 2年縛りのように違約金なしで解約できる時期が決まっている契約や、
+/// 更新日のN日前までに解約を申し出る必要がある契約を表現する
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct ContractTerm {
+    /// 最低契約期間の終了日
+    commitment_end_date: Option<DateTime<Utc>>,
+
+    /// 解約の事前通知期間(日数)
+    notice_period_days: Option<u32>,
+}
+
+impl ContractTerm {
+    /// 契約条件を生成する
+    ///
+    /// # 引数
+    /// * `commitment_end_date` - [Option<DateTime<Utc>>] 最低契約期間の終了日
+    /// * `notice_period_days` - [Option<u32>] 解約の事前通知期間(日数)
+    ///
+    /// # 戻り値
+    /// - [ContractTerm] 生成された契約条件
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidContractTerm] 事前通知期間が上限を超える場合
+    pub fn new(
+        commitment_end_date: Option<DateTime<Utc>>,
+        notice_period_days: Option<u32>,
+    ) -> Result<Self, SubscribeError> {
+        if notice_period_days.is_some_and(|d| d > MAX_NOTICE_PERIOD_DAYS) {
+            return Err(SubscribeError::InvalidContractTerm(format!(
+                "notice period must be {MAX_NOTICE_PERIOD_DAYS} days or less"
+            )));
+        }
+        Ok(Self { commitment_end_date, notice_period_days })
+    }
+
+    /// 最低契約期間の終了日を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<DateTime<Utc>>] 最低契約期間の終了日への参照
+    pub fn commitment_end_date(&self) -> &Option<DateTime<Utc>> {
+        &self.commitment_end_date
+    }
+
+    /// 解約の事前通知期間を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<u32>] 解約の事前通知期間(日数)
+    pub fn notice_period_days(&self) -> Option<u32> {
+        self.notice_period_days
+    }
+
+    /// 違約金なしで解約できる最終日を計算する
+    ///
+    /// 最低契約期間中は契約期間の終了日、それ以降は次回の更新日(次回支払予定日)を基準に、
+    /// 事前通知期間の日数だけ遡った日を最終日とする
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    /// * `renewal_date` - [DateTime<Utc>] 次回の更新日
+    ///
+    /// # 戻り値
+    /// - [Option<DateTime<Utc>>] 解約の最終日。最低契約期間を過ぎていて事前通知期間もない場合は[None]
+    pub fn last_cancellation_date(&self, base: &DateTime<Utc>, renewal_date: &DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let deadline = match self.commitment_end_date {
+            Some(end) if end.date_naive() >= base.date_naive() => end,
+            _ if self.notice_period_days.is_some() => *renewal_date,
+            _ => return None,
+        };
+        Some(deadline - Duration::days(i64::from(self.notice_period_days.unwrap_or(0))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::rstest;
+
+    fn date(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_new_failed_notice_period_too_long() {
+        let result = ContractTerm::new(None, Some(MAX_NOTICE_PERIOD_DAYS + 1));
+        assert!(matches!(result, Err(SubscribeError::InvalidContractTerm(_))))
+    }
+
+    #[rstest]
+    // 契約期間中は契約終了日から通知期間を遡る
+    #[case(Some((2025, 3, 31)), Some(30), (2024, 6, 1), Some((2025, 3, 1)))]
+    #[case(Some((2025, 3, 31)), None, (2024, 6, 1), Some((2025, 3, 31)))]
+    // 契約期間終了後は次回更新日から通知期間を遡る
+    #[case(Some((2024, 3, 31)), Some(10), (2024, 6, 1), Some((2024, 6, 20)))]
+    #[case(None, Some(10), (2024, 6, 1), Some((2024, 6, 20)))]
+    #[case(Some((2024, 3, 31)), None, (2024, 6, 1), None)]
+    #[case(None, None, (2024, 6, 1), None)]
+    fn test_last_cancellation_date(
+        #[case] commitment_end: Option<(i32, u32, u32)>,
+        #[case] notice_days: Option<u32>,
+        #[case] base: (i32, u32, u32),
+        #[case] expected: Option<(i32, u32, u32)>,
+    ) {
+        let term = ContractTerm::new(commitment_end.map(|(y, m, d)| date(y, m, d)), notice_days).unwrap();
+
+        let result = term.last_cancellation_date(&date(base.0, base.1, base.2), &date(2024, 6, 30));
+
+        assert_eq!(result, expected.map(|(y, m, d)| date(y, m, d)))
+    }
+}
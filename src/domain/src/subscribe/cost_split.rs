@@ -0,0 +1,273 @@
+use crate::subscribe::subscribe_error::SubscribeError;
+use crate::user::user_id::UserId;
+use crate::value_object::money::Money;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::fmt::{Display, Formatter};
+
+/// 費用を分担するメンバー
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SplitMember {
+    /// アプリを利用していない人(名前のみ)
+    Person(String),
+
+    /// アプリを利用している他のユーザー
+    User(UserId),
+}
+
+impl Display for SplitMember {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplitMember::Person(name) => write!(f, "{}", name),
+            SplitMember::User(user_id) => write!(f, "{}", user_id),
+        }
+    }
+}
+
+/// メンバーの負担分
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Share {
+    /// 1回の請求あたりの固定額
+    Fixed(Money),
+
+    /// 請求額に対する割合(%)
+    Percentage(Decimal),
+}
+
+/// メンバーと負担分の組
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MemberShare {
+    member: SplitMember,
+    share: Share,
+}
+
+impl MemberShare {
+    /// メンバーの負担分を生成する
+    ///
+    /// # 引数
+    /// * `member` - [SplitMember] 分担するメンバー
+    /// * `share` - [Share] 負担分
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidCostSplit] メンバー名が空、または割合が0%以下か100%を超える場合
+    pub fn new(member: SplitMember, share: Share) -> Result<Self, SubscribeError> {
+        if matches!(&member, SplitMember::Person(name) if name.trim().is_empty()) {
+            return Err(SubscribeError::InvalidCostSplit("member name must not be empty".to_string()));
+        }
+        if let Share::Percentage(p) = share {
+            if p <= Decimal::ZERO || p > Decimal::ONE_HUNDRED {
+                return Err(SubscribeError::InvalidCostSplit(format!("percentage must be in (0, 100]: {p}")));
+            }
+        }
+        Ok(Self { member, share })
+    }
+
+    pub fn member(&self) -> &SplitMember {
+        &self.member
+    }
+
+    pub fn share(&self) -> &Share {
+        &self.share
+    }
+
+    /// 1回の請求あたりの負担額を計算する
+    ///
+    /// # 引数
+    /// * `amount` - [Money] 1回の請求額
+    ///
+    /// # 戻り値
+    /// - [Decimal] 通貨の補助単位の桁数で四捨五入した負担額
+    pub fn owed(&self, amount: &Money) -> Decimal {
+        match &self.share {
+            Share::Fixed(money) => *money.amount().value(),
+            Share::Percentage(p) => (amount.amount().value() * p / Decimal::ONE_HUNDRED)
+                .round_dp_with_strategy(amount.currency().minor_units(), RoundingStrategy::MidpointAwayFromZero)
+                .normalize(),
+        }
+    }
+}
+
+/// 家族プランなどで請求額を複数人で分担する設定
+///
+/// 所有者以外のメンバーの負担分を保持し、残りを所有者の負担分とする
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CostSplit {
+    members: Vec<MemberShare>,
+}
+
+impl CostSplit {
+    /// 分担設定を生成する
+    ///
+    /// # 引数
+    /// * `members` - Vec<[MemberShare]> 所有者以外のメンバーの負担分
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidCostSplit] 同じメンバーが重複している、または割合の合計が100%を超える場合
+    pub fn new(members: Vec<MemberShare>) -> Result<Self, SubscribeError> {
+        for (i, m) in members.iter().enumerate() {
+            if members[..i].iter().any(|other| other.member == m.member) {
+                return Err(SubscribeError::InvalidCostSplit(format!("duplicate member: {}", m.member)));
+            }
+        }
+        let total_percentage: Decimal = members
+            .iter()
+            .filter_map(|m| match m.share {
+                Share::Percentage(p) => Some(p),
+                Share::Fixed(_) => None,
+            })
+            .sum();
+        if total_percentage > Decimal::ONE_HUNDRED {
+            return Err(SubscribeError::InvalidCostSplit(format!("total percentage exceeds 100: {total_percentage}")));
+        }
+        Ok(Self { members })
+    }
+
+    pub fn members(&self) -> &Vec<MemberShare> {
+        &self.members
+    }
+
+    /// 分担するメンバーがいないか判定する
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// 請求額に対して分担設定が成り立つか検証する
+    ///
+    /// # 引数
+    /// * `amount` - [Money] 1回の請求額
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidCostSplit] 固定額の通貨が異なる、またはメンバーの負担額の合計が請求額を超える場合
+    pub fn validate_against(&self, amount: &Money) -> Result<(), SubscribeError> {
+        for m in &self.members {
+            if let Share::Fixed(money) = &m.share {
+                if money.currency() != amount.currency() {
+                    return Err(SubscribeError::InvalidCostSplit(format!(
+                        "currency of {} must be {}",
+                        m.member,
+                        amount.currency()
+                    )));
+                }
+            }
+        }
+        let total = self.total_owed(amount);
+        if total > *amount.amount().value() {
+            return Err(SubscribeError::InvalidCostSplit(format!("total share {total} exceeds amount {amount}")));
+        }
+        Ok(())
+    }
+
+    /// 所有者以外のメンバーの負担額の合計を計算する
+    ///
+    /// # 引数
+    /// * `amount` - [Money] 1回の請求額
+    pub fn total_owed(&self, amount: &Money) -> Decimal {
+        self.members.iter().map(|m| m.owed(amount)).sum()
+    }
+
+    /// 所有者自身の負担額を計算する
+    ///
+    /// 請求額の変更などでメンバーの負担額が請求額を超えた場合は0とする
+    ///
+    /// # 引数
+    /// * `amount` - [Money] 1回の請求額
+    ///
+    /// # 戻り値
+    /// - [Decimal] 所有者の1回の請求あたりの負担額
+    pub fn own_share(&self, amount: &Money) -> Decimal {
+        (amount.amount().value() - self.total_owed(amount)).max(Decimal::ZERO).normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_object::{amount::Amount, currency::Currency};
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    fn money(value: &str, currency: Currency) -> Money {
+        Money::new(Amount::from_str(value).unwrap(), currency).unwrap()
+    }
+
+    fn person(name: &str, share: Share) -> MemberShare {
+        MemberShare::new(SplitMember::Person(name.to_string()), share).unwrap()
+    }
+
+    #[rstest]
+    #[case(Decimal::ZERO)]
+    #[case(Decimal::from(101))]
+    fn test_member_share_invalid_percentage(#[case] p: Decimal) {
+        let result = MemberShare::new(SplitMember::Person("hoge".to_string()), Share::Percentage(p));
+        assert!(matches!(result, Err(SubscribeError::InvalidCostSplit(_))))
+    }
+
+    #[test]
+    fn test_member_share_empty_name() {
+        let result = MemberShare::new(SplitMember::Person(" ".to_string()), Share::Percentage(Decimal::TEN));
+        assert!(matches!(result, Err(SubscribeError::InvalidCostSplit(_))))
+    }
+
+    #[test]
+    fn test_cost_split_duplicate_member() {
+        let result = CostSplit::new(vec![
+            person("hoge", Share::Percentage(Decimal::TEN)),
+            person("hoge", Share::Percentage(Decimal::TEN)),
+        ]);
+        assert!(matches!(result, Err(SubscribeError::InvalidCostSplit(_))))
+    }
+
+    #[test]
+    fn test_cost_split_total_percentage_exceeds() {
+        let result = CostSplit::new(vec![
+            person("hoge", Share::Percentage(Decimal::from(60))),
+            person("fuga", Share::Percentage(Decimal::from(50))),
+        ]);
+        assert!(matches!(result, Err(SubscribeError::InvalidCostSplit(_))))
+    }
+
+    #[rstest]
+    #[case("2980", Currency::JPY, "993", "994")]
+    #[case("22.99", Currency::USD, "7.66", "7.67")]
+    fn test_own_share_with_percentage(
+        #[case] amount: &str,
+        #[case] currency: Currency,
+        #[case] each: &str,
+        #[case] own: &str,
+    ) {
+        let amount = money(amount, currency);
+        let percent = Decimal::from_str("33.33").unwrap();
+        let split = CostSplit::new(vec![
+            person("hoge", Share::Percentage(percent)),
+            person("fuga", Share::Percentage(percent)),
+        ])
+        .unwrap();
+
+        assert_eq!(split.members()[0].owed(&amount), Decimal::from_str(each).unwrap());
+        assert_eq!(split.own_share(&amount), Decimal::from_str(own).unwrap());
+    }
+
+    #[test]
+    fn test_own_share_with_fixed_and_user() {
+        let amount = money("1680", Currency::JPY);
+        let split = CostSplit::new(vec![
+            person("hoge", Share::Fixed(money("280", Currency::JPY))),
+            MemberShare::new(SplitMember::User(UserId::new()), Share::Percentage(Decimal::from(50))).unwrap(),
+        ])
+        .unwrap();
+
+        assert!(split.validate_against(&amount).is_ok());
+        assert_eq!(split.total_owed(&amount), Decimal::from(1120));
+        assert_eq!(split.own_share(&amount), Decimal::from(560));
+    }
+
+    #[test]
+    fn test_validate_against_failed() {
+        let amount = money("1000", Currency::JPY);
+        let exceeds = CostSplit::new(vec![person("hoge", Share::Fixed(money("1200", Currency::JPY)))]).unwrap();
+        let currency = CostSplit::new(vec![person("hoge", Share::Fixed(money("1", Currency::USD)))]).unwrap();
+
+        assert!(matches!(exceeds.validate_against(&amount), Err(SubscribeError::InvalidCostSplit(_))));
+        assert!(matches!(currency.validate_against(&amount), Err(SubscribeError::InvalidCostSplit(_))));
+        assert_eq!(exceeds.own_share(&amount), Decimal::ZERO);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::value_object::money::Money;
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+
+/// 金額の変更履歴の1件を表す値オブジェクト
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PriceChange {
+    /// 変更後の金額が適用される日時
+    effective_date: DateTime<Utc>,
+
+    /// 変更後の金額
+    amount: Money,
+}
+
+impl PriceChange {
+    /// 金額の変更履歴を生成する
+    ///
+    /// # 引数
+    /// * `effective_date` - [DateTime<Utc>] 適用日時
+    /// * `amount` - [Money] 変更後の金額
+    ///
+    /// # 戻り値
+    /// - [PriceChange] 生成された変更履歴
+    pub fn new(effective_date: DateTime<Utc>, amount: Money) -> Self {
+        Self { effective_date, amount }
+    }
+
+    /// 適用日時を取得する
+    ///
+    /// # 戻り値
+    /// - [DateTime<Utc>] 適用日時への参照
+    pub fn effective_date(&self) -> &DateTime<Utc> {
+        &self.effective_date
+    }
+
+    /// 変更後の金額を取得する
+    ///
+    /// # 戻り値
+    /// - [Money] 変更後の金額への参照
+    pub fn amount(&self) -> &Money {
+        &self.amount
+    }
+
+    /// 直前の金額からの変化率(%)を計算する
+    ///
+    /// 小数点以下2桁で四捨五入する
+    ///
+    /// # 引数
+    /// * `previous` - [PriceChange] 直前の変更履歴
+    ///
+    /// # 戻り値
+    /// - [Decimal] 変化率(%)。値上げは正、値下げは負の値
+    pub fn change_rate(&self, previous: &PriceChange) -> Decimal {
+        let previous = previous.amount.amount().value();
+        let current = self.amount.amount().value();
+        ((current - previous) / previous * Decimal::ONE_HUNDRED)
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+            .normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_object::{amount::Amount, currency::Currency};
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    fn create_price_change(amount: &str) -> PriceChange {
+        PriceChange::new(Utc::now(), Money::new(Amount::from_str(amount).unwrap(), Currency::JPY).unwrap())
+    }
+
+    #[rstest]
+    #[case("1000", "1200", "20")]
+    #[case("1490", "1980", "32.89")]
+    #[case("1200", "1000", "-16.67")]
+    #[case("1000", "1000", "0")]
+    fn test_change_rate(#[case] previous: &str, #[case] current: &str, #[case] expected: &str) {
+        let previous = create_price_change(previous);
+        let current = create_price_change(current);
+
+        assert_eq!(current.change_rate(&previous), Decimal::from_str(expected).unwrap());
+    }
+}
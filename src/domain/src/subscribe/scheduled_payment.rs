@@ -0,0 +1,55 @@
+use crate::value_object::money::Money;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+
+/// 将来発生する請求の1件を表す値オブジェクト
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ScheduledPayment {
+    /// 支払予定日
+    payment_date: DateTime<Utc>,
+
+    /// 請求額 (支払予定日時点で適用される金額)
+    amount: Money,
+
+    /// 費用を分担している場合の所有者自身の負担額
+    own_amount: Decimal,
+}
+
+impl ScheduledPayment {
+    /// 将来の請求を生成する
+    ///
+    /// # 引数
+    /// * `payment_date` - [DateTime<Utc>] 支払予定日
+    /// * `amount` - [Money] 請求額
+    /// * `own_amount` - [Decimal] 所有者自身の負担額
+    ///
+    /// # 戻り値
+    /// - [ScheduledPayment] 生成された請求
+    pub fn new(payment_date: DateTime<Utc>, amount: Money, own_amount: Decimal) -> Self {
+        Self { payment_date, amount, own_amount }
+    }
+
+    /// 支払予定日を取得する
+    ///
+    /// # 戻り値
+    /// - [DateTime<Utc>] 支払予定日への参照
+    pub fn payment_date(&self) -> &DateTime<Utc> {
+        &self.payment_date
+    }
+
+    /// 請求額を取得する
+    ///
+    /// # 戻り値
+    /// - [Money] 請求額への参照
+    pub fn amount(&self) -> &Money {
+        &self.amount
+    }
+
+    /// 所有者自身の負担額を取得する
+    ///
+    /// # 戻り値
+    /// - [Decimal] 所有者自身の負担額
+    pub fn own_amount(&self) -> Decimal {
+        self.own_amount
+    }
+}
@@ -1,12 +1,18 @@
-use crate::{payment_cycle::PaymentCycleError, value_object::amount::AmountError, AggregateIdError};
+use crate::{
+    payment_cycle::PaymentCycleError,
+    value_object::{amount::AmountError, currency::CurrencyError, money::MoneyError},
+    AggregateIdError,
+};
 use thiserror::Error;
 
-use super::subscribe_name::SubscribeNameError;
+use super::{subscribe_name::SubscribeNameError, tag::TagError};
 
 /// サブスクリプション操作に関するエラー
 ///
 /// # バリアント
 /// * `InvalidAmountError` - 金額が不正な場合のエラー
+/// * `InvalidMoneyError` - 通貨または通貨に対する金額の桁数が不正な場合のエラー
+/// * `InvalidStatusTransition` - 許可されていないステータス遷移の場合のエラー
 ///
 /// # 実装
 /// * `thiserror::Error` を導出して、エラーメッセージを定義
@@ -16,9 +22,15 @@ pub enum SubscribeError {
     #[error("Invalid Amount: {0:?}")]
     InvalidAmountError(#[from] AmountError),
 
+    #[error("Invalid Money: {0}")]
+    InvalidMoneyError(#[from] MoneyError),
+
     #[error("Not match Subscribe Status: {0}")]
     InvalidSubscribeStatus(String),
 
+    #[error("Invalid subscribe status transition: {0} -> {1}")]
+    InvalidStatusTransition(String, String),
+
     #[error("Failed to delete subscribe: {0}")]
     DeleteSubscribeFailed(String),
 
@@ -51,6 +63,15 @@ pub enum SubscribeError {
 
     #[error("{0}")]
     InvalidSubscribeName(String),
+
+    #[error("Invalid contract term: {0}")]
+    InvalidContractTerm(String),
+
+    #[error("Invalid cost split: {0}")]
+    InvalidCostSplit(String),
+
+    #[error("Invalid tag: {0}")]
+    InvalidTag(String),
 }
 
 impl From<AggregateIdError> for SubscribeError {
@@ -70,3 +91,15 @@ impl From<SubscribeNameError> for SubscribeError {
         SubscribeError::InvalidSubscribeName(value.to_string())
     }
 }
+
+impl From<CurrencyError> for SubscribeError {
+    fn from(value: CurrencyError) -> Self {
+        SubscribeError::InvalidMoneyError(MoneyError::from(value))
+    }
+}
+
+impl From<TagError> for SubscribeError {
+    fn from(value: TagError) -> Self {
+        SubscribeError::InvalidTag(value.to_string())
+    }
+}
@@ -4,14 +4,54 @@ use std::str::FromStr;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum SubscribeStatus {
+    TRIAL,
     ACTIVE,
     PAUSED,
     CANCELLED,
 }
 
+impl SubscribeStatus {
+    /// 指定したステータスへ遷移可能か判定する
+    ///
+    /// # 引数
+    /// * `next` - [SubscribeStatus] 遷移先のステータス
+    ///
+    /// # 戻り値
+    /// - [bool] 遷移可能な場合はtrue
+    pub fn can_transition_to(&self, next: &SubscribeStatus) -> bool {
+        matches!(
+            (self, next),
+            (SubscribeStatus::TRIAL, SubscribeStatus::ACTIVE)
+                | (SubscribeStatus::TRIAL, SubscribeStatus::CANCELLED)
+                | (SubscribeStatus::ACTIVE, SubscribeStatus::PAUSED)
+                | (SubscribeStatus::ACTIVE, SubscribeStatus::CANCELLED)
+                | (SubscribeStatus::PAUSED, SubscribeStatus::ACTIVE)
+                | (SubscribeStatus::PAUSED, SubscribeStatus::CANCELLED)
+                | (SubscribeStatus::CANCELLED, SubscribeStatus::ACTIVE)
+        )
+    }
+
+    /// 指定したステータスへの遷移を検証する
+    ///
+    /// 同一ステータスへの変更は遷移とみなさず許可する
+    ///
+    /// # 引数
+    /// * `next` - [SubscribeStatus] 遷移先のステータス
+    ///
+    /// # エラー
+    /// - [SubscribeError::InvalidStatusTransition] 許可されていない遷移の場合
+    pub fn validate_transition(&self, next: &SubscribeStatus) -> Result<(), SubscribeError> {
+        if self == next || self.can_transition_to(next) {
+            return Ok(());
+        }
+        Err(SubscribeError::InvalidStatusTransition(self.to_string(), next.to_string()))
+    }
+}
+
 impl Display for SubscribeStatus {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
+            SubscribeStatus::TRIAL => write!(f, "TRIAL"),
             SubscribeStatus::ACTIVE => write!(f, "ACTIVE"),
             SubscribeStatus::PAUSED => write!(f, "PAUSED"),
             SubscribeStatus::CANCELLED => write!(f, "CANCELLED"),
@@ -24,6 +64,7 @@ impl FromStr for SubscribeStatus {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "TRIAL" => Ok(SubscribeStatus::TRIAL),
             "ACTIVE" => Ok(SubscribeStatus::ACTIVE),
             "PAUSED" => Ok(SubscribeStatus::PAUSED),
             "CANCELLED" => Ok(SubscribeStatus::CANCELLED),
@@ -39,6 +80,7 @@ mod tests {
     #[test]
     fn test_subscribe_status_fmt() {
         let test_case = vec![
+            (SubscribeStatus::TRIAL, "TRIAL"),
             (SubscribeStatus::ACTIVE, "ACTIVE"),
             (SubscribeStatus::PAUSED, "PAUSED"),
             (SubscribeStatus::CANCELLED, "CANCELLED"),
@@ -52,6 +94,7 @@ mod tests {
     #[test]
     fn test_subscribe_status_from_str_success() {
         let test_case = vec![
+            ("TRIAL", SubscribeStatus::TRIAL),
             ("ACTIVE", SubscribeStatus::ACTIVE),
             ("PAUSED", SubscribeStatus::PAUSED),
             ("CANCELLED", SubscribeStatus::CANCELLED),
@@ -81,4 +124,35 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_can_transition_to() {
+        let test_case = vec![
+            (SubscribeStatus::ACTIVE, SubscribeStatus::PAUSED, true),
+            (SubscribeStatus::ACTIVE, SubscribeStatus::CANCELLED, true),
+            (SubscribeStatus::PAUSED, SubscribeStatus::ACTIVE, true),
+            (SubscribeStatus::PAUSED, SubscribeStatus::CANCELLED, true),
+            (SubscribeStatus::CANCELLED, SubscribeStatus::ACTIVE, true),
+            (SubscribeStatus::CANCELLED, SubscribeStatus::PAUSED, false),
+            (SubscribeStatus::ACTIVE, SubscribeStatus::ACTIVE, false),
+            (SubscribeStatus::TRIAL, SubscribeStatus::ACTIVE, true),
+            (SubscribeStatus::TRIAL, SubscribeStatus::CANCELLED, true),
+            (SubscribeStatus::TRIAL, SubscribeStatus::PAUSED, false),
+            (SubscribeStatus::ACTIVE, SubscribeStatus::TRIAL, false),
+            (SubscribeStatus::CANCELLED, SubscribeStatus::TRIAL, false),
+        ];
+
+        for (from, to, expected) in test_case {
+            assert_eq!(from.can_transition_to(&to), expected, "{} -> {}", from, to)
+        }
+    }
+
+    #[test]
+    fn test_validate_transition() {
+        assert!(SubscribeStatus::ACTIVE.validate_transition(&SubscribeStatus::ACTIVE).is_ok());
+        assert!(SubscribeStatus::ACTIVE.validate_transition(&SubscribeStatus::PAUSED).is_ok());
+
+        let result = SubscribeStatus::CANCELLED.validate_transition(&SubscribeStatus::PAUSED);
+        assert!(matches!(result, Err(SubscribeError::InvalidStatusTransition(_, _))))
+    }
 }
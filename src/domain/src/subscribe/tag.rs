@@ -0,0 +1,216 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// タグの最大文字数
+pub const MAX_TAG_LENGTH: usize = 20;
+
+/// 1つのサブスクに付けられるタグの最大数
+pub const MAX_TAGS_PER_SUBSCRIBE: usize = 10;
+
+/// カテゴリとは別にサブスクへ自由に付けるラベルを表す値オブジェクト
+///
+/// 前後の空白を除去し、英字は小文字に揃えて保持する
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Tag(String);
+
+/// タグに関するエラー
+#[derive(Error, Debug, Clone)]
+pub enum TagError {
+    /// タグが空の場合のエラー
+    #[error("the tag is empty")]
+    Empty,
+    /// タグが長すぎる場合のエラー
+    #[error("the tag is too long")]
+    TooLong,
+    /// タグに空白やカンマが含まれる場合のエラー
+    #[error("the tag contains invalid character: {0:?}")]
+    InvalidCharacter(char),
+    /// タグの数が上限を超える場合のエラー
+    #[error("too many tags: up to {MAX_TAGS_PER_SUBSCRIBE} tags are allowed")]
+    TooMany,
+}
+
+impl Tag {
+    /// 新しいタグを生成する
+    ///
+    /// # 引数
+    /// * `value` - [&str] タグ
+    ///
+    /// # 戻り値
+    /// - [Result<Tag, TagError>] 生成結果
+    ///
+    /// # エラー
+    /// - [TagError::Empty] タグが空の場合
+    /// - [TagError::TooLong] タグが20文字を超える場合
+    /// - [TagError::InvalidCharacter] タグに空白やカンマが含まれる場合
+    pub fn new(value: &str) -> Result<Self, TagError> {
+        let value = value.trim().to_lowercase();
+        if value.is_empty() {
+            return Err(TagError::Empty);
+        }
+        if value.chars().count() > MAX_TAG_LENGTH {
+            return Err(TagError::TooLong);
+        }
+        if let Some(c) = value.chars().find(|c| c.is_whitespace() || *c == ',') {
+            return Err(TagError::InvalidCharacter(c));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl FromStr for Tag {
+    type Err = TagError;
+
+    /// 文字列からタグを生成する
+    ///
+    /// # 引数
+    /// * `s` - [&str] 変換元の文字列
+    ///
+    /// # 戻り値
+    /// - Ok [Tag]
+    /// - Err [TagError]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// サブスクに付けられたタグの集合
+///
+/// 同じタグは1つにまとめ、常に辞書順で保持する
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct TagSet(BTreeSet<Tag>);
+
+impl TagSet {
+    /// タグの集合を生成する
+    ///
+    /// # 引数
+    /// * `tags` - タグのリスト。重複は1つにまとめる
+    ///
+    /// # エラー
+    /// - [TagError::TooMany] 重複を除いたタグの数が上限を超える場合
+    pub fn new(tags: impl IntoIterator<Item = Tag>) -> Result<Self, TagError> {
+        let tags: BTreeSet<Tag> = tags.into_iter().collect();
+        if tags.len() > MAX_TAGS_PER_SUBSCRIBE {
+            return Err(TagError::TooMany);
+        }
+        Ok(Self(tags))
+    }
+
+    /// タグを追加する
+    ///
+    /// # 引数
+    /// * `tag` - [Tag] 追加するタグ
+    ///
+    /// # 戻り値
+    /// - [bool] 新たに追加した場合はtrue、既に付いていた場合はfalse
+    ///
+    /// # エラー
+    /// - [TagError::TooMany] 追加するとタグの数が上限を超える場合
+    pub fn add(&mut self, tag: Tag) -> Result<bool, TagError> {
+        if self.0.contains(&tag) {
+            return Ok(false);
+        }
+        if self.0.len() >= MAX_TAGS_PER_SUBSCRIBE {
+            return Err(TagError::TooMany);
+        }
+        Ok(self.0.insert(tag))
+    }
+
+    /// タグを削除する
+    ///
+    /// # 戻り値
+    /// - [bool] 削除した場合はtrue、付いていなかった場合はfalse
+    pub fn remove(&mut self, tag: &Tag) -> bool {
+        self.0.remove(tag)
+    }
+
+    pub fn contains(&self, tag: &Tag) -> bool {
+        self.0.contains(tag)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Tag> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("shared", "shared")]
+    #[case("  Work-Expensable ", "work-expensable")]
+    #[case("解約検討", "解約検討")]
+    fn test_tag_new_success(#[case] value: &str, #[case] expected: &str) {
+        let result = Tag::new(value);
+        assert_eq!(result.unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("", TagError::Empty)]
+    #[case("   ", TagError::Empty)]
+    #[case("abcdefghijklmnopqrstu", TagError::TooLong)]
+    #[case("cancel soon", TagError::InvalidCharacter(' '))]
+    #[case("a,b", TagError::InvalidCharacter(','))]
+    fn test_tag_new_failed(#[case] value: &str, #[case] expected: TagError) {
+        let result = Tag::from_str(value);
+        assert_eq!(result.unwrap_err().to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn test_tag_equality_ignores_case() {
+        assert_eq!(Tag::new("Shared").unwrap(), Tag::new("shared").unwrap());
+    }
+
+    fn tags(n: usize) -> Vec<Tag> {
+        (0..n).map(|i| Tag::new(&format!("tag{i}")).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_tag_set_new_merges_duplicates() {
+        let set = TagSet::new(vec![
+            Tag::new("b").unwrap(),
+            Tag::new("a").unwrap(),
+            Tag::new("B").unwrap(),
+        ])
+        .unwrap();
+
+        assert_eq!(set.iter().map(|t| t.to_string()).collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_tag_set_new_too_many() {
+        let result = TagSet::new(tags(MAX_TAGS_PER_SUBSCRIBE + 1));
+        assert!(matches!(result, Err(TagError::TooMany)));
+    }
+
+    #[test]
+    fn test_tag_set_add_and_remove() {
+        let mut set = TagSet::new(tags(MAX_TAGS_PER_SUBSCRIBE - 1)).unwrap();
+        let shared = Tag::new("shared").unwrap();
+
+        assert!(set.add(shared.clone()).unwrap());
+        assert!(!set.add(shared.clone()).unwrap());
+        assert!(matches!(set.add(Tag::new("extra").unwrap()), Err(TagError::TooMany)));
+        assert!(set.remove(&shared));
+        assert!(!set.remove(&shared));
+        assert!(!set.contains(&shared));
+    }
+}
@@ -0,0 +1,97 @@
+use crate::value_object::money::Money;
+use chrono::{DateTime, Utc};
+
+/// 無料トライアルの情報を表す値オブジェクト
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Trial {
+    /// トライアル終了日
+    end_date: DateTime<Utc>,
+
+    /// トライアル終了後の金額。[None] の場合はサブスクの金額をそのまま引き継ぐ
+    post_trial_amount: Option<Money>,
+}
+
+impl Trial {
+    /// トライアル情報を生成する
+    ///
+    /// # 引数
+    /// * `end_date` - [DateTime<Utc>] トライアル終了日
+    /// * `post_trial_amount` - [Option<Money>] トライアル終了後の金額
+    ///
+    /// # 戻り値
+    /// - [Trial] 生成されたトライアル情報
+    pub fn new(end_date: DateTime<Utc>, post_trial_amount: Option<Money>) -> Self {
+        Self { end_date, post_trial_amount }
+    }
+
+    /// トライアル終了日を取得する
+    ///
+    /// # 戻り値
+    /// - [DateTime<Utc>] トライアル終了日への参照
+    pub fn end_date(&self) -> &DateTime<Utc> {
+        &self.end_date
+    }
+
+    /// トライアル終了後の金額を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<Money>] トライアル終了後の金額への参照
+    pub fn post_trial_amount(&self) -> &Option<Money> {
+        &self.post_trial_amount
+    }
+
+    /// 基準日時点でトライアルが終了しているか判定する
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    ///
+    /// # 戻り値
+    /// - [bool] トライアル終了日当日以降の場合はtrue
+    pub fn is_ended(&self, base: &DateTime<Utc>) -> bool {
+        self.end_date.date_naive() <= base.date_naive()
+    }
+
+    /// 基準日からN日以内にトライアルが終了するか判定する
+    ///
+    /// # 引数
+    /// * `base` - [DateTime<Utc>] 基準日時
+    /// * `days` - [u32] 日数
+    ///
+    /// # 戻り値
+    /// - [bool] 基準日当日からN日後までに終了する場合はtrue
+    pub fn ends_within(&self, base: &DateTime<Utc>, days: u32) -> bool {
+        let end = self.end_date.date_naive();
+        let from = base.date_naive();
+        end >= from && (end - from).num_days() <= i64::from(days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case((2024, 1, 10), (2024, 1, 9), false)]
+    #[case((2024, 1, 10), (2024, 1, 10), true)]
+    #[case((2024, 1, 10), (2024, 1, 11), true)]
+    fn test_trial_is_ended(#[case] end: (i32, u32, u32), #[case] base: (i32, u32, u32), #[case] expected: bool) {
+        let trial = Trial::new(Utc.with_ymd_and_hms(end.0, end.1, end.2, 12, 0, 0).unwrap(), None);
+        let base = Utc.with_ymd_and_hms(base.0, base.1, base.2, 0, 0, 0).unwrap();
+
+        assert_eq!(trial.is_ended(&base), expected);
+    }
+
+    #[rstest]
+    #[case((2024, 1, 10), 0, false)]
+    #[case((2024, 1, 11), 0, true)]
+    #[case((2024, 1, 4), 7, true)]
+    #[case((2024, 1, 3), 7, false)]
+    fn test_trial_ends_within(#[case] base: (i32, u32, u32), #[case] days: u32, #[case] expected: bool) {
+        let trial = Trial::new(Utc.with_ymd_and_hms(2024, 1, 11, 0, 0, 0).unwrap(), None);
+        let base = Utc.with_ymd_and_hms(base.0, base.1, base.2, 9, 0, 0).unwrap();
+
+        assert_eq!(trial.ends_within(&base, days), expected, "base: {}, days: {}", base, days);
+    }
+}
@@ -1,7 +1,11 @@
-use crate::user::user_id::UserId;
+use crate::user::{user_error::UserError, user_id::UserId, user_profile::UserProfile, user_settings::UserSettings};
+use crate::value_object::{country::Country, currency::Currency};
 use chrono::{DateTime, Utc};
 
+pub mod user_error;
 pub mod user_id;
+pub mod user_profile;
+pub mod user_settings;
 
 /// ユーザー情報を表す構造体
 #[derive(Debug, Clone)]
@@ -9,9 +13,15 @@ pub struct User {
     /// ユーザーを一意に識別するID
     user_id: UserId,
 
+    /// 名前やメールアドレスなどのプロフィール
+    profile: UserProfile,
+
     /// ユーザーが所属する国のID
     country_id: i32,
 
+    /// 通知や既定の通貨などの設定
+    settings: UserSettings,
+
     /// ユーザーの作成日時（UTC）
     created_at: DateTime<Utc>,
 
@@ -20,30 +30,51 @@ pub struct User {
 }
 
 impl User {
-    /// 新しいユーザーを作成する
+    /// 新しいユーザーを既定の設定で作成する
     ///
     /// # 引数
+    /// * `profile` - [UserProfile] プロフィール
     /// * `country_id` - [i32] 国ID
     ///
     /// # 戻り値
     /// - [User] 作成されたユーザー
-    pub fn new(country_id: i32) -> Self {
+    ///
+    /// # エラー
+    /// - [UserError::UnsupportedCountry] 国マスタに存在しない国IDの場合
+    pub fn new(profile: UserProfile, country_id: i32) -> Result<Self, UserError> {
+        Self::verify_country(country_id)?;
         let now = Utc::now();
-        Self { user_id: UserId::new(), country_id, created_at: now, updated_at: now }
+        Ok(Self {
+            user_id: UserId::new(),
+            profile,
+            country_id,
+            settings: UserSettings::default(),
+            created_at: now,
+            updated_at: now,
+        })
     }
 
     /// 既存のデータからユーザーを生成する
     ///
     /// # 引数
     /// * `user_id` - [UserId] ユーザーID
+    /// * `profile` - [UserProfile] プロフィール
     /// * `country_id` - [i32] 国ID
+    /// * `settings` - [UserSettings] 設定
     /// * `created_at` - [DateTime<Utc>] 作成日時
     /// * `updated_at` - [DateTime<Utc>] 更新日時
     ///
     /// # 戻り値
     /// - [User] 生成されたユーザー
-    pub fn from(user_id: UserId, country_id: i32, created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Self {
-        Self { user_id, country_id, created_at, updated_at }
+    pub fn from(
+        user_id: UserId,
+        profile: UserProfile,
+        country_id: i32,
+        settings: UserSettings,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self { user_id, profile, country_id, settings, created_at, updated_at }
     }
 
     /// ユーザーIDを取得する
@@ -54,6 +85,14 @@ impl User {
         &self.user_id
     }
 
+    /// プロフィールを取得する
+    ///
+    /// # 戻り値
+    /// - [&UserProfile] プロフィールへの参照
+    pub fn profile(&self) -> &UserProfile {
+        &self.profile
+    }
+
     /// 国IDを取得する
     ///
     /// # 戻り値
@@ -62,6 +101,61 @@ impl User {
         self.country_id
     }
 
+    /// 設定を取得する
+    ///
+    /// # 戻り値
+    /// - [&UserSettings] 設定への参照
+    pub fn settings(&self) -> &UserSettings {
+        &self.settings
+    }
+
+    /// 国IDに対応する国を取得する
+    ///
+    /// # 戻り値
+    /// - [Option<Country>] 国。国マスタに存在しない国IDの場合は [None]
+    pub fn country(&self) -> Option<Country> {
+        Country::from_id(self.country_id)
+    }
+
+    /// ユーザーの既定の通貨を取得する
+    ///
+    /// 設定で通貨を指定している場合はその通貨を、それ以外は国IDに対応する国の通貨を返す。
+    /// 国が特定できない場合は [Currency::default] を返す
+    ///
+    /// # 戻り値
+    /// - [Currency] 既定の通貨
+    pub fn default_currency(&self) -> Currency {
+        match self.settings.currency() {
+            Some(currency) => *currency,
+            None => self.country().map(|c| c.currency()).unwrap_or_default(),
+        }
+    }
+
+    /// プロフィール、国、設定を変更する
+    ///
+    /// # 引数
+    /// * `profile` - [UserProfile] 変更後のプロフィール
+    /// * `country_id` - [i32] 変更後の国ID
+    /// * `settings` - [UserSettings] 変更後の設定
+    ///
+    /// # エラー
+    /// - [UserError::UnsupportedCountry] 国マスタに存在しない国IDの場合
+    pub fn change(&mut self, profile: UserProfile, country_id: i32, settings: UserSettings) -> Result<(), UserError> {
+        Self::verify_country(country_id)?;
+        self.profile = profile;
+        self.country_id = country_id;
+        self.settings = settings;
+        Ok(())
+    }
+
+    /// 更新日時を記録する
+    ///
+    /// # 引数
+    /// * `now` - [DateTime<Utc>] 更新日時
+    pub fn mark_updated(&mut self, now: DateTime<Utc>) {
+        self.updated_at = now;
+    }
+
     /// 作成日時を取得する
     ///
     /// # 戻り値
@@ -77,6 +171,14 @@ impl User {
     pub fn updated_at(&self) -> &DateTime<Utc> {
         &self.updated_at
     }
+
+    /// 国IDが国マスタに存在するか検証する
+    fn verify_country(country_id: i32) -> Result<(), UserError> {
+        match Country::from_id(country_id) {
+            Some(_) => Ok(()),
+            None => Err(UserError::UnsupportedCountry(country_id)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -84,16 +186,28 @@ mod tests {
     use super::*;
     use chrono::TimeZone;
 
+    fn create_profile() -> UserProfile {
+        UserProfile::new("山田 太郎", "taro@example.com", None).unwrap()
+    }
+
     #[test]
     fn test_user_new_success() {
         let country_id = 1;
-        let result = User::new(country_id);
+        let result = User::new(create_profile(), country_id).unwrap();
 
         assert_eq!(country_id, result.country_id());
+        assert_eq!(&UserSettings::default(), result.settings());
         assert!(result.created_at() <= &Utc::now());
         assert_eq!(result.created_at(), result.updated_at());
     }
 
+    #[test]
+    fn test_user_new_unsupported_country() {
+        let result = User::new(create_profile(), 999);
+
+        assert!(matches!(result, Err(UserError::UnsupportedCountry(999))));
+    }
+
     #[test]
     fn test_user_from_success() {
         let user_id = UserId::new();
@@ -101,7 +215,8 @@ mod tests {
         let created_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
         let updated_at = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
 
-        let result = User::from(user_id.clone(), country_id, created_at, updated_at);
+        let result =
+            User::from(user_id.clone(), create_profile(), country_id, UserSettings::default(), created_at, updated_at);
 
         assert_eq!(&user_id, result.user_id());
         assert_eq!(country_id, result.country_id());
@@ -116,11 +231,46 @@ mod tests {
         let created_at = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
         let updated_at = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
 
-        let user = User::from(user_id.clone(), country_id, created_at, updated_at);
+        let user =
+            User::from(user_id.clone(), create_profile(), country_id, UserSettings::default(), created_at, updated_at);
 
         assert_eq!(&user_id, user.user_id());
         assert_eq!(country_id, user.country_id());
         assert_eq!(&created_at, user.created_at());
         assert_eq!(&updated_at, user.updated_at());
     }
+
+    #[test]
+    fn test_user_default_currency() {
+        let test_case = vec![
+            (1, Currency::JPY),
+            (2, Currency::USD),
+            (4, Currency::EUR),
+            (999, Currency::JPY),
+        ];
+
+        for (country_id, expected) in test_case {
+            let now = Utc::now();
+            let user = User::from(UserId::new(), create_profile(), country_id, UserSettings::default(), now, now);
+            assert_eq!(user.default_currency(), expected, "country_id: {}", country_id)
+        }
+    }
+
+    #[test]
+    fn test_user_default_currency_from_settings() {
+        let mut user = User::new(create_profile(), 1).unwrap();
+        user.change(create_profile(), 1, UserSettings::new(false, Some(Currency::USD))).unwrap();
+
+        assert_eq!(user.default_currency(), Currency::USD);
+    }
+
+    #[test]
+    fn test_user_change_unsupported_country() {
+        let mut user = User::new(create_profile(), 1).unwrap();
+
+        let result = user.change(create_profile(), 999, UserSettings::default());
+
+        assert!(matches!(result, Err(UserError::UnsupportedCountry(999))));
+        assert_eq!(user.country_id(), 1);
+    }
 }
@@ -1,4 +1,53 @@
+use crate::{value_object::currency::CurrencyError, AggregateIdError};
 use thiserror::Error;
 
+use super::user_profile::UserProfileError;
+
+/// ユーザー操作に関するエラー
+///
+/// # バリアント
+/// * `InvalidProfile` - 名前またはメールアドレスが不正な場合のエラー
+/// * `UnsupportedCountry` - 国マスタに存在しない国IDの場合のエラー
+/// * `AlreadyExists` - 同じユーザーIDのユーザーが既に存在する場合のエラー
+/// * `NotExists` - 対象のユーザーが存在しない場合のエラー
 #[derive(Debug, Error)]
-pub enum UserError {}
+pub enum UserError {
+    #[error("Invalid profile: {0}")]
+    InvalidProfile(#[from] UserProfileError),
+
+    #[error("Invalid currency: {0}")]
+    InvalidCurrency(#[from] CurrencyError),
+
+    #[error("Unsupported country id: {0}")]
+    UnsupportedCountry(i32),
+
+    #[error("Failed to create user: {0}")]
+    CreateUserFailed(String),
+
+    #[error("Failed to find by id user: {0}")]
+    FindByIdError(String),
+
+    #[error("Failed to update user: {0}")]
+    UpdateUserFailed(String),
+
+    #[error("Failed to delete user: {0}")]
+    DeleteUserFailed(String),
+
+    #[error("Required user field '{0}' was missing")]
+    MissingField(String),
+
+    #[error("User already exists")]
+    AlreadyExists,
+
+    #[error("User not exist")]
+    NotExists,
+
+    #[error("{0}")]
+    UserIdFailed(String),
+}
+
+impl From<AggregateIdError> for UserError {
+    fn from(value: AggregateIdError) -> Self {
+        UserError::UserIdFailed(value.to_string())
+    }
+}
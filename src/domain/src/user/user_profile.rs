@@ -0,0 +1,133 @@
+use thiserror::Error;
+
+/// 名前の最大文字数
+const MAX_NAME_LENGTH: usize = 50;
+
+/// メールアドレスの最大文字数 (RFC 5321)
+const MAX_EMAIL_LENGTH: usize = 254;
+
+/// ユーザーのプロフィールに関するエラー
+#[derive(Error, Debug, Clone)]
+pub enum UserProfileError {
+    /// 名前が空の場合のエラー
+    #[error("the user name is empty")]
+    EmptyName,
+    /// 名前が長すぎる場合のエラー
+    #[error("the user name is too long")]
+    NameTooLong,
+    /// メールアドレスの形式が不正な場合のエラー
+    #[error("invalid email address: {0}")]
+    InvalidEmail(String),
+}
+
+/// ユーザーのプロフィールを表す値オブジェクト
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UserProfile {
+    /// 表示名
+    name: String,
+
+    /// メールアドレス
+    email: String,
+
+    /// プロフィール画像のパス (未設定は [None])
+    profile_icon_path: Option<String>,
+}
+
+impl UserProfile {
+    /// プロフィールを作成する
+    ///
+    /// 名前とメールアドレスの前後の空白は取り除く
+    ///
+    /// # 引数
+    /// * `name` - 表示名 (1〜50文字)
+    /// * `email` - メールアドレス
+    /// * `profile_icon_path` - プロフィール画像のパス。空文字は未設定として扱う
+    ///
+    /// # 戻り値
+    /// - [UserProfile] 作成したプロフィール
+    ///
+    /// # エラー
+    /// - [UserProfileError] 名前またはメールアドレスが不正な場合
+    pub fn new(name: &str, email: &str, profile_icon_path: Option<String>) -> Result<Self, UserProfileError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(UserProfileError::EmptyName);
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(UserProfileError::NameTooLong);
+        }
+
+        let email = email.trim();
+        if !Self::is_valid_email(email) {
+            return Err(UserProfileError::InvalidEmail(email.to_string()));
+        }
+
+        let profile_icon_path = profile_icon_path.filter(|p| !p.trim().is_empty());
+        Ok(Self { name: name.to_string(), email: email.to_string(), profile_icon_path })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn profile_icon_path(&self) -> &Option<String> {
+        &self.profile_icon_path
+    }
+
+    /// ローカル部とドメインが1つの `@` で区切られ、ドメインに `.` を含むかを確認する
+    fn is_valid_email(email: &str) -> bool {
+        if email.len() > MAX_EMAIL_LENGTH || email.chars().any(char::is_whitespace) {
+            return false;
+        }
+        match email.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && !domain.contains('@')
+                    && domain.split('.').count() >= 2
+                    && domain.split('.').all(|label| !label.is_empty())
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_user_profile_new_success() {
+        let result = UserProfile::new(" 山田 太郎 ", "taro@example.com", Some("".to_string())).unwrap();
+
+        assert_eq!(result.name(), "山田 太郎");
+        assert_eq!(result.email(), "taro@example.com");
+        assert_eq!(result.profile_icon_path(), &None);
+    }
+
+    #[test]
+    fn test_user_profile_new_name_error() {
+        assert!(matches!(UserProfile::new(" ", "taro@example.com", None), Err(UserProfileError::EmptyName)));
+        assert!(matches!(
+            UserProfile::new(&"あ".repeat(51), "taro@example.com", None),
+            Err(UserProfileError::NameTooLong)
+        ));
+    }
+
+    #[rstest]
+    #[case("taro@example.com", true)]
+    #[case("taro.yamada+sub@mail.example.co.jp", true)]
+    #[case("taro", false)]
+    #[case("@example.com", false)]
+    #[case("taro@example", false)]
+    #[case("taro@@example.com", false)]
+    #[case("taro@example..com", false)]
+    #[case("ta ro@example.com", false)]
+    fn test_user_profile_email(#[case] email: &str, #[case] expected: bool) {
+        assert_eq!(UserProfile::new("taro", email, None).is_ok(), expected, "email: {}", email);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::value_object::currency::Currency;
+
+/// ユーザーごとのアプリの設定を表す値オブジェクト
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UserSettings {
+    /// 支払予定などの通知を受け取るか
+    notification: bool,
+
+    /// 既定の通貨。未設定の場合は国の通貨を用いる
+    currency: Option<Currency>,
+}
+
+impl UserSettings {
+    /// 設定を作成する
+    ///
+    /// # 引数
+    /// * `notification` - 通知を受け取るか
+    /// * `currency` - [Option<Currency>] 既定の通貨。国の通貨を用いる場合は [None]
+    ///
+    /// # 戻り値
+    /// - [UserSettings] 作成した設定
+    pub fn new(notification: bool, currency: Option<Currency>) -> Self {
+        Self { notification, currency }
+    }
+
+    pub fn notification(&self) -> bool {
+        self.notification
+    }
+
+    pub fn currency(&self) -> &Option<Currency> {
+        &self.currency
+    }
+}
+
+impl Default for UserSettings {
+    /// 通知を受け取り、国の通貨を用いる設定
+    fn default() -> Self {
+        Self::new(true, None)
+    }
+}
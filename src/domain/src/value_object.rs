@@ -1 +1,4 @@
 pub mod amount;
+pub mod country;
+pub mod currency;
+pub mod money;
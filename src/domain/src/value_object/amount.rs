@@ -71,7 +71,7 @@ impl FromStr for Amount {
     /// - [AmountError] パースエラー
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let value = s.parse::<Decimal>().map_err(|_| AmountError::ParseError)?;
-        Ok(Self::try_from(value)?)
+        Self::try_from(value)
     }
 }
 
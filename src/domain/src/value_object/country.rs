@@ -0,0 +1,126 @@
+use crate::value_object::currency::Currency;
+
+/// 国マスタの国を表す値オブジェクト
+///
+/// 国IDは国マスタテーブルの `countryId` と対応する
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Country {
+    JP,
+    US,
+    GB,
+    DE,
+    FR,
+    KR,
+    CN,
+    AU,
+    CA,
+}
+
+impl Country {
+    const ALL: [Country; 9] = [
+        Country::JP,
+        Country::US,
+        Country::GB,
+        Country::DE,
+        Country::FR,
+        Country::KR,
+        Country::CN,
+        Country::AU,
+        Country::CA,
+    ];
+
+    /// 国IDから国を取得する
+    ///
+    /// # 引数
+    /// * `country_id` - [i32] 国ID
+    ///
+    /// # 戻り値
+    /// - [Option<Country>] 該当する国。国マスタに存在しないIDの場合は [None]
+    pub fn from_id(country_id: i32) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.id() == country_id)
+    }
+
+    /// 国IDを取得する
+    ///
+    /// # 戻り値
+    /// - [i32] 国ID
+    pub fn id(&self) -> i32 {
+        match self {
+            Country::JP => 1,
+            Country::US => 2,
+            Country::GB => 3,
+            Country::DE => 4,
+            Country::FR => 5,
+            Country::KR => 6,
+            Country::CN => 7,
+            Country::AU => 8,
+            Country::CA => 9,
+        }
+    }
+
+    /// ISO 3166-1 alpha-2 の国コードを取得する
+    ///
+    /// # 戻り値
+    /// - [&str] 2文字の国コード
+    pub fn code(&self) -> &str {
+        match self {
+            Country::JP => "JP",
+            Country::US => "US",
+            Country::GB => "GB",
+            Country::DE => "DE",
+            Country::FR => "FR",
+            Country::KR => "KR",
+            Country::CN => "CN",
+            Country::AU => "AU",
+            Country::CA => "CA",
+        }
+    }
+
+    /// 国で使用される通貨を取得する
+    ///
+    /// # 戻り値
+    /// - [Currency] 通貨
+    pub fn currency(&self) -> Currency {
+        match self {
+            Country::JP => Currency::JPY,
+            Country::US => Currency::USD,
+            Country::GB => Currency::GBP,
+            Country::DE | Country::FR => Currency::EUR,
+            Country::KR => Currency::KRW,
+            Country::CN => Currency::CNY,
+            Country::AU => Currency::AUD,
+            Country::CA => Currency::CAD,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, Some(Country::JP))]
+    #[case(2, Some(Country::US))]
+    #[case(5, Some(Country::FR))]
+    #[case(0, None)]
+    #[case(99, None)]
+    fn test_country_from_id(#[case] id: i32, #[case] expected: Option<Country>) {
+        assert_eq!(Country::from_id(id), expected);
+    }
+
+    #[test]
+    fn test_country_id_round_trip() {
+        for country in Country::ALL {
+            assert_eq!(Country::from_id(country.id()), Some(country));
+        }
+    }
+
+    #[rstest]
+    #[case(Country::JP, Currency::JPY)]
+    #[case(Country::US, Currency::USD)]
+    #[case(Country::DE, Currency::EUR)]
+    fn test_country_currency(#[case] country: Country, #[case] expected: Currency) {
+        assert_eq!(country.currency(), expected);
+    }
+}
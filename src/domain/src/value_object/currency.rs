@@ -0,0 +1,129 @@
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+use thiserror::Error;
+
+/// ISO 4217 の通貨コードを表す値オブジェクト
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Currency {
+    #[default]
+    JPY,
+    USD,
+    EUR,
+    GBP,
+    KRW,
+    CNY,
+    AUD,
+    CAD,
+}
+
+/// 通貨に関するエラー
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum CurrencyError {
+    #[error("Unsupported currency code: {0}")]
+    InvalidCode(String),
+}
+
+impl Currency {
+    /// ISO 4217 の通貨コードを取得する
+    ///
+    /// # 戻り値
+    /// - [&str] 3文字の通貨コード
+    pub fn code(&self) -> &str {
+        match self {
+            Currency::JPY => "JPY",
+            Currency::USD => "USD",
+            Currency::EUR => "EUR",
+            Currency::GBP => "GBP",
+            Currency::KRW => "KRW",
+            Currency::CNY => "CNY",
+            Currency::AUD => "AUD",
+            Currency::CAD => "CAD",
+        }
+    }
+
+    /// 補助単位の桁数を取得する
+    ///
+    /// # 戻り値
+    /// - [u32] 小数点以下の桁数 (JPYは0、USDは2)
+    pub fn minor_units(&self) -> u32 {
+        match self {
+            Currency::JPY | Currency::KRW => 0,
+            _ => 2,
+        }
+    }
+}
+
+impl FromStr for Currency {
+    type Err = CurrencyError;
+
+    /// 通貨コードから通貨を生成する
+    ///
+    /// # 引数
+    /// * `s` - [&str] ISO 4217 の通貨コード(大文字小文字は区別しない)
+    ///
+    /// # 戻り値
+    /// - [Result<Currency, CurrencyError>] 生成された通貨
+    ///
+    /// # エラー
+    /// - [CurrencyError::InvalidCode] 未対応の通貨コードの場合
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "JPY" => Ok(Currency::JPY),
+            "USD" => Ok(Currency::USD),
+            "EUR" => Ok(Currency::EUR),
+            "GBP" => Ok(Currency::GBP),
+            "KRW" => Ok(Currency::KRW),
+            "CNY" => Ok(Currency::CNY),
+            "AUD" => Ok(Currency::AUD),
+            "CAD" => Ok(Currency::CAD),
+            _ => Err(CurrencyError::InvalidCode(s.to_owned())),
+        }
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("JPY", Currency::JPY)]
+    #[case("usd", Currency::USD)]
+    #[case("Eur", Currency::EUR)]
+    fn test_currency_from_str_success(#[case] s: &str, #[case] expected: Currency) {
+        let result = Currency::from_str(s);
+        assert_eq!(result, Ok(expected));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("YEN")]
+    #[case("XXX")]
+    fn test_currency_from_str_failed(#[case] s: &str) {
+        let result = Currency::from_str(s);
+        assert_eq!(result, Err(CurrencyError::InvalidCode(s.to_owned())));
+    }
+
+    #[rstest]
+    #[case(Currency::JPY, 0)]
+    #[case(Currency::KRW, 0)]
+    #[case(Currency::USD, 2)]
+    #[case(Currency::EUR, 2)]
+    fn test_currency_minor_units(#[case] currency: Currency, #[case] expected: u32) {
+        assert_eq!(currency.minor_units(), expected);
+    }
+
+    #[test]
+    fn test_currency_to_string() {
+        assert_eq!(Currency::USD.to_string(), "USD");
+        assert_eq!(Currency::default(), Currency::JPY);
+    }
+}
@@ -0,0 +1,105 @@
+use crate::value_object::amount::{Amount, AmountError};
+use crate::value_object::currency::{Currency, CurrencyError};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// 金額と通貨を組にした値オブジェクト
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Money {
+    /// 金額
+    amount: Amount,
+
+    /// 通貨
+    currency: Currency,
+}
+
+/// 通貨付き金額に関するエラー
+#[derive(Debug, Clone, Error)]
+pub enum MoneyError {
+    #[error("{0}")]
+    InvalidAmount(#[from] AmountError),
+
+    #[error("{0}")]
+    InvalidCurrency(#[from] CurrencyError),
+
+    #[error("Amount {0} exceeds the minor units of {1}")]
+    InvalidScale(String, String),
+}
+
+impl Money {
+    /// 通貨付き金額を生成する
+    ///
+    /// # 引数
+    /// * `amount` - [Amount] 金額
+    /// * `currency` - [Currency] 通貨
+    ///
+    /// # 戻り値
+    /// - [Result<Money, MoneyError>] 生成結果
+    ///
+    /// # エラー
+    /// - [MoneyError::InvalidScale] 金額の小数点以下の桁数が通貨の補助単位を超える場合 (例: JPYで100.5)
+    pub fn new(amount: Amount, currency: Currency) -> Result<Self, MoneyError> {
+        if amount.value().normalize().scale() > currency.minor_units() {
+            return Err(MoneyError::InvalidScale(amount.to_string(), currency.to_string()));
+        }
+        Ok(Self { amount, currency })
+    }
+
+    /// 金額を取得する
+    ///
+    /// # 戻り値
+    /// - [&Amount] 金額への参照
+    pub fn amount(&self) -> &Amount {
+        &self.amount
+    }
+
+    /// 通貨を取得する
+    ///
+    /// # 戻り値
+    /// - [&Currency] 通貨への参照
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case("1980", Currency::JPY)]
+    #[case("1980.00", Currency::JPY)]
+    #[case("9.99", Currency::USD)]
+    #[case("10", Currency::USD)]
+    fn test_money_new_success(#[case] amount: &str, #[case] currency: Currency) {
+        let result = Money::new(Amount::from_str(amount).unwrap(), currency);
+
+        assert!(result.is_ok());
+        let result = result.unwrap();
+        assert_eq!(result.amount(), &Amount::from_str(amount).unwrap());
+        assert_eq!(result.currency(), &currency);
+    }
+
+    #[rstest]
+    #[case("100.5", Currency::JPY)]
+    #[case("9.999", Currency::USD)]
+    fn test_money_new_invalid_scale(#[case] amount: &str, #[case] currency: Currency) {
+        let result = Money::new(Amount::from_str(amount).unwrap(), currency);
+
+        assert!(matches!(result, Err(MoneyError::InvalidScale(_, _))));
+    }
+
+    #[test]
+    fn test_money_to_string() {
+        let money = Money::new(Amount::from_str("9.99").unwrap(), Currency::USD).unwrap();
+        assert_eq!(money.to_string(), "9.99 USD");
+    }
+}
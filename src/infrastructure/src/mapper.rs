@@ -1,5 +1,5 @@
 use aws_sdk_dynamodb::types::AttributeValue;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 use std::collections::HashMap;
 use std::str::FromStr;
 
@@ -8,7 +8,7 @@ use std::str::FromStr;
 /// Tはドメインモデルの型、Eはエラー型を表します
 ///
 /// # Example
-/// ```
+/// ```ignore
 /// impl Mapper<PaymentMethod, PaymentError> for PaymentRepositoryImpl {
 ///     fn to_domain_model(v: HashMap<String, AttributeValue>) -> Result<PaymentMethod, PaymentError> {
 ///         // 実装
@@ -43,6 +43,19 @@ pub fn as_datetime(val: Option<&AttributeValue>) -> Option<DateTime<Utc>> {
     val.and_then(|v| v.as_s().ok()).and_then(|s| DateTime::<Utc>::from_str(s).ok())
 }
 
+/// UTC日時を文字列の大小比較で前後関係を判定できる固定長の形式に変換します
+///
+/// フィルター式で日時を比較する属性(削除日時など)の保存に使用します
+///
+/// # Arguments
+/// * `value` - 変換する日時
+///
+/// # Returns
+/// 秒単位に切り捨てた `2024-01-01T00:00:00Z` 形式のAttributeValue
+pub fn comparable_datetime(value: &DateTime<Utc>) -> AttributeValue {
+    AttributeValue::S(value.to_rfc3339_opts(SecondsFormat::Secs, true))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +98,15 @@ mod tests {
         assert!(result.is_some())
     }
 
+    #[test]
+    fn test_comparable_datetime_round_trip() {
+        let value = DateTime::<Utc>::from_str("2024-01-01T09:30:15.123+09:00").unwrap();
+        let attr = comparable_datetime(&value);
+
+        assert_eq!(attr.as_s().unwrap(), "2024-01-01T00:30:15Z");
+        assert_eq!(as_datetime(Some(&attr)).unwrap().timestamp(), value.timestamp())
+    }
+
     #[test]
     fn test_as_datetime_default_value() {
         let binding1 = None;
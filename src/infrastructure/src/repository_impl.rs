@@ -1,3 +1,6 @@
+pub mod budget_repository_impl;
 pub mod category_repository_impl;
+pub mod ledger_repository_impl;
 pub mod payment_repository_impl;
 pub mod subscribe_repository_impl;
+pub mod user_repository_impl;
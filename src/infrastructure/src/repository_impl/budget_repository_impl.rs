@@ -0,0 +1,306 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::error::ProvideErrorMetadata;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use domain::{
+    budget::{budget_error::BudgetError, budget_id::BudgetId, Budget},
+    category::category_id::CategoryId,
+    repository::budget_repository::BudgetRepository,
+    user::user_id::UserId,
+    value_object::{amount::Amount, currency::Currency, money::Money},
+    AggregateId,
+};
+use tracing::{error, info};
+
+use crate::mapper::{as_datetime, as_string, Mapper};
+
+const BUDGET_KEY: &str = "budget_id";
+const USER_ID: &str = "user_id";
+
+const CATEGORY_ID: &str = "category_id";
+const LIMIT_AMOUNT: &str = "limit_amount";
+const CURRENCY: &str = "currency";
+const CREATED_AT: &str = "created_at";
+const UPDATED_AT: &str = "updated_at";
+
+const USER_ID_CONDITION: &str = "#user_id = :user_id";
+const USER_ID_ATTR: &str = "#user_id";
+const USER_ID_VALUE: &str = ":user_id";
+
+const EXISTS_CONDITION: &str = "attribute_exists(#user_id)";
+
+const UPDATE_EXPRESSION: &str = "SET #category_id = :category_id, \
+                                   #limit_amount = :limit_amount, \
+                                   #currency = :currency, \
+                                   #updated_at = :updated_at";
+
+const CATEGORY_ID_ATTR: &str = "#category_id";
+const LIMIT_AMOUNT_ATTR: &str = "#limit_amount";
+const CURRENCY_ATTR: &str = "#currency";
+const UPDATED_AT_ATTR: &str = "#updated_at";
+
+const CATEGORY_ID_VALUE: &str = ":category_id";
+const LIMIT_AMOUNT_VALUE: &str = ":limit_amount";
+const CURRENCY_VALUE: &str = ":currency";
+const UPDATED_AT_VALUE: &str = ":updated_at";
+
+pub struct BudgetRepositoryImpl {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl BudgetRepositoryImpl {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
+        Self { client, table: table.to_string() }
+    }
+}
+
+/// 全体の予算はカテゴリIDをNULLとして保存する
+fn category_id_attribute(budget: &Budget) -> AttributeValue {
+    match budget.category_id() {
+        Some(v) => AttributeValue::S(v.value().to_owned()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn updated_at_attribute(budget: &Budget) -> AttributeValue {
+    match budget.updated_at() {
+        Some(v) => AttributeValue::S(v.to_rfc3339()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+#[async_trait::async_trait]
+impl BudgetRepository for BudgetRepositoryImpl {
+    async fn create(&self, budget: &Budget) -> Result<(), BudgetError> {
+        let request = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .item(BUDGET_KEY, AttributeValue::S(budget.budget_id().value().to_owned()))
+            .item(USER_ID, AttributeValue::S(budget.user_id().value().to_owned()))
+            .item(CATEGORY_ID, category_id_attribute(budget))
+            .item(LIMIT_AMOUNT, AttributeValue::S(budget.limit().amount().to_string()))
+            .item(CURRENCY, AttributeValue::S(budget.limit().currency().to_string()))
+            .item(CREATED_AT, AttributeValue::S(budget.created_at().to_rfc3339()))
+            .item(UPDATED_AT, updated_at_attribute(budget));
+
+        match request.send().await {
+            Ok(p) => {
+                info!("{:?}", p);
+                Ok(())
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(BudgetError::CreateBudgetFailed(msg))
+            }
+        }
+    }
+
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<Budget>, BudgetError> {
+        let result = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression(USER_ID_CONDITION)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                BudgetError::QueryError(msg)
+            })?;
+
+        match result.items {
+            Some(items) => {
+                info!("{:?}", items);
+                items.into_iter().map(BudgetRepositoryImpl::map_to_domain_model).collect()
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn find_by_id(&self, budget_id: &BudgetId, user_id: &UserId) -> Result<Budget, BudgetError> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(BUDGET_KEY, AttributeValue::S(budget_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                BudgetError::FindByIdError(msg)
+            })?;
+
+        match result.item {
+            Some(item) => {
+                info!("{:?}", item);
+                BudgetRepositoryImpl::map_to_domain_model(item)
+            }
+            None => {
+                error!("{:?}, {:?}", BudgetError::NotExists.to_string(), &budget_id);
+                Err(BudgetError::NotExists)
+            }
+        }
+    }
+
+    async fn update(&self, budget: &Budget) -> Result<(), BudgetError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key(BUDGET_KEY, AttributeValue::S(budget.budget_id().value().to_owned()))
+            .key(USER_ID, AttributeValue::S(budget.user_id().value().to_owned()))
+            .update_expression(UPDATE_EXPRESSION)
+            .condition_expression(EXISTS_CONDITION)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(CATEGORY_ID_ATTR, CATEGORY_ID)
+            .expression_attribute_names(LIMIT_AMOUNT_ATTR, LIMIT_AMOUNT)
+            .expression_attribute_names(CURRENCY_ATTR, CURRENCY)
+            .expression_attribute_names(UPDATED_AT_ATTR, UPDATED_AT)
+            .expression_attribute_values(CATEGORY_ID_VALUE, category_id_attribute(budget))
+            .expression_attribute_values(LIMIT_AMOUNT_VALUE, AttributeValue::S(budget.limit().amount().to_string()))
+            .expression_attribute_values(CURRENCY_VALUE, AttributeValue::S(budget.limit().currency().to_string()))
+            .expression_attribute_values(UPDATED_AT_VALUE, updated_at_attribute(budget))
+            .send()
+            .await;
+
+        match result {
+            Ok(u) => {
+                info!("{:?}", u);
+                Ok(())
+            }
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = BudgetError::NotExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(BudgetError::UpdateBudgetFailed(msg))
+            }
+        }
+    }
+
+    async fn delete(&self, budget_id: &BudgetId, user_id: &UserId) -> Result<(), BudgetError> {
+        let result = self
+            .client
+            .delete_item()
+            .table_name(&self.table)
+            .key(BUDGET_KEY, AttributeValue::S(budget_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .return_values(ReturnValue::AllOld)
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                BudgetError::DeleteBudgetFailed(msg)
+            });
+
+        match result {
+            Ok(u) => {
+                if u.attributes.is_none() {
+                    let err = BudgetError::NotExists;
+                    error!("{:?}", err);
+                    return Err(err);
+                }
+                info!("{:?}", u);
+                Ok(())
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Mapper<Budget, BudgetError> for BudgetRepositoryImpl {
+    fn map_to_domain_model(v: HashMap<String, AttributeValue>) -> Result<Budget, BudgetError> {
+        let budget_id = BudgetId::from_str(&as_string(v.get(BUDGET_KEY), ""))?;
+        let user_id = UserId::from_str(&as_string(v.get(USER_ID), ""))?;
+        let category_id = match v.get(CATEGORY_ID).and_then(|c| c.as_s().ok()) {
+            Some(c) => Some(CategoryId::from_str(c)?),
+            None => None,
+        };
+        let amount = Amount::from_str(&as_string(v.get(LIMIT_AMOUNT), ""))?;
+        let currency = Currency::from_str(&as_string(v.get(CURRENCY), ""))?;
+        let limit = Money::new(amount, currency)?;
+        let created_at = as_datetime(v.get(CREATED_AT)).ok_or(BudgetError::MissingField(CREATED_AT.to_string()))?;
+        let updated_at = as_datetime(v.get(UPDATED_AT));
+
+        Ok(Budget::from(budget_id, user_id, category_id, limit, created_at, updated_at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    #[test]
+    fn test_map_to_domain_model_success() {
+        let category_id = CategoryId::new();
+        let test = HashMap::from([
+            (BUDGET_KEY.into(), AttributeValue::S(BudgetId::new().to_string())),
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (CATEGORY_ID.into(), AttributeValue::S(category_id.to_string())),
+            (LIMIT_AMOUNT.into(), AttributeValue::S("5000".into())),
+            (CURRENCY.into(), AttributeValue::S("JPY".into())),
+            (CREATED_AT.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (UPDATED_AT.into(), AttributeValue::Null(true)),
+        ]);
+
+        match BudgetRepositoryImpl::map_to_domain_model(test.clone()) {
+            Ok(v) => {
+                assert_eq!(v.budget_id().to_string(), as_string(test.get(BUDGET_KEY), ""));
+                assert_eq!(v.user_id().to_string(), as_string(test.get(USER_ID), ""));
+                assert_eq!(v.category_id(), &Some(category_id));
+                assert_eq!(v.limit().to_string(), "5000 JPY");
+                assert!(v.updated_at().is_none());
+            }
+            Err(e) => {
+                println!("{:?}", e.to_string());
+                panic!("{:?}", e)
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_to_domain_model_overall_budget() {
+        let test = HashMap::from([
+            (BUDGET_KEY.into(), AttributeValue::S(BudgetId::new().to_string())),
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (CATEGORY_ID.into(), AttributeValue::Null(true)),
+            (LIMIT_AMOUNT.into(), AttributeValue::S("49.99".into())),
+            (CURRENCY.into(), AttributeValue::S("USD".into())),
+            (CREATED_AT.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+        ]);
+
+        let result = BudgetRepositoryImpl::map_to_domain_model(test).unwrap();
+
+        assert!(result.is_overall());
+    }
+}
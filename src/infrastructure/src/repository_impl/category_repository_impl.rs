@@ -1,19 +1,34 @@
 use std::str::FromStr;
 
 use aws_sdk_dynamodb::error::ProvideErrorMetadata;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue, TransactWriteItem, Update};
+use chrono::{DateTime, Utc};
 use domain::{
-    category::{category_error::CategoryError, category_id::CategoryId, category_name::CategoryName, Category},
+    category::{
+        category_appearance::{CategoryColor, CategoryIcon},
+        category_error::CategoryError,
+        category_id::CategoryId,
+        category_name::CategoryName,
+        Category,
+    },
     repository::category_repository::CategoryRepository,
+    subscribe::Subscribe,
     user::user_id::UserId,
 };
 use tracing::{error, info};
 
-use crate::mapper::{as_string, Mapper};
+use crate::mapper::{as_datetime, as_string, comparable_datetime, Mapper};
 
 const CATEGORY_KEY: &str = "category_id";
 const USER_ID: &str = "user_id";
 const CATEGORY_NAME: &str = "category_name";
+const COLOR: &str = "color";
+const ICON: &str = "icon";
+const SORT_ORDER: &str = "sort_order";
+const CREATED_AT: &str = "created_at";
+const UPDATED_AT: &str = "updated_at";
+const DELETED_AT: &str = "deleted_at";
+const SUBSCRIBE_KEY: &str = "subscribe_id";
 
 const USER_ID_CONDITION: &str = "#user_id = :user_id";
 const USER_ID_ATTR: &str = "#user_id";
@@ -22,17 +37,95 @@ const USER_ID_VALUE: &str = ":user_id";
 const CATEGORY_NAME_ATTR: &str = "#category_name";
 const CATEGORY_NAME_VALUE: &str = ":category_name";
 
-const UPDATE_EXPRESSION: &str = "SET #category_name = :category_name";
+const COLOR_ATTR: &str = "#color";
+const COLOR_VALUE: &str = ":color";
+const ICON_ATTR: &str = "#icon";
+const ICON_VALUE: &str = ":icon";
+
+const SORT_ORDER_ATTR: &str = "#sort_order";
+const SORT_ORDER_VALUE: &str = ":sort_order";
+
+const UPDATED_AT_ATTR: &str = "#updated_at";
+const UPDATED_AT_VALUE: &str = ":updated_at";
+const DELETED_AT_ATTR: &str = "#deleted_at";
+const DELETED_AT_VALUE: &str = ":deleted_at";
+const CATEGORY_ID_ATTR: &str = "#category_id";
+const CATEGORY_ID_VALUE: &str = ":category_id";
+const FROM_CATEGORY_ID_VALUE: &str = ":from_category_id";
+
+const UPDATE_EXPRESSION: &str =
+    "SET #category_name = :category_name, #color = :color, #icon = :icon, #updated_at = :updated_at";
+const REORDER_EXPRESSION: &str = "SET #sort_order = :sort_order, #updated_at = :updated_at";
+const SOFT_DELETE_EXPRESSION: &str = "SET #deleted_at = :deleted_at";
+const RESTORE_EXPRESSION: &str = "REMOVE #deleted_at";
+const REASSIGN_EXPRESSION: &str = "SET #category_id = :category_id, #updated_at = :updated_at";
+
+const NOT_DELETED_CONDITION: &str = "attribute_not_exists(#deleted_at)";
+const DELETED_CONDITION: &str = "attribute_exists(#deleted_at)";
+const DELETED_BEFORE_CONDITION: &str = "#deleted_at < :deleted_at";
+const EXISTS_NOT_DELETED_CONDITION: &str = "attribute_exists(#user_id) AND attribute_not_exists(#deleted_at)";
+/// ゴミ箱にあるサブスクも付け替えるため、削除日時は条件に含めない
+const BELONGS_TO_CATEGORY_CONDITION: &str = "attribute_exists(#user_id) AND #category_id = :from_category_id";
+
+/// 1つのトランザクションで書き込めるアイテムの上限
+const MAX_TRANSACT_ITEMS: usize = 100;
 
 #[derive(Debug)]
 pub struct CategoryRepositoryImpl {
     client: aws_sdk_dynamodb::Client,
     table: String,
+    /// カテゴリの削除時にサブスクを付け替えるためのサブスクのテーブル
+    subscribe_table: String,
 }
 
 impl CategoryRepositoryImpl {
-    pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
-        Self { client, table: table.to_owned() }
+    pub fn new(client: aws_sdk_dynamodb::Client, table: &str, subscribe_table: &str) -> Self {
+        Self { client, table: table.to_owned(), subscribe_table: subscribe_table.to_owned() }
+    }
+
+    /// ユーザーのカテゴリを削除日時の有無で絞り込んで取得する
+    async fn query_by_user(&self, user_id: &UserId, filter: &str) -> Result<Vec<Category>, CategoryError> {
+        let result = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression(USER_ID_CONDITION.to_string())
+            .filter_expression(filter)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                CategoryError::QueryError(msg)
+            })?;
+        match result.items {
+            Some(v) => v.into_iter().map(CategoryRepositoryImpl::map_to_domain_model).collect(),
+            None => Ok(vec![]),
+        }
+    }
+}
+
+/// 未設定の値はNULLとして保存する
+fn optional_attribute<T: ToString>(value: &Option<T>) -> AttributeValue {
+    match value {
+        Some(v) => AttributeValue::S(v.to_string()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn updated_at_attribute(category: &Category) -> AttributeValue {
+    optional_datetime_attribute(category.updated_at())
+}
+
+fn optional_datetime_attribute(value: &Option<DateTime<Utc>>) -> AttributeValue {
+    match value {
+        Some(v) => AttributeValue::S(v.to_rfc3339()),
+        None => AttributeValue::Null(true),
     }
 }
 
@@ -51,7 +144,12 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 .table_name(&self.table)
                 .item(CATEGORY_KEY, AttributeValue::S(category.category_id().to_string()))
                 .item(USER_ID, AttributeValue::S(category.user_id().to_string()))
-                .item(CATEGORY_NAME, AttributeValue::S(category.category_name().to_string()));
+                .item(CATEGORY_NAME, AttributeValue::S(category.category_name().to_string()))
+                .item(COLOR, optional_attribute(category.color()))
+                .item(ICON, optional_attribute(category.icon()))
+                .item(SORT_ORDER, AttributeValue::N(category.sort_order().to_string()))
+                .item(CREATED_AT, AttributeValue::S(category.created_at().to_rfc3339()))
+                .item(UPDATED_AT, updated_at_attribute(category));
 
             match request.send().await {
                 Ok(p) => {
@@ -79,29 +177,10 @@ impl CategoryRepository for CategoryRepositoryImpl {
         >,
     > {
         let result = Box::pin(async move {
-            let result = self
-                .client
-                .query()
-                .table_name(&self.table)
-                .key_condition_expression(USER_ID_CONDITION.to_string())
-                .expression_attribute_names(USER_ID_ATTR, USER_ID)
-                .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
-                .send()
-                .await
-                .map_err(|e| {
-                    let msg = match e.message() {
-                        Some(s) => s.to_string(),
-                        None => e.to_string(),
-                    };
-                    CategoryError::QueryError(msg)
-                })?;
-            match result.items {
-                Some(v) => {
-                    let result = v.into_iter().map(|item| CategoryRepositoryImpl::map_to_domain_model(item)).collect();
-                    result
-                }
-                None => Ok(vec![]),
-            }
+            let mut categories = self.query_by_user(user_id, NOT_DELETED_CONDITION).await?;
+            // 並び順が同じ場合は登録の古い順に並べる
+            categories.sort_by_key(|c| (c.sort_order(), *c.created_at()));
+            Ok(categories)
         });
         result
     }
@@ -118,7 +197,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 + '_,
         >,
     > {
-        let result = Box::pin(async move {
+        (Box::pin(async move {
             let result = self
                 .client
                 .get_item()
@@ -136,19 +215,18 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 })?;
 
             match result.item {
-                Some(item) => {
+                Some(item) if !item.contains_key(DELETED_AT) => {
                     info!("{:?}", item);
                     CategoryRepositoryImpl::map_to_domain_model(item)
                 }
-                None => {
+                _ => {
                     let error =
                         CategoryError::FindByIdError(format!("category_id: {}, user_id: {}", category_id, user_id));
                     error!("{:?}", error);
                     Err(error)
                 }
             }
-        });
-        result
+        })) as _
     }
 
     fn update<'a>(
@@ -157,7 +235,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
     ) -> std::pin::Pin<
         Box<dyn std::future::Future<Output = Result<(), domain::category::category_error::CategoryError>> + Send + '_>,
     > {
-        let result = Box::pin(async move {
+        Box::pin(async move {
             let result = self
                 .client
                 .update_item()
@@ -165,33 +243,104 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 .key(CATEGORY_KEY, AttributeValue::S(category.category_id().to_string()))
                 .key(USER_ID, AttributeValue::S(category.user_id().to_string()))
                 .update_expression(UPDATE_EXPRESSION)
+                .condition_expression(EXISTS_NOT_DELETED_CONDITION)
+                .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
                 .expression_attribute_names(CATEGORY_NAME_ATTR, CATEGORY_NAME)
+                .expression_attribute_names(COLOR_ATTR, COLOR)
+                .expression_attribute_names(ICON_ATTR, ICON)
+                .expression_attribute_names(UPDATED_AT_ATTR, UPDATED_AT)
                 .expression_attribute_values(
                     CATEGORY_NAME_VALUE,
                     AttributeValue::S(category.category_name().to_string()),
                 )
+                .expression_attribute_values(COLOR_VALUE, optional_attribute(category.color()))
+                .expression_attribute_values(ICON_VALUE, optional_attribute(category.icon()))
+                .expression_attribute_values(UPDATED_AT_VALUE, updated_at_attribute(category))
                 .send()
-                .await
-                .map_err(|e| {
+                .await;
+
+            match result {
+                Ok(p) => {
+                    info!("{:?}", p);
+                    Ok(())
+                }
+                // 存在しない、またはゴミ箱にあるカテゴリは更新しない
+                Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                    let err = CategoryError::NotExist;
+                    error!("{:?}", err);
+                    Err(err)
+                }
+                Err(e) => {
+                    error!("{:?}", e);
                     let msg = match e.message() {
                         Some(s) => s.to_string(),
                         None => e.to_string(),
                     };
-                    CategoryError::UpdateCategoryFailed(msg)
-                });
+                    Err(CategoryError::UpdateCategoryFailed(msg))
+                }
+            }
+        })
+    }
+
+    fn reorder<'a>(
+        &'a self,
+        categories: &'a [Category],
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>> {
+        Box::pin(async move {
+            if categories.is_empty() {
+                return Ok(());
+            }
+            if categories.len() > MAX_TRANSACT_ITEMS {
+                return Err(CategoryError::InvalidOrder(format!(
+                    "cannot reorder more than {} categories at once",
+                    MAX_TRANSACT_ITEMS
+                )));
+            }
+
+            let mut items = Vec::with_capacity(categories.len());
+            for category in categories {
+                let update = Update::builder()
+                    .table_name(&self.table)
+                    .key(USER_ID, AttributeValue::S(category.user_id().to_string()))
+                    .key(CATEGORY_KEY, AttributeValue::S(category.category_id().to_string()))
+                    .update_expression(REORDER_EXPRESSION)
+                    .condition_expression(EXISTS_NOT_DELETED_CONDITION)
+                    .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                    .expression_attribute_names(SORT_ORDER_ATTR, SORT_ORDER)
+                    .expression_attribute_names(UPDATED_AT_ATTR, UPDATED_AT)
+                    .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                    .expression_attribute_values(SORT_ORDER_VALUE, AttributeValue::N(category.sort_order().to_string()))
+                    .expression_attribute_values(UPDATED_AT_VALUE, updated_at_attribute(category))
+                    .build()
+                    .map_err(|e| CategoryError::UpdateCategoryFailed(e.to_string()))?;
+                items.push(TransactWriteItem::builder().update(update).build());
+            }
+
+            let result = self.client.transact_write_items().set_transact_items(Some(items)).send().await;
 
             match result {
-                Ok(p) => {
-                    info!("{:?}", p);
+                Ok(t) => {
+                    info!("{:?}", t);
                     Ok(())
                 }
+                // 並べ替えの途中で削除されたカテゴリが含まれていた場合
+                Err(e) if e.as_service_error().is_some_and(|e| e.is_transaction_canceled_exception()) => {
+                    let err = CategoryError::NotExist;
+                    error!("{:?}", err);
+                    Err(err)
+                }
                 Err(e) => {
-                    error!("{:?}", e);
-                    Err(CategoryError::UpdateCategoryFailed(e.to_string()))
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    let err = CategoryError::UpdateCategoryFailed(msg);
+                    error!("{:?}", err);
+                    Err(err)
                 }
             }
-        });
-        result
+        })
     }
 
     fn delete<'a>(
@@ -201,6 +350,216 @@ impl CategoryRepository for CategoryRepositoryImpl {
     ) -> std::pin::Pin<
         Box<dyn std::future::Future<Output = Result<(), domain::category::category_error::CategoryError>> + Send + '_>,
     > {
+        Box::pin(async move {
+            let result = self
+                .client
+                .update_item()
+                .table_name(&self.table)
+                .key(USER_ID, AttributeValue::S(user_id.to_string()))
+                .key(CATEGORY_KEY, AttributeValue::S(category_id.to_string()))
+                .update_expression(SOFT_DELETE_EXPRESSION)
+                .condition_expression(EXISTS_NOT_DELETED_CONDITION)
+                .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(&Utc::now()))
+                .send()
+                .await;
+
+            match result {
+                Ok(u) => {
+                    info!("{:?}", u);
+                    Ok(())
+                }
+                // 存在しない、または既にゴミ箱に移動したカテゴリ
+                Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                    let err = CategoryError::NotExist;
+                    error!("{:?}", err);
+                    Err(err)
+                }
+                Err(e) => {
+                    error!("{:?}", e);
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    Err(CategoryError::DeleteCategoryFailed(msg))
+                }
+            }
+        })
+    }
+
+    fn delete_with_reassignment<'a>(
+        &'a self,
+        category_id: &'a CategoryId,
+        user_id: &'a UserId,
+        subscribes: &'a [Subscribe],
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>> {
+        Box::pin(async move {
+            // カテゴリの削除も同じトランザクションに含めるため、サブスクは上限より1件少なくなる
+            if subscribes.len() >= MAX_TRANSACT_ITEMS {
+                return Err(CategoryError::DeleteCategoryFailed(format!(
+                    "cannot reassign more than {} subscriptions at once",
+                    MAX_TRANSACT_ITEMS - 1
+                )));
+            }
+
+            let mut items = Vec::with_capacity(subscribes.len() + 1);
+            for subscribe in subscribes {
+                let update = Update::builder()
+                    .table_name(&self.subscribe_table)
+                    .key(SUBSCRIBE_KEY, AttributeValue::S(subscribe.subscribe_id().to_string()))
+                    .key(USER_ID, AttributeValue::S(subscribe.user_id().to_string()))
+                    .update_expression(REASSIGN_EXPRESSION)
+                    .condition_expression(BELONGS_TO_CATEGORY_CONDITION)
+                    .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                    .expression_attribute_names(CATEGORY_ID_ATTR, CATEGORY_KEY)
+                    .expression_attribute_names(UPDATED_AT_ATTR, UPDATED_AT)
+                    .expression_attribute_values(
+                        CATEGORY_ID_VALUE,
+                        AttributeValue::S(subscribe.category_id().to_string()),
+                    )
+                    .expression_attribute_values(FROM_CATEGORY_ID_VALUE, AttributeValue::S(category_id.to_string()))
+                    .expression_attribute_values(UPDATED_AT_VALUE, optional_datetime_attribute(subscribe.updated_at()))
+                    .build()
+                    .map_err(|e| CategoryError::DeleteCategoryFailed(e.to_string()))?;
+                items.push(TransactWriteItem::builder().update(update).build());
+            }
+
+            let delete = Update::builder()
+                .table_name(&self.table)
+                .key(USER_ID, AttributeValue::S(user_id.to_string()))
+                .key(CATEGORY_KEY, AttributeValue::S(category_id.to_string()))
+                .update_expression(SOFT_DELETE_EXPRESSION)
+                .condition_expression(EXISTS_NOT_DELETED_CONDITION)
+                .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(&Utc::now()))
+                .build()
+                .map_err(|e| CategoryError::DeleteCategoryFailed(e.to_string()))?;
+            items.push(TransactWriteItem::builder().update(delete).build());
+
+            let result = self.client.transact_write_items().set_transact_items(Some(items)).send().await;
+
+            match result {
+                Ok(t) => {
+                    info!("{:?}", t);
+                    Ok(())
+                }
+                // カテゴリが削除済み、またはサブスクが途中で別のカテゴリに変更された場合
+                Err(e) if e.as_service_error().is_some_and(|e| e.is_transaction_canceled_exception()) => {
+                    let err = CategoryError::NotExist;
+                    error!("{:?}", err);
+                    Err(err)
+                }
+                Err(e) => {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    let err = CategoryError::DeleteCategoryFailed(msg);
+                    error!("{:?}", err);
+                    Err(err)
+                }
+            }
+        })
+    }
+
+    fn restore<'a>(
+        &'a self,
+        category_id: &'a CategoryId,
+        user_id: &'a UserId,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>> {
+        Box::pin(async move {
+            let result = self
+                .client
+                .update_item()
+                .table_name(&self.table)
+                .key(USER_ID, AttributeValue::S(user_id.to_string()))
+                .key(CATEGORY_KEY, AttributeValue::S(category_id.to_string()))
+                .update_expression(RESTORE_EXPRESSION)
+                .condition_expression(DELETED_CONDITION)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .send()
+                .await;
+
+            match result {
+                Ok(u) => {
+                    info!("{:?}", u);
+                    Ok(())
+                }
+                // ゴミ箱に存在しないカテゴリ
+                Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                    let err = CategoryError::NotExist;
+                    error!("{:?}", err);
+                    Err(err)
+                }
+                Err(e) => {
+                    error!("{:?}", e);
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    Err(CategoryError::UpdateCategoryFailed(msg))
+                }
+            }
+        })
+    }
+
+    fn find_deleted<'a>(
+        &'a self,
+        user_id: &'a UserId,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Category>, CategoryError>> + Send + '_>> {
+        Box::pin(async move { self.query_by_user(user_id, DELETED_CONDITION).await })
+    }
+
+    fn find_deleted_before<'a>(
+        &'a self,
+        before: &'a DateTime<Utc>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Category>, CategoryError>> + Send + '_>> {
+        Box::pin(async move {
+            let mut categories = vec![];
+            let mut exclusive_start_key = None;
+
+            loop {
+                let result = self
+                    .client
+                    .scan()
+                    .table_name(&self.table)
+                    .filter_expression(DELETED_BEFORE_CONDITION)
+                    .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                    .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(before))
+                    .set_exclusive_start_key(exclusive_start_key)
+                    .send()
+                    .await
+                    .map_err(|e| {
+                        let msg = match e.message() {
+                            Some(s) => s.to_string(),
+                            None => e.to_string(),
+                        };
+                        CategoryError::QueryError(msg)
+                    })?;
+
+                if let Some(items) = result.items {
+                    for item in items {
+                        categories.push(CategoryRepositoryImpl::map_to_domain_model(item)?);
+                    }
+                }
+
+                match result.last_evaluated_key {
+                    Some(key) => exclusive_start_key = Some(key),
+                    None => break,
+                }
+            }
+
+            Ok(categories)
+        })
+    }
+
+    fn purge<'a>(
+        &'a self,
+        category_id: &'a CategoryId,
+        user_id: &'a UserId,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), CategoryError>> + Send + '_>> {
         Box::pin(async move {
             let result = self
                 .client
@@ -208,6 +567,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
                 .table_name(&self.table)
                 .key(USER_ID, AttributeValue::S(user_id.to_string()))
                 .key(CATEGORY_KEY, AttributeValue::S(category_id.to_string()))
+                .return_values(ReturnValue::AllOld)
                 .send()
                 .await
                 .map_err(|e| {
@@ -220,7 +580,7 @@ impl CategoryRepository for CategoryRepositoryImpl {
 
             match result {
                 Ok(u) => {
-                    if let None = u.attributes {
+                    if u.attributes.is_none() {
                         let err = CategoryError::NotExist;
                         error!("{:?}", err);
                         return Err(err);
@@ -242,8 +602,35 @@ impl Mapper<Category, CategoryError> for CategoryRepositoryImpl {
         let category_id = CategoryId::from_str(&as_string(v.get(CATEGORY_KEY), ""))?;
         let user_id = UserId::from_str(&as_string(v.get(USER_ID), ""))?;
         let category_name = CategoryName::from_str(&as_string(v.get(CATEGORY_NAME), ""))?;
+        let color = match v.get(COLOR).and_then(|c| c.as_s().ok()) {
+            Some(c) => Some(CategoryColor::from_str(c)?),
+            None => None,
+        };
+        let icon = match v.get(ICON).and_then(|i| i.as_s().ok()) {
+            Some(i) => Some(CategoryIcon::from_str(i)?),
+            None => None,
+        };
+        // 並び順の記録前に登録されたアイテムは先頭として扱う
+        let sort_order = match v.get(SORT_ORDER).and_then(|n| n.as_n().ok()) {
+            Some(n) => n.parse::<u32>().map_err(|_| CategoryError::ParseCategoryFailed(SORT_ORDER.into()))?,
+            None => 0,
+        };
+        // 作成日時の記録前に登録されたアイテムは最も古いものとして扱う
+        let created_at = as_datetime(v.get(CREATED_AT)).unwrap_or(DateTime::UNIX_EPOCH);
+        let updated_at = as_datetime(v.get(UPDATED_AT));
+        let deleted_at = as_datetime(v.get(DELETED_AT));
 
-        Ok(Category::from(category_id, user_id, category_name))
+        Ok(Category::from(
+            category_id,
+            user_id,
+            category_name,
+            color,
+            icon,
+            sort_order,
+            created_at,
+            updated_at,
+            deleted_at,
+        ))
     }
 }
 
@@ -260,21 +647,39 @@ mod tests {
                 (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
                 (CATEGORY_NAME.into(), AttributeValue::S("123".to_string())),
             ]),
+            HashMap::from([
+                (CATEGORY_KEY.into(), AttributeValue::S(CategoryId::new().to_string())),
+                (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+                (CATEGORY_NAME.into(), AttributeValue::S("hoge".to_string())),
+                (COLOR.into(), AttributeValue::S("#1E90FF".to_string())),
+                (ICON.into(), AttributeValue::Null(true)),
+                (SORT_ORDER.into(), AttributeValue::N("2".to_string())),
+                (CREATED_AT.into(), AttributeValue::S("2024-01-01T00:00:00Z".to_string())),
+                (UPDATED_AT.into(), AttributeValue::S("2024-02-01T00:00:00Z".to_string())),
+                (DELETED_AT.into(), AttributeValue::S("2024-06-01T00:00:00Z".to_string())),
+            ]),
         ];
 
-        let _test = test_case
-            .into_iter()
-            .map(|test| match CategoryRepositoryImpl::map_to_domain_model(test.clone()) {
+        for test in test_case {
+            match CategoryRepositoryImpl::map_to_domain_model(test.clone()) {
                 Ok(v) => {
                     assert_eq!(v.category_id().to_string(), as_string(test.get(CATEGORY_KEY), ""));
                     assert_eq!(v.user_id().to_string(), as_string(test.get(USER_ID), ""));
                     assert_eq!(v.category_name().to_string(), as_string(test.get(CATEGORY_NAME), ""));
+                    assert_eq!(v.is_deleted(), test.contains_key(DELETED_AT));
+                    assert_eq!(v.updated_at().is_some(), test.contains_key(UPDATED_AT));
+                    assert_eq!(
+                        v.color().as_ref().map(|c| c.to_string()),
+                        test.get(COLOR).and_then(|c| c.as_s().ok()).cloned()
+                    );
+                    assert!(v.icon().is_none());
+                    assert_eq!(v.sort_order().to_string(), test.get(SORT_ORDER).map_or("0", |n| n.as_n().unwrap()));
                 }
                 Err(e) => {
                     error!("{:?}", e);
-                    assert!(false);
+                    panic!("{:?}", e)
                 }
-            })
-            .collect::<()>();
+            }
+        }
     }
 }
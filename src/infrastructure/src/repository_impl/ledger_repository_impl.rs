@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::error::ProvideErrorMetadata;
+use aws_sdk_dynamodb::types::AttributeValue;
+use domain::{
+    ledger::{ledger_error::LedgerError, payment_id::PaymentId, payment_status::PaymentStatus, Payment},
+    repository::ledger_repository::LedgerRepository,
+    subscribe::subscribe_id::SubscribeId,
+    user::user_id::UserId,
+    value_object::{amount::Amount, currency::Currency, money::Money},
+    AggregateId,
+};
+use tracing::{error, info};
+
+use crate::mapper::{as_datetime, as_string, Mapper};
+
+const PAYMENT_KEY: &str = "payment_id";
+const USER_ID: &str = "user_id";
+
+const SUBSCRIBE_ID: &str = "subscribe_id";
+const DUE_DATE: &str = "due_date";
+const PAID_DATE: &str = "paid_date";
+const AMOUNT: &str = "amount";
+const CURRENCY: &str = "currency";
+const STATUS: &str = "status";
+const NOTE: &str = "note";
+
+const USER_ID_CONDITION: &str = "#user_id = :user_id";
+const USER_ID_ATTR: &str = "#user_id";
+const USER_ID_VALUE: &str = ":user_id";
+
+const SUBSCRIBE_ID_CONDITION: &str = "#subscribe_id = :subscribe_id";
+const SUBSCRIBE_ID_ATTR: &str = "#subscribe_id";
+const SUBSCRIBE_ID_VALUE: &str = ":subscribe_id";
+
+pub struct LedgerRepositoryImpl {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl LedgerRepositoryImpl {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
+        Self { client, table: table.to_string() }
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerRepository for LedgerRepositoryImpl {
+    async fn save(&self, payment: &Payment) -> Result<(), LedgerError> {
+        let request = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .item(PAYMENT_KEY, AttributeValue::S(payment.payment_id().value().to_owned()))
+            .item(USER_ID, AttributeValue::S(payment.user_id().value().to_owned()))
+            .item(SUBSCRIBE_ID, AttributeValue::S(payment.subscribe_id().value().to_owned()))
+            .item(DUE_DATE, AttributeValue::S(payment.due_date().to_rfc3339()))
+            .item(
+                PAID_DATE,
+                match payment.paid_date() {
+                    Some(v) => AttributeValue::S(v.to_rfc3339()),
+                    None => AttributeValue::Null(true),
+                },
+            )
+            .item(AMOUNT, AttributeValue::S(payment.amount().amount().to_string()))
+            .item(CURRENCY, AttributeValue::S(payment.amount().currency().to_string()))
+            .item(STATUS, AttributeValue::S(payment.status().to_string()))
+            .item(
+                NOTE,
+                match payment.note() {
+                    Some(v) => AttributeValue::S(v.to_owned()),
+                    None => AttributeValue::Null(true),
+                },
+            );
+
+        match request.send().await {
+            Ok(p) => {
+                info!("{:?}", p);
+                Ok(())
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(LedgerError::SaveFailed(msg))
+            }
+        }
+    }
+
+    async fn find_by_id(&self, payment_id: &PaymentId, user_id: &UserId) -> Result<Payment, LedgerError> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(PAYMENT_KEY, AttributeValue::S(payment_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                LedgerError::FindByIdError(msg)
+            })?;
+
+        match result.item {
+            Some(item) => {
+                info!("{:?}", item);
+                LedgerRepositoryImpl::map_to_domain_model(item)
+            }
+            None => {
+                error!("{:?}, {:?}", LedgerError::NotExists.to_string(), &payment_id);
+                Err(LedgerError::NotExists)
+            }
+        }
+    }
+
+    async fn find_all(&self, user_id: &UserId) -> Result<Vec<Payment>, LedgerError> {
+        let mut payments = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(&self.table)
+                .key_condition_expression(USER_ID_CONDITION)
+                .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    LedgerError::QueryError(msg)
+                })?;
+
+            if let Some(items) = result.items {
+                info!("{:?}", items);
+                for item in items {
+                    payments.push(LedgerRepositoryImpl::map_to_domain_model(item)?);
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(payments)
+    }
+
+    async fn find_by_subscribe(
+        &self,
+        user_id: &UserId,
+        subscribe_id: &SubscribeId,
+    ) -> Result<Vec<Payment>, LedgerError> {
+        let mut payments = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(&self.table)
+                .key_condition_expression(USER_ID_CONDITION)
+                .filter_expression(SUBSCRIBE_ID_CONDITION)
+                .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                .expression_attribute_names(SUBSCRIBE_ID_ATTR, SUBSCRIBE_ID)
+                .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
+                .expression_attribute_values(SUBSCRIBE_ID_VALUE, AttributeValue::S(subscribe_id.to_string()))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    LedgerError::QueryError(msg)
+                })?;
+
+            if let Some(items) = result.items {
+                info!("{:?}", items);
+                for item in items {
+                    payments.push(LedgerRepositoryImpl::map_to_domain_model(item)?);
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(payments)
+    }
+}
+
+impl Mapper<Payment, LedgerError> for LedgerRepositoryImpl {
+    fn map_to_domain_model(v: HashMap<String, AttributeValue>) -> Result<Payment, LedgerError> {
+        let payment_id = PaymentId::from_str(&as_string(v.get(PAYMENT_KEY), ""))?;
+        let user_id = UserId::from_str(&as_string(v.get(USER_ID), ""))?;
+        let subscribe_id = SubscribeId::from_str(&as_string(v.get(SUBSCRIBE_ID), ""))?;
+        let due_date = as_datetime(v.get(DUE_DATE)).ok_or(LedgerError::MissingField(DUE_DATE.to_string()))?;
+        let paid_date = as_datetime(v.get(PAID_DATE));
+        let amount = Amount::from_str(&as_string(v.get(AMOUNT), ""))?;
+        let currency = Currency::from_str(&as_string(v.get(CURRENCY), ""))?;
+        let amount = Money::new(amount, currency)?;
+        let status = PaymentStatus::from_str(&as_string(v.get(STATUS), ""))?;
+        let note = v.get(NOTE).and_then(|n| n.as_s().ok()).map(ToString::to_string);
+
+        Ok(Payment::from(payment_id, user_id, subscribe_id, due_date, paid_date, amount, status, note))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    #[test]
+    fn test_map_to_domain_model_success() {
+        let test = HashMap::from([
+            (PAYMENT_KEY.into(), AttributeValue::S(PaymentId::new().to_string())),
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (SUBSCRIBE_ID.into(), AttributeValue::S(SubscribeId::new().to_string())),
+            (DUE_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (PAID_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (AMOUNT.into(), AttributeValue::S("9.99".into())),
+            (CURRENCY.into(), AttributeValue::S("USD".into())),
+            (STATUS.into(), AttributeValue::S("PAID".into())),
+            (NOTE.into(), AttributeValue::Null(true)),
+        ]);
+
+        match LedgerRepositoryImpl::map_to_domain_model(test.clone()) {
+            Ok(v) => {
+                assert_eq!(v.payment_id().to_string(), as_string(test.get(PAYMENT_KEY), ""));
+                assert_eq!(v.user_id().to_string(), as_string(test.get(USER_ID), ""));
+                assert_eq!(v.subscribe_id().to_string(), as_string(test.get(SUBSCRIBE_ID), ""));
+                assert_eq!(v.due_date().to_rfc3339(), as_string(test.get(DUE_DATE), ""));
+                assert_eq!(v.paid_date().map(|d| d.to_rfc3339()), Some(as_string(test.get(PAID_DATE), "")));
+                assert_eq!(v.amount().to_string(), "9.99 USD");
+                assert_eq!(v.status(), &PaymentStatus::PAID);
+                assert!(v.note().is_none());
+            }
+            Err(e) => {
+                println!("{:?}", e.to_string());
+                panic!("{:?}", e)
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_to_domain_model_invalid_status() {
+        let test = HashMap::from([
+            (PAYMENT_KEY.into(), AttributeValue::S(PaymentId::new().to_string())),
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (SUBSCRIBE_ID.into(), AttributeValue::S(SubscribeId::new().to_string())),
+            (DUE_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (AMOUNT.into(), AttributeValue::S("1000".into())),
+            (CURRENCY.into(), AttributeValue::S("JPY".into())),
+            (STATUS.into(), AttributeValue::S("UNKNOWN".into())),
+        ]);
+
+        let result = LedgerRepositoryImpl::map_to_domain_model(test);
+
+        assert!(matches!(result, Err(LedgerError::InvalidPaymentStatus(_))))
+    }
+}
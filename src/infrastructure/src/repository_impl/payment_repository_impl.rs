@@ -1,6 +1,7 @@
-use crate::mapper::{as_datetime, as_string, Mapper};
+use crate::mapper::{as_datetime, as_string, comparable_datetime, Mapper};
 use aws_sdk_dynamodb::error::ProvideErrorMetadata;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use chrono::{DateTime, Utc};
 use domain::payment::payment_error::PaymentError;
 use domain::payment::payment_method_id::PaymentMethodId;
 use domain::payment::payment_method_name::{PaymentMethodCategoryName, PaymentMethodKindName};
@@ -19,28 +20,38 @@ const METHOD_KIND_NAME: &str = "method_kind_name";
 const ADDITIONAL_NAME: &str = "additional_name";
 const CREATED_AT: &str = "created_at";
 const UPDATED_AT: &str = "updated_at";
+const DELETED_AT: &str = "deleted_at";
 
 const USER_ID_CONDITION: &str = "#user_id = :user_id";
 const USER_ID_ATTR: &str = "#user_id";
 const USER_ID_VALUE: &str = ":user_id";
 
+const NOT_DELETED_CONDITION: &str = "attribute_not_exists(#deleted_at)";
+const DELETED_CONDITION: &str = "attribute_exists(#deleted_at)";
+const DELETED_BEFORE_CONDITION: &str = "#deleted_at < :deleted_at";
+const EXISTS_NOT_DELETED_CONDITION: &str = "attribute_exists(#user_id) AND attribute_not_exists(#deleted_at)";
+
 // Expression constants
 const UPDATE_EXPRESSION: &str = "SET #method_name = :method_name, \
                                    #method_kind_name = :method_kind_name, \
                                    #additional_name = :additional_name, \
                                    #updated_at = :updated_at";
+const SOFT_DELETE_EXPRESSION: &str = "SET #deleted_at = :deleted_at";
+const RESTORE_EXPRESSION: &str = "REMOVE #deleted_at";
 
 // Attribute name constants
 const METHOD_NAME_ATTR: &str = "#method_name";
 const METHOD_KIND_NAME_ATTR: &str = "#method_kind_name";
 const ADDITIONAL_NAME_ATTR: &str = "#additional_name";
 const UPDATED_AT_ATTR: &str = "#updated_at";
+const DELETED_AT_ATTR: &str = "#deleted_at";
 
 // Attribute value constants
 const METHOD_NAME_VALUE: &str = ":method_name";
 const METHOD_KIND_NAME_VALUE: &str = ":method_kind_name";
 const ADDITIONAL_NAME_VALUE: &str = ":additional_name";
 const UPDATED_AT_VALUE: &str = ":updated_at";
+const DELETED_AT_VALUE: &str = ":deleted_at";
 
 pub struct PaymentRepositoryImpl {
     client: aws_sdk_dynamodb::Client,
@@ -51,6 +62,37 @@ impl PaymentRepositoryImpl {
     pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
         Self { client, table: table.to_string() }
     }
+
+    /// ユーザーの支払い方法を削除日時の有無で絞り込んで取得する
+    async fn query_by_user(&self, user_id: &UserId, filter: &str) -> Result<Vec<PaymentMethod>, PaymentError> {
+        let result = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression(USER_ID_CONDITION)
+            .filter_expression(filter)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.value().to_string()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                PaymentError::QueryError(msg)
+            })?;
+
+        match result.items {
+            Some(items) => {
+                info!("{:?}", items);
+
+                items.into_iter().map(PaymentRepositoryImpl::map_to_domain_model).collect()
+            }
+            None => Ok(vec![]),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -87,31 +129,7 @@ impl PaymentRepository for PaymentRepositoryImpl {
     }
 
     async fn find_all(&self, user_id: &UserId) -> Result<Vec<PaymentMethod>, PaymentError> {
-        let result = self
-            .client
-            .query()
-            .table_name(&self.table)
-            .key_condition_expression(USER_ID_CONDITION)
-            .expression_attribute_names(USER_ID_ATTR, USER_ID)
-            .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.value().to_string()))
-            .send()
-            .await
-            .map_err(|e| {
-                let msg = match e.message() {
-                    Some(s) => s.to_string(),
-                    None => e.to_string(),
-                };
-                PaymentError::QueryError(msg)
-            })?;
-
-        match result.items {
-            Some(items) => {
-                info!("{:?}", items);
-                let result = items.into_iter().map(|item| PaymentRepositoryImpl::map_to_domain_model(item)).collect();
-                result
-            }
-            None => Ok(vec![]),
-        }
+        self.query_by_user(user_id, NOT_DELETED_CONDITION).await
     }
 
     async fn find_by_id(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<PaymentMethod, PaymentError> {
@@ -132,11 +150,11 @@ impl PaymentRepository for PaymentRepositoryImpl {
             })?;
 
         match result.item {
-            Some(item) => {
+            Some(item) if !item.contains_key(DELETED_AT) => {
                 info!("{:?}", item);
                 PaymentRepositoryImpl::map_to_domain_model(item)
             }
-            None => {
+            _ => {
                 let error = PaymentError::FindByIdError(payment_id.value().to_string());
                 error!("{:?}", error);
                 Err(error)
@@ -195,12 +213,127 @@ impl PaymentRepository for PaymentRepositoryImpl {
     }
 
     async fn delete(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key(PAYMENT_METHOD_KEY, AttributeValue::S(payment_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .update_expression(SOFT_DELETE_EXPRESSION)
+            .condition_expression(EXISTS_NOT_DELETED_CONDITION)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(&Utc::now()))
+            .send()
+            .await;
+
+        match result {
+            Ok(u) => {
+                info!("{:?}", u);
+                Ok(())
+            }
+            // 存在しない、または既にゴミ箱に移動した支払い方法
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = PaymentError::NotExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(PaymentError::DeletePaymentMethodFailed(msg))
+            }
+        }
+    }
+
+    async fn restore(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key(PAYMENT_METHOD_KEY, AttributeValue::S(payment_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .update_expression(RESTORE_EXPRESSION)
+            .condition_expression(DELETED_CONDITION)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .send()
+            .await;
+
+        match result {
+            Ok(u) => {
+                info!("{:?}", u);
+                Ok(())
+            }
+            // ゴミ箱に存在しない支払い方法
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = PaymentError::NotExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(PaymentError::UpdatePaymentMethodError(msg))
+            }
+        }
+    }
+
+    async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<PaymentMethod>, PaymentError> {
+        self.query_by_user(user_id, DELETED_CONDITION).await
+    }
+
+    async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<PaymentMethod>, PaymentError> {
+        let mut payments = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .scan()
+                .table_name(&self.table)
+                .filter_expression(DELETED_BEFORE_CONDITION)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(before))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    PaymentError::QueryError(msg)
+                })?;
+
+            if let Some(items) = result.items {
+                for item in items {
+                    payments.push(PaymentRepositoryImpl::map_to_domain_model(item)?);
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(payments)
+    }
+
+    async fn purge(&self, payment_id: &PaymentMethodId, user_id: &UserId) -> Result<(), PaymentError> {
         let result = self
             .client
             .delete_item()
             .table_name(&self.table)
             .key(PAYMENT_METHOD_KEY, AttributeValue::S(payment_id.value().to_owned()))
             .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .return_values(ReturnValue::AllOld)
             .send()
             .await
             .map_err(|e| {
@@ -213,6 +346,11 @@ impl PaymentRepository for PaymentRepositoryImpl {
 
         match result {
             Ok(u) => {
+                if u.attributes.is_none() {
+                    let err = PaymentError::NotExists;
+                    error!("{:?}", err);
+                    return Err(err);
+                }
                 info!("{:?}", u);
                 Ok(())
             }
@@ -241,8 +379,8 @@ impl PaymentRepository for PaymentRepositoryImpl {
             })?;
 
         match result.item {
-            Some(_) => Ok(true),
-            None => {
+            Some(item) if !item.contains_key(DELETED_AT) => Ok(true),
+            _ => {
                 error!("{:?}, {:?}", PaymentError::NotExists.to_string(), &payment_id);
                 Ok(false)
             }
@@ -259,6 +397,7 @@ impl Mapper<PaymentMethod, PaymentError> for PaymentRepositoryImpl {
         let additional_name = &as_string(v.get(ADDITIONAL_NAME), "");
         let created_at = as_datetime(v.get(CREATED_AT)).ok_or(PaymentError::MissingField("created_at".to_string()))?;
         let updated_at = as_datetime(v.get(UPDATED_AT));
+        let deleted_at = as_datetime(v.get(DELETED_AT));
 
         let payment = PaymentMethod::new(
             payment_method_id,
@@ -268,6 +407,7 @@ impl Mapper<PaymentMethod, PaymentError> for PaymentRepositoryImpl {
             additional_name,
             created_at,
             updated_at,
+            deleted_at,
         );
 
         Ok(payment)
@@ -291,22 +431,33 @@ mod tests {
                 (CREATED_AT.to_string(), AttributeValue::S("2024-01-01T00:00:00Z".to_string())),
                 (UPDATED_AT.to_string(), AttributeValue::S("2077-02-02T00:00:00Z".to_string())),
             ]),
+            HashMap::from([
+                (
+                    PAYMENT_METHOD_KEY.to_string(),
+                    AttributeValue::S("pay_550e8400-e29b-41d4-a716-446655440001".to_string()),
+                ),
+                (USER_ID.to_string(), AttributeValue::S("usr_550e8400-e29b-41d4-a716-446655440000".to_string())),
+                (METHOD_NAME.to_string(), AttributeValue::S("Credit Card".to_string())),
+                (METHOD_KIND_NAME.to_string(), AttributeValue::S("JCB".to_string())),
+                (CREATED_AT.to_string(), AttributeValue::S("2024-01-01T00:00:00Z".to_string())),
+                (DELETED_AT.to_string(), AttributeValue::S("2024-06-01T00:00:00Z".to_string())),
+            ]),
         ];
 
-        let _ = test_case
-            .into_iter()
-            .map(|test| match PaymentRepositoryImpl::map_to_domain_model(test.clone()) {
+        for test in test_case {
+            match PaymentRepositoryImpl::map_to_domain_model(test.clone()) {
                 Ok(v) => {
                     assert_eq!(v.payment_method_id().value().to_string(), as_string(test.get(PAYMENT_METHOD_KEY), ""));
                     assert_eq!(v.user_id().value().to_string(), as_string(test.get(USER_ID), ""));
                     assert_eq!(v.method_name().to_string(), as_string(test.get(METHOD_NAME), ""));
-                    assert_eq!(v.method_kind_name().to_string(), as_string(test.get(METHOD_KIND_NAME), ""))
+                    assert_eq!(v.method_kind_name().to_string(), as_string(test.get(METHOD_KIND_NAME), ""));
+                    assert_eq!(v.is_deleted(), test.contains_key(DELETED_AT))
                 }
                 Err(e) => {
                     println!("{:?}", e.to_string());
-                    assert!(false)
+                    panic!("{:?}", e)
                 }
-            })
-            .collect::<()>();
+            }
+        }
     }
 }
@@ -1,23 +1,34 @@
+use std::collections::HashMap;
 use std::str::FromStr;
 
 use aws_sdk_dynamodb::error::ProvideErrorMetadata;
-use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use chrono::{DateTime, Utc};
 use domain::{
     category::category_id::CategoryId,
     payment::payment_method_id::PaymentMethodId,
     payment_cycle::PaymentCycle,
     repository::subscribe_repository::SubscribeRepository,
     subscribe::{
-        subscribe_error::SubscribeError, subscribe_id::SubscribeId, subscribe_name::SubscribeName,
-        subscribe_status::SubscribeStatus, Subscribe,
+        contract_term::ContractTerm,
+        cost_split::{CostSplit, MemberShare, Share, SplitMember},
+        price_change::PriceChange,
+        subscribe_error::SubscribeError,
+        subscribe_id::SubscribeId,
+        subscribe_name::SubscribeName,
+        subscribe_status::SubscribeStatus,
+        tag::{Tag, TagSet},
+        trial::Trial,
+        Subscribe,
     },
     user::user_id::UserId,
-    value_object::amount::Amount,
+    value_object::{amount::Amount, currency::Currency, money::Money},
     AggregateId,
 };
-use tracing::{error, info};
+use rust_decimal::Decimal;
+use tracing::{error, info, warn};
 
-use crate::mapper::{as_datetime, as_string, Mapper};
+use crate::mapper::{as_datetime, as_string, comparable_datetime, Mapper};
 
 const SUBSCRIBE_KEY: &str = "subscribe_id";
 const USER_ID: &str = "user_id";
@@ -25,6 +36,7 @@ const USER_ID: &str = "user_id";
 const NAME: &str = "name";
 const PAYMENT_METHOD_ID: &str = "payment_method_id";
 const AMOUNT: &str = "amount";
+const CURRENCY: &str = "currency";
 const PAYMENT_CYCLE: &str = "payment_cycle";
 const CATEGORY_ID: &str = "category_id";
 const ICON_LOCAL_PATH: &str = "icon_local_path";
@@ -34,14 +46,51 @@ const NEXT_PAYMENT_DATE: &str = "next_payment_date";
 const AUTO_RENEWAL: &str = "auto_renewal";
 const STATUS: &str = "status";
 const MEMO: &str = "memo";
+const TRIAL_END_DATE: &str = "trial_end_date";
+const POST_TRIAL_AMOUNT: &str = "post_trial_amount";
+const PRICE_HISTORY: &str = "price_history";
+const EFFECTIVE_DATE: &str = "effective_date";
+const AMOUNT_VERSION: &str = "amount_version";
+const COMMITMENT_END_DATE: &str = "commitment_end_date";
+const NOTICE_PERIOD_DAYS: &str = "notice_period_days";
+const COST_SPLIT: &str = "cost_split";
+const MEMBER_TYPE: &str = "member_type";
+const MEMBER: &str = "member";
+const SHARE_TYPE: &str = "share_type";
+const SHARE: &str = "share";
+const TAGS: &str = "tags";
+const CREATED_AT: &str = "created_at";
+const UPDATED_AT: &str = "updated_at";
+const DELETED_AT: &str = "deleted_at";
+
+const MEMBER_TYPE_PERSON: &str = "person";
+const MEMBER_TYPE_USER: &str = "user";
+const SHARE_TYPE_FIXED: &str = "fixed";
+const SHARE_TYPE_PERCENTAGE: &str = "percentage";
+
+/// 金額を請求額のまま保存する形式のバージョン
+const BILLED_AMOUNT_VERSION: &str = "2";
 
 const USER_ID_CONDITION: &str = "#user_id = :user_id";
+const USER_ID_CATEGORY_CONDITION: &str = "#user_id = :user_id AND #category_id = :category_id";
+const CATEGORY_INDEX: &str = "gsi-category";
 const USER_ID_ATTR: &str = "#user_id";
 const USER_ID_VALUE: &str = ":user_id";
 
+const STATUS_CONDITION: &str = "#status = :status AND attribute_not_exists(#deleted_at)";
+const NOT_DELETED_CONDITION: &str = "attribute_not_exists(#deleted_at)";
+const DELETED_CONDITION: &str = "attribute_exists(#deleted_at)";
+const DELETED_BEFORE_CONDITION: &str = "#deleted_at < :deleted_at";
+const EXISTS_NOT_DELETED_CONDITION: &str = "attribute_exists(#user_id) AND attribute_not_exists(#deleted_at)";
+const SOFT_DELETE_EXPRESSION: &str = "SET #deleted_at = :deleted_at";
+const RESTORE_EXPRESSION: &str = "REMOVE #deleted_at";
+const NOT_MIGRATED_CONDITION: &str = "attribute_not_exists(#amount_version)";
+const MIGRATE_AMOUNT_EXPRESSION: &str = "SET #amount = :amount, #amount_version = :amount_version";
+
 const UPDATE_EXPRESSION: &str = "SET #name = :name, \
                                  #payment_method_id = :payment_method_id, \
                                  #amount = :amount, \
+                                 #currency = :currency, \
                                  #payment_cycle = :payment_cycle, \
                                  #category_id = :category_id, \
                                  #icon_local_path = :icon_local_path, \
@@ -50,11 +99,21 @@ const UPDATE_EXPRESSION: &str = "SET #name = :name, \
                                  #next_payment_date = :next_payment_date, \
                                  #auto_renewal = :auto_renewal, \
                                  #status = :status, \
-                                 #memo = :memo";
+                                 #memo = :memo, \
+                                 #trial_end_date = :trial_end_date, \
+                                 #post_trial_amount = :post_trial_amount, \
+                                 #price_history = :price_history, \
+                                 #commitment_end_date = :commitment_end_date, \
+                                 #notice_period_days = :notice_period_days, \
+                                 #cost_split = :cost_split, \
+                                 #tags = :tags, \
+                                 #updated_at = :updated_at, \
+                                 #amount_version = :amount_version";
 
 const NAME_ATTR: &str = "#name";
 const PAYMENT_METHOD_ID_ATTR: &str = "#payment_method_id";
 const AMOUNT_ATTR: &str = "#amount";
+const CURRENCY_ATTR: &str = "#currency";
 const PAYMENT_CYCLE_ATTR: &str = "#payment_cycle";
 const CATEGORY_ID_ATTR: &str = "#category_id";
 const ICON_LOCAL_PATH_ATTR: &str = "#icon_local_path";
@@ -64,10 +123,21 @@ const NEXT_PAYMENT_DATE_ATTR: &str = "#next_payment_date";
 const AUTO_RENEWAL_ATTR: &str = "#auto_renewal";
 const STATUS_ATTR: &str = "#status";
 const MEMO_ATTR: &str = "#memo";
+const TRIAL_END_DATE_ATTR: &str = "#trial_end_date";
+const POST_TRIAL_AMOUNT_ATTR: &str = "#post_trial_amount";
+const PRICE_HISTORY_ATTR: &str = "#price_history";
+const AMOUNT_VERSION_ATTR: &str = "#amount_version";
+const COMMITMENT_END_DATE_ATTR: &str = "#commitment_end_date";
+const NOTICE_PERIOD_DAYS_ATTR: &str = "#notice_period_days";
+const COST_SPLIT_ATTR: &str = "#cost_split";
+const TAGS_ATTR: &str = "#tags";
+const UPDATED_AT_ATTR: &str = "#updated_at";
+const DELETED_AT_ATTR: &str = "#deleted_at";
 
 const NAME_VALUE: &str = ":name";
 const PAYMENT_METHOD_ID_VALUE: &str = ":payment_method_id";
 const AMOUNT_VALUE: &str = ":amount";
+const CURRENCY_VALUE: &str = ":currency";
 const PAYMENT_CYCLE_VALUE: &str = ":payment_cycle";
 const CATEGORY_ID_VALUE: &str = ":category_id";
 const ICON_LOCAL_PATH_VALUE: &str = ":icon_local_path";
@@ -77,6 +147,16 @@ const NEXT_PAYMENT_DATE_VALUE: &str = ":next_payment_date";
 const AUTO_RENEWAL_VALUE: &str = ":auto_renewal";
 const STATUS_VALUE: &str = ":status";
 const MEMO_VALUE: &str = ":memo";
+const TRIAL_END_DATE_VALUE: &str = ":trial_end_date";
+const POST_TRIAL_AMOUNT_VALUE: &str = ":post_trial_amount";
+const PRICE_HISTORY_VALUE: &str = ":price_history";
+const AMOUNT_VERSION_VALUE: &str = ":amount_version";
+const COMMITMENT_END_DATE_VALUE: &str = ":commitment_end_date";
+const NOTICE_PERIOD_DAYS_VALUE: &str = ":notice_period_days";
+const COST_SPLIT_VALUE: &str = ":cost_split";
+const TAGS_VALUE: &str = ":tags";
+const UPDATED_AT_VALUE: &str = ":updated_at";
+const DELETED_AT_VALUE: &str = ":deleted_at";
 
 #[derive(Debug)]
 pub struct SubscribeRepositoryImpl {
@@ -88,6 +168,356 @@ impl SubscribeRepositoryImpl {
     pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
         Self { client, table: table.to_owned() }
     }
+
+    /// 月額換算で保存されていた既存のサブスクを請求額での保存形式に移行する
+    ///
+    /// 旧形式では年払いの金額を `floor(金額 / 12)` で保存していたため、12倍した値を請求額として復元する。
+    /// 切り捨てで失われた端数(最大11)は復元できない。
+    /// 移行済みかどうかは `amount_version` で判定するため、再実行しても二重に変換されない。
+    /// 請求額を復元できない不正な既存データはログに記録してスキップし、残りの移行を続ける
+    ///
+    /// # 戻り値
+    /// - [usize] 移行したサブスクの件数
+    ///
+    /// # エラー
+    /// - [SubscribeError] 取得・更新に失敗した場合
+    pub async fn migrate_billed_amount(&self) -> Result<usize, SubscribeError> {
+        let mut count = 0;
+        let mut skipped = 0;
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .scan()
+                .table_name(&self.table)
+                .filter_expression(NOT_MIGRATED_CONDITION)
+                .expression_attribute_names(AMOUNT_VERSION_ATTR, AMOUNT_VERSION)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    SubscribeError::QueryError(msg)
+                })?;
+
+            let items = result.items.unwrap_or_default();
+            let total = items.len();
+            let restorable = restorable_items(items);
+            skipped += total - restorable.len();
+            for (item, amount) in restorable {
+                if self.migrate_item(&item, &amount).await? {
+                    count += 1;
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        info!("migrated {} subscribes, skipped {} invalid items", count, skipped);
+        Ok(count)
+    }
+
+    /// ユーザーのサブスクを削除日時の有無で絞り込んで取得する
+    async fn query_by_user(&self, user_id: &UserId, filter: &str) -> Result<Vec<Subscribe>, SubscribeError> {
+        let result = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression(USER_ID_CONDITION)
+            .filter_expression(filter)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                SubscribeError::QueryError(msg)
+            })?;
+
+        match result.items {
+            Some(items) => {
+                info!("{:?}", items);
+
+                items.into_iter().map(SubscribeRepositoryImpl::map_to_domain_model).collect()
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    /// カテゴリのGSIを用いてユーザーの指定カテゴリのサブスクを取得する
+    ///
+    /// # 引数
+    /// * `category_id` - [CategoryId] 取得対象のカテゴリID
+    /// * `user_id` - [UserId] 取得対象のユーザーID
+    async fn query_by_category(
+        &self,
+        category_id: &CategoryId,
+        user_id: &UserId,
+    ) -> Result<Vec<Subscribe>, SubscribeError> {
+        let mut subscribes = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .query()
+                .table_name(&self.table)
+                .index_name(CATEGORY_INDEX)
+                .key_condition_expression(USER_ID_CATEGORY_CONDITION)
+                .filter_expression(NOT_DELETED_CONDITION)
+                .expression_attribute_names(USER_ID_ATTR, USER_ID)
+                .expression_attribute_names(CATEGORY_ID_ATTR, CATEGORY_ID)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
+                .expression_attribute_values(CATEGORY_ID_VALUE, AttributeValue::S(category_id.to_string()))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    SubscribeError::QueryError(msg)
+                })?;
+
+            if let Some(items) = result.items {
+                for item in items {
+                    subscribes.push(SubscribeRepositoryImpl::map_to_domain_model(item)?);
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(subscribes)
+    }
+
+    async fn migrate_item(
+        &self,
+        item: &HashMap<String, AttributeValue>,
+        amount: &Amount,
+    ) -> Result<bool, SubscribeError> {
+        let mut request = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .update_expression(MIGRATE_AMOUNT_EXPRESSION)
+            .condition_expression(NOT_MIGRATED_CONDITION)
+            .expression_attribute_names(AMOUNT_ATTR, AMOUNT)
+            .expression_attribute_names(AMOUNT_VERSION_ATTR, AMOUNT_VERSION)
+            .expression_attribute_values(AMOUNT_VALUE, AttributeValue::S(amount.to_string()))
+            .expression_attribute_values(AMOUNT_VERSION_VALUE, AttributeValue::N(BILLED_AMOUNT_VERSION.to_owned()));
+        for key in [
+            SUBSCRIBE_KEY,
+            USER_ID,
+        ] {
+            if let Some(v) = item.get(key) {
+                request = request.key(key, v.clone());
+            }
+        }
+
+        match request.send().await {
+            Ok(_) => Ok(true),
+            // 移行中に更新されたアイテムは新形式で保存済みのためスキップする
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => Ok(false),
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(SubscribeError::UpdateSubscribeError(msg))
+            }
+        }
+    }
+}
+
+fn trial_end_date_attribute(subscribe: &Subscribe) -> AttributeValue {
+    match subscribe.trial() {
+        Some(trial) => AttributeValue::S(trial.end_date().to_rfc3339()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn post_trial_amount_attribute(subscribe: &Subscribe) -> AttributeValue {
+    match subscribe.trial().as_ref().and_then(|t| t.post_trial_amount().as_ref()) {
+        Some(money) => AttributeValue::S(money.amount().to_string()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn commitment_end_date_attribute(subscribe: &Subscribe) -> AttributeValue {
+    match subscribe.contract_term().commitment_end_date() {
+        Some(date) => AttributeValue::S(date.to_rfc3339()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn notice_period_days_attribute(subscribe: &Subscribe) -> AttributeValue {
+    match subscribe.contract_term().notice_period_days() {
+        Some(days) => AttributeValue::N(days.to_string()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn price_history_attribute(subscribe: &Subscribe) -> AttributeValue {
+    let history = subscribe
+        .price_history()
+        .iter()
+        .map(|p| {
+            AttributeValue::M(HashMap::from([
+                (EFFECTIVE_DATE.to_owned(), AttributeValue::S(p.effective_date().to_rfc3339())),
+                (AMOUNT.to_owned(), AttributeValue::S(p.amount().amount().to_string())),
+                (CURRENCY.to_owned(), AttributeValue::S(p.amount().currency().to_string())),
+            ]))
+        })
+        .collect();
+    AttributeValue::L(history)
+}
+
+fn cost_split_attribute(subscribe: &Subscribe) -> AttributeValue {
+    let members = subscribe
+        .cost_split()
+        .members()
+        .iter()
+        .map(|m| {
+            let (member_type, member) = match m.member() {
+                SplitMember::Person(name) => (MEMBER_TYPE_PERSON, name.to_owned()),
+                SplitMember::User(user_id) => (MEMBER_TYPE_USER, user_id.to_string()),
+            };
+            let (share_type, share) = match m.share() {
+                Share::Fixed(money) => (SHARE_TYPE_FIXED, money.amount().to_string()),
+                Share::Percentage(p) => (SHARE_TYPE_PERCENTAGE, p.to_string()),
+            };
+            AttributeValue::M(HashMap::from([
+                (MEMBER_TYPE.to_owned(), AttributeValue::S(member_type.to_owned())),
+                (MEMBER.to_owned(), AttributeValue::S(member)),
+                (SHARE_TYPE.to_owned(), AttributeValue::S(share_type.to_owned())),
+                (SHARE.to_owned(), AttributeValue::S(share)),
+            ]))
+        })
+        .collect();
+    AttributeValue::L(members)
+}
+
+/// 費用の分担設定をドメインモデルに変換する
+///
+/// 固定額は全てサブスクと同じ通貨で保存されている
+fn as_cost_split(val: Option<&AttributeValue>, currency: Currency) -> Result<CostSplit, SubscribeError> {
+    let Some(list) = val.and_then(|v| v.as_l().ok()) else {
+        return Ok(CostSplit::default());
+    };
+    let members = list
+        .iter()
+        .map(|item| {
+            let item = item.as_m().map_err(|_| SubscribeError::ParseFailed(COST_SPLIT.into()))?;
+            let member = as_string(item.get(MEMBER), "");
+            let member = match as_string(item.get(MEMBER_TYPE), "").as_str() {
+                MEMBER_TYPE_PERSON => SplitMember::Person(member),
+                MEMBER_TYPE_USER => SplitMember::User(UserId::from_str(&member)?),
+                _ => return Err(SubscribeError::ParseFailed(format!("{COST_SPLIT}.{MEMBER_TYPE}"))),
+            };
+            let share = as_string(item.get(SHARE), "");
+            let share = match as_string(item.get(SHARE_TYPE), "").as_str() {
+                SHARE_TYPE_FIXED => Share::Fixed(Money::new(Amount::from_str(&share)?, currency)?),
+                SHARE_TYPE_PERCENTAGE => Share::Percentage(
+                    Decimal::from_str(&share)
+                        .map_err(|_| SubscribeError::ParseFailed(format!("{COST_SPLIT}.{SHARE}")))?,
+                ),
+                _ => return Err(SubscribeError::ParseFailed(format!("{COST_SPLIT}.{SHARE_TYPE}"))),
+            };
+            MemberShare::new(member, share)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    CostSplit::new(members)
+}
+
+fn updated_at_attribute(subscribe: &Subscribe) -> AttributeValue {
+    match subscribe.updated_at() {
+        Some(v) => AttributeValue::S(v.to_rfc3339()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+fn tags_attribute(subscribe: &Subscribe) -> AttributeValue {
+    AttributeValue::L(subscribe.tags().iter().map(|t| AttributeValue::S(t.to_string())).collect())
+}
+
+/// タグをドメインモデルに変換する
+fn as_tags(val: Option<&AttributeValue>) -> Result<TagSet, SubscribeError> {
+    let Some(list) = val.and_then(|v| v.as_l().ok()) else {
+        return Ok(TagSet::default());
+    };
+    let tags = list
+        .iter()
+        .map(|item| {
+            let s = item.as_s().map_err(|_| SubscribeError::ParseFailed(TAGS.into()))?;
+            Ok(Tag::from_str(s)?)
+        })
+        .collect::<Result<Vec<_>, SubscribeError>>()?;
+    Ok(TagSet::new(tags)?)
+}
+
+/// 金額の変更履歴をドメインモデルに変換する
+///
+/// 通貨を記録していない旧形式の変更履歴は、サブスクの現在の通貨で保存されていたものとして扱う
+fn as_price_history(val: Option<&AttributeValue>, currency: Currency) -> Result<Vec<PriceChange>, SubscribeError> {
+    let Some(list) = val.and_then(|v| v.as_l().ok()) else {
+        return Ok(vec![]);
+    };
+    list.iter()
+        .map(|item| {
+            let item = item.as_m().map_err(|_| SubscribeError::ParseFailed(PRICE_HISTORY.into()))?;
+            let effective_date = as_datetime(item.get(EFFECTIVE_DATE))
+                .ok_or(SubscribeError::MissingField(format!("{PRICE_HISTORY}.{EFFECTIVE_DATE}")))?;
+            let currency = match item.get(CURRENCY).and_then(|c| c.as_s().ok()) {
+                Some(c) => Currency::from_str(c)?,
+                None => currency,
+            };
+            let amount = Money::new(Amount::from_str(&as_string(item.get(AMOUNT), ""))?, currency)?;
+            Ok(PriceChange::new(effective_date, amount))
+        })
+        .collect()
+}
+
+/// スキャンしたアイテムのうち請求額を復元できるものを、復元した請求額と組にして取り出す
+///
+/// 不正なアイテムは移行全体を止めないよう、ログに記録して除外する
+fn restorable_items(items: Vec<HashMap<String, AttributeValue>>) -> Vec<(HashMap<String, AttributeValue>, Amount)> {
+    items
+        .into_iter()
+        .filter_map(|item| match restore_billed_amount(&item) {
+            Ok(amount) => Some((item, amount)),
+            Err(e) => {
+                warn!("skip invalid subscribe {}: {}", as_string(item.get(SUBSCRIBE_KEY), ""), e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// 旧形式で保存された金額から請求額を復元する
+fn restore_billed_amount(item: &HashMap<String, AttributeValue>) -> Result<Amount, SubscribeError> {
+    let amount = Amount::from_str(&as_string(item.get(AMOUNT), ""))?;
+    match PaymentCycle::from_str(&as_string(item.get(PAYMENT_CYCLE), ""))? {
+        PaymentCycle::Yearly => Ok(Amount::try_from(amount.value() * Decimal::from(12))?),
+        _ => Ok(amount),
+    }
 }
 
 #[async_trait::async_trait]
@@ -102,12 +532,14 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
             .item(NAME, AttributeValue::S(subscribe.name().to_string()))
             .item(PAYMENT_METHOD_ID, AttributeValue::S(subscribe.payment_method_id().to_string()))
             .item(AMOUNT, AttributeValue::S(subscribe.amount().to_string()))
+            .item(CURRENCY, AttributeValue::S(subscribe.currency().to_string()))
+            .item(AMOUNT_VERSION, AttributeValue::N(BILLED_AMOUNT_VERSION.to_owned()))
             .item(PAYMENT_CYCLE, AttributeValue::S(subscribe.payment_cycle().to_string()))
             .item(CATEGORY_ID, AttributeValue::S(subscribe.category_id().to_string()))
             .item(ICON_LOCAL_PATH, AttributeValue::S(subscribe.icon_local_path().to_string()))
             .item(NOTIFICATION, AttributeValue::Bool(subscribe.notification()))
-            .item(FIRST_PAYMENT_DATE, AttributeValue::S(subscribe.first_payment_date().to_string()))
-            .item(NEXT_PAYMENT_DATE, AttributeValue::S(subscribe.next_payment_date().to_string()))
+            .item(FIRST_PAYMENT_DATE, AttributeValue::S(subscribe.first_payment_date().to_rfc3339()))
+            .item(NEXT_PAYMENT_DATE, AttributeValue::S(subscribe.next_payment_date().to_rfc3339()))
             .item(AUTO_RENEWAL, AttributeValue::Bool(subscribe.auto_renewal()))
             .item(STATUS, AttributeValue::S(subscribe.status().to_string()))
             .item(MEMO, {
@@ -116,7 +548,16 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
                 } else {
                     AttributeValue::Null(true)
                 }
-            });
+            })
+            .item(TRIAL_END_DATE, trial_end_date_attribute(subscribe))
+            .item(POST_TRIAL_AMOUNT, post_trial_amount_attribute(subscribe))
+            .item(PRICE_HISTORY, price_history_attribute(subscribe))
+            .item(COMMITMENT_END_DATE, commitment_end_date_attribute(subscribe))
+            .item(NOTICE_PERIOD_DAYS, notice_period_days_attribute(subscribe))
+            .item(COST_SPLIT, cost_split_attribute(subscribe))
+            .item(TAGS, tags_attribute(subscribe))
+            .item(CREATED_AT, AttributeValue::S(subscribe.created_at().to_rfc3339()))
+            .item(UPDATED_AT, updated_at_attribute(subscribe));
 
         match request.send().await {
             Ok(p) => {
@@ -131,31 +572,15 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
     }
 
     async fn find_all(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError> {
-        let result = self
-            .client
-            .query()
-            .table_name(&self.table)
-            .key_condition_expression(USER_ID_CONDITION)
-            .expression_attribute_names(USER_ID_ATTR, USER_ID)
-            .expression_attribute_values(USER_ID_VALUE, AttributeValue::S(user_id.to_string()))
-            .send()
-            .await
-            .map_err(|e| {
-                let msg = match e.message() {
-                    Some(s) => s.to_string(),
-                    None => e.to_string(),
-                };
-                SubscribeError::QueryError(msg)
-            })?;
+        self.query_by_user(user_id, NOT_DELETED_CONDITION).await
+    }
 
-        match result.items {
-            Some(items) => {
-                info!("{:?}", items);
-                let result = items.into_iter().map(|item| SubscribeRepositoryImpl::map_to_domain_model(item)).collect();
-                result
-            }
-            None => Ok(vec![]),
-        }
+    async fn find_by_category(
+        &self,
+        category_id: &CategoryId,
+        user_id: &UserId,
+    ) -> Result<Vec<Subscribe>, SubscribeError> {
+        self.query_by_category(category_id, user_id).await
     }
 
     async fn find_by_id(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<Subscribe, SubscribeError> {
@@ -176,11 +601,11 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
             })?;
 
         match result.item {
-            Some(item) => {
+            Some(item) if !item.contains_key(DELETED_AT) => {
                 info!("{:?}", item);
                 SubscribeRepositoryImpl::map_to_domain_model(item)
             }
-            None => {
+            _ => {
                 let error =
                     SubscribeError::FindByIdError(format!("subscribe_id: {:?}, user_id: {:?}", subscribe_id, user_id));
                 error!("{:?}", error);
@@ -189,6 +614,45 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
         }
     }
 
+    async fn find_by_status(&self, status: &SubscribeStatus) -> Result<Vec<Subscribe>, SubscribeError> {
+        let mut subscribes = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .scan()
+                .table_name(&self.table)
+                .filter_expression(STATUS_CONDITION)
+                .expression_attribute_names(STATUS_ATTR, STATUS)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .expression_attribute_values(STATUS_VALUE, AttributeValue::S(status.to_string()))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    SubscribeError::QueryError(msg)
+                })?;
+
+            if let Some(items) = result.items {
+                for item in items {
+                    subscribes.push(SubscribeRepositoryImpl::map_to_domain_model(item)?);
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(subscribes)
+    }
+
     async fn update(&self, subscribe: &Subscribe) -> Result<(), SubscribeError> {
         let result = self
             .client
@@ -200,6 +664,7 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
             .expression_attribute_names(NAME_ATTR, NAME)
             .expression_attribute_names(PAYMENT_METHOD_ID_ATTR, PAYMENT_METHOD_ID)
             .expression_attribute_names(AMOUNT_ATTR, AMOUNT)
+            .expression_attribute_names(CURRENCY_ATTR, CURRENCY)
             .expression_attribute_names(PAYMENT_CYCLE_ATTR, PAYMENT_CYCLE)
             .expression_attribute_names(CATEGORY_ID_ATTR, CATEGORY_ID)
             .expression_attribute_names(ICON_LOCAL_PATH_ATTR, ICON_LOCAL_PATH)
@@ -209,22 +674,30 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
             .expression_attribute_names(AUTO_RENEWAL_ATTR, AUTO_RENEWAL)
             .expression_attribute_names(STATUS_ATTR, STATUS)
             .expression_attribute_names(MEMO_ATTR, MEMO)
+            .expression_attribute_names(TRIAL_END_DATE_ATTR, TRIAL_END_DATE)
+            .expression_attribute_names(POST_TRIAL_AMOUNT_ATTR, POST_TRIAL_AMOUNT)
+            .expression_attribute_names(PRICE_HISTORY_ATTR, PRICE_HISTORY)
+            .expression_attribute_names(AMOUNT_VERSION_ATTR, AMOUNT_VERSION)
+            .expression_attribute_names(COMMITMENT_END_DATE_ATTR, COMMITMENT_END_DATE)
+            .expression_attribute_names(NOTICE_PERIOD_DAYS_ATTR, NOTICE_PERIOD_DAYS)
+            .expression_attribute_names(COST_SPLIT_ATTR, COST_SPLIT)
+            .expression_attribute_names(TAGS_ATTR, TAGS)
+            .expression_attribute_names(UPDATED_AT_ATTR, UPDATED_AT)
             .expression_attribute_values(NAME_VALUE, AttributeValue::S(subscribe.name().to_string()))
             .expression_attribute_values(
                 PAYMENT_METHOD_ID_VALUE,
                 AttributeValue::S(subscribe.payment_method_id().to_string()),
             )
             .expression_attribute_values(AMOUNT_VALUE, AttributeValue::S(subscribe.amount().to_string()))
-            .expression_attribute_values(
-                PAYMENT_CYCLE_VALUE,
-                AttributeValue::S(subscribe.payment_cycle().as_str().to_owned()),
-            )
+            .expression_attribute_values(CURRENCY_VALUE, AttributeValue::S(subscribe.currency().to_string()))
+            .expression_attribute_values(AMOUNT_VERSION_VALUE, AttributeValue::N(BILLED_AMOUNT_VERSION.to_owned()))
+            .expression_attribute_values(PAYMENT_CYCLE_VALUE, AttributeValue::S(subscribe.payment_cycle().to_string()))
             .expression_attribute_values(CATEGORY_ID_VALUE, AttributeValue::S(subscribe.category_id().to_string()))
             .expression_attribute_values(
                 ICON_LOCAL_PATH_VALUE,
                 AttributeValue::S(subscribe.icon_local_path().to_string()),
             )
-            .expression_attribute_values(NOTIFICATION_VALUE, AttributeValue::S(subscribe.notification().to_string()))
+            .expression_attribute_values(NOTIFICATION_VALUE, AttributeValue::Bool(subscribe.notification()))
             .expression_attribute_values(
                 FIRST_PAYMENT_DATE_VALUE,
                 AttributeValue::S(subscribe.first_payment_date().to_rfc3339()),
@@ -233,7 +706,7 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
                 NEXT_PAYMENT_DATE_VALUE,
                 AttributeValue::S(subscribe.next_payment_date().to_rfc3339()),
             )
-            .expression_attribute_values(AUTO_RENEWAL_VALUE, AttributeValue::S(subscribe.auto_renewal().to_string()))
+            .expression_attribute_values(AUTO_RENEWAL_VALUE, AttributeValue::Bool(subscribe.auto_renewal()))
             .expression_attribute_values(STATUS_VALUE, AttributeValue::S(subscribe.status().to_string()))
             .expression_attribute_values(MEMO_VALUE, {
                 if let Some(memo) = subscribe.memo() {
@@ -242,6 +715,14 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
                     AttributeValue::Null(true)
                 }
             })
+            .expression_attribute_values(TRIAL_END_DATE_VALUE, trial_end_date_attribute(subscribe))
+            .expression_attribute_values(POST_TRIAL_AMOUNT_VALUE, post_trial_amount_attribute(subscribe))
+            .expression_attribute_values(PRICE_HISTORY_VALUE, price_history_attribute(subscribe))
+            .expression_attribute_values(COMMITMENT_END_DATE_VALUE, commitment_end_date_attribute(subscribe))
+            .expression_attribute_values(NOTICE_PERIOD_DAYS_VALUE, notice_period_days_attribute(subscribe))
+            .expression_attribute_values(COST_SPLIT_VALUE, cost_split_attribute(subscribe))
+            .expression_attribute_values(TAGS_VALUE, tags_attribute(subscribe))
+            .expression_attribute_values(UPDATED_AT_VALUE, updated_at_attribute(subscribe))
             .send()
             .await
             .map_err(|e| {
@@ -265,12 +746,127 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
     }
 
     async fn delete(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key(SUBSCRIBE_KEY, AttributeValue::S(subscribe_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .update_expression(SOFT_DELETE_EXPRESSION)
+            .condition_expression(EXISTS_NOT_DELETED_CONDITION)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(&Utc::now()))
+            .send()
+            .await;
+
+        match result {
+            Ok(u) => {
+                info!("{:?}", u);
+                Ok(())
+            }
+            // 存在しない、または既にゴミ箱に移動したサブスク
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = SubscribeError::NotExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(SubscribeError::DeleteSubscribeFailed(msg))
+            }
+        }
+    }
+
+    async fn restore(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key(SUBSCRIBE_KEY, AttributeValue::S(subscribe_id.value().to_owned()))
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .update_expression(RESTORE_EXPRESSION)
+            .condition_expression(DELETED_CONDITION)
+            .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+            .send()
+            .await;
+
+        match result {
+            Ok(u) => {
+                info!("{:?}", u);
+                Ok(())
+            }
+            // ゴミ箱に存在しないサブスク
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = SubscribeError::NotExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(SubscribeError::UpdateSubscribeError(msg))
+            }
+        }
+    }
+
+    async fn find_deleted(&self, user_id: &UserId) -> Result<Vec<Subscribe>, SubscribeError> {
+        self.query_by_user(user_id, DELETED_CONDITION).await
+    }
+
+    async fn find_deleted_before(&self, before: &DateTime<Utc>) -> Result<Vec<Subscribe>, SubscribeError> {
+        let mut subscribes = vec![];
+        let mut exclusive_start_key = None;
+
+        loop {
+            let result = self
+                .client
+                .scan()
+                .table_name(&self.table)
+                .filter_expression(DELETED_BEFORE_CONDITION)
+                .expression_attribute_names(DELETED_AT_ATTR, DELETED_AT)
+                .expression_attribute_values(DELETED_AT_VALUE, comparable_datetime(before))
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|e| {
+                    let msg = match e.message() {
+                        Some(s) => s.to_string(),
+                        None => e.to_string(),
+                    };
+                    SubscribeError::QueryError(msg)
+                })?;
+
+            if let Some(items) = result.items {
+                for item in items {
+                    subscribes.push(SubscribeRepositoryImpl::map_to_domain_model(item)?);
+                }
+            }
+
+            match result.last_evaluated_key {
+                Some(key) => exclusive_start_key = Some(key),
+                None => break,
+            }
+        }
+
+        Ok(subscribes)
+    }
+
+    async fn purge(&self, subscribe_id: &SubscribeId, user_id: &UserId) -> Result<(), SubscribeError> {
         let result = self
             .client
             .delete_item()
             .table_name(&self.table)
             .key(SUBSCRIBE_KEY, AttributeValue::S(subscribe_id.value().to_owned()))
             .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .return_values(ReturnValue::AllOld)
             .send()
             .await
             .map_err(|e| {
@@ -283,7 +879,7 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
 
         match result {
             Ok(u) => {
-                if let None = u.attributes {
+                if u.attributes.is_none() {
                     let err = SubscribeError::NotExists;
                     error!("{:?}", err);
                     return Err(err);
@@ -300,12 +896,15 @@ impl SubscribeRepository for SubscribeRepositoryImpl {
 }
 
 impl Mapper<Subscribe, SubscribeError> for SubscribeRepositoryImpl {
-    fn map_to_domain_model(v: std::collections::HashMap<String, AttributeValue>) -> Result<Subscribe, SubscribeError> {
+    fn map_to_domain_model(v: HashMap<String, AttributeValue>) -> Result<Subscribe, SubscribeError> {
         let subscribe_id = SubscribeId::from_str(&as_string(v.get(SUBSCRIBE_KEY), ""))?;
         let user_id = UserId::from_str(&as_string(v.get(USER_ID), ""))?;
         let name = SubscribeName::from_str(&as_string(v.get(NAME), ""))?;
         let payment_method_id = PaymentMethodId::from_str(&as_string(v.get(PAYMENT_METHOD_ID), ""))?;
         let amount = Amount::from_str(&as_string(v.get(AMOUNT), ""))?;
+        // 通貨導入前のアイテムは既定の通貨で保存されていたものとして扱う
+        let currency = Currency::from_str(&as_string(v.get(CURRENCY), Currency::default().code()))?;
+        let amount = Money::new(amount, currency)?;
         let payment_cycle = PaymentCycle::from_str(&as_string(v.get(PAYMENT_CYCLE), ""))?;
         let category_id = CategoryId::from_str(&as_string(v.get(CATEGORY_ID), ""))?;
         let icon_local_path = as_string(v.get(ICON_LOCAL_PATH), "");
@@ -325,6 +924,23 @@ impl Mapper<Subscribe, SubscribeError> for SubscribeRepositoryImpl {
             .map_err(|_| SubscribeError::ParseFailed(AUTO_RENEWAL.into()))?;
         let status = SubscribeStatus::from_str(&as_string(v.get(STATUS), ""))?;
         let memo = Some(as_string(v.get(MEMO), ""));
+        let post_trial_amount = match v.get(POST_TRIAL_AMOUNT).and_then(|a| a.as_s().ok()) {
+            Some(s) => Some(Money::new(Amount::from_str(s)?, *amount.currency())?),
+            None => None,
+        };
+        let trial = as_datetime(v.get(TRIAL_END_DATE)).map(|end_date| Trial::new(end_date, post_trial_amount));
+        let price_history = as_price_history(v.get(PRICE_HISTORY), *amount.currency())?;
+        let notice_period_days = match v.get(NOTICE_PERIOD_DAYS).and_then(|n| n.as_n().ok()) {
+            Some(n) => Some(n.parse::<u32>().map_err(|_| SubscribeError::ParseFailed(NOTICE_PERIOD_DAYS.into()))?),
+            None => None,
+        };
+        let contract_term = ContractTerm::new(as_datetime(v.get(COMMITMENT_END_DATE)), notice_period_days)?;
+        let cost_split = as_cost_split(v.get(COST_SPLIT), *amount.currency())?;
+        let tags = as_tags(v.get(TAGS))?;
+        // 作成日時の記録前に登録されたアイテムは最も古いものとして扱う
+        let created_at = as_datetime(v.get(CREATED_AT)).unwrap_or(DateTime::UNIX_EPOCH);
+        let updated_at = as_datetime(v.get(UPDATED_AT));
+        let deleted_at = as_datetime(v.get(DELETED_AT));
 
         Ok(Subscribe::from(
             subscribe_id,
@@ -341,6 +957,14 @@ impl Mapper<Subscribe, SubscribeError> for SubscribeRepositoryImpl {
             *auto_renewal,
             status,
             memo,
+            trial,
+            contract_term,
+            cost_split,
+            tags,
+            created_at,
+            updated_at,
+            deleted_at,
+            price_history,
         ))
     }
 }
@@ -349,7 +973,6 @@ impl Mapper<Subscribe, SubscribeError> for SubscribeRepositoryImpl {
 mod tests {
     use chrono::Utc;
     use domain::category::category_id;
-    use std::collections::HashMap;
 
     use super::*;
 
@@ -362,6 +985,7 @@ mod tests {
                 (NAME.to_string(), AttributeValue::S("hoge".into())),
                 (PAYMENT_METHOD_ID.into(), AttributeValue::S(PaymentMethodId::new().to_string())),
                 (AMOUNT.into(), AttributeValue::S("5000".into())),
+                (CURRENCY.into(), AttributeValue::S("USD".into())),
                 (PAYMENT_CYCLE.into(), AttributeValue::S("monthly".into())),
                 (CATEGORY_ID.into(), AttributeValue::S(category_id::CategoryId::new().to_string())),
                 (ICON_LOCAL_PATH.into(), AttributeValue::S("../../".into())),
@@ -371,18 +995,61 @@ mod tests {
                 (AUTO_RENEWAL.into(), AttributeValue::Bool(false)),
                 (STATUS.into(), AttributeValue::S("ACTIVE".into())),
                 (MEMO.into(), AttributeValue::S("hoge".into())),
+                (TRIAL_END_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+                (POST_TRIAL_AMOUNT.into(), AttributeValue::S("6000".into())),
+                (COMMITMENT_END_DATE.into(), AttributeValue::S("2026-04-30T00:00:00+00:00".into())),
+                (NOTICE_PERIOD_DAYS.into(), AttributeValue::N("30".into())),
+                (CREATED_AT.into(), AttributeValue::S("2024-01-01T00:00:00+00:00".into())),
+                (UPDATED_AT.into(), AttributeValue::Null(true)),
+                (
+                    COST_SPLIT.into(),
+                    AttributeValue::L(vec![
+                        AttributeValue::M(HashMap::from([
+                            (MEMBER_TYPE.into(), AttributeValue::S(MEMBER_TYPE_PERSON.into())),
+                            (MEMBER.into(), AttributeValue::S("hoge".into())),
+                            (SHARE_TYPE.into(), AttributeValue::S(SHARE_TYPE_FIXED.into())),
+                            (SHARE.into(), AttributeValue::S("1000".into())),
+                        ])),
+                        AttributeValue::M(HashMap::from([
+                            (MEMBER_TYPE.into(), AttributeValue::S(MEMBER_TYPE_USER.into())),
+                            (MEMBER.into(), AttributeValue::S(UserId::new().to_string())),
+                            (SHARE_TYPE.into(), AttributeValue::S(SHARE_TYPE_PERCENTAGE.into())),
+                            (SHARE.into(), AttributeValue::S("25".into())),
+                        ])),
+                    ]),
+                ),
+                (
+                    TAGS.into(),
+                    AttributeValue::L(vec![
+                        AttributeValue::S("shared".into()),
+                        AttributeValue::S("work".into()),
+                    ]),
+                ),
+                (
+                    PRICE_HISTORY.into(),
+                    AttributeValue::L(vec![
+                        AttributeValue::M(HashMap::from([
+                            (EFFECTIVE_DATE.into(), AttributeValue::S("2024-05-01T00:00:00+00:00".into())),
+                            (AMOUNT.into(), AttributeValue::S("5000".into())),
+                        ])),
+                        AttributeValue::M(HashMap::from([
+                            (EFFECTIVE_DATE.into(), AttributeValue::S("2023-01-01T00:00:00+00:00".into())),
+                            (AMOUNT.into(), AttributeValue::S("4500".into())),
+                        ])),
+                    ]),
+                ),
             ]),
         ];
 
-        let _test = test_case
-            .into_iter()
-            .map(|test| match SubscribeRepositoryImpl::map_to_domain_model(test.clone()) {
+        for test in test_case {
+            match SubscribeRepositoryImpl::map_to_domain_model(test.clone()) {
                 Ok(v) => {
                     assert_eq!(v.subscribe_id().to_string(), as_string(test.get(SUBSCRIBE_KEY), ""));
                     assert_eq!(v.user_id().to_string(), as_string(test.get(USER_ID), ""));
                     assert_eq!(v.name().to_string(), as_string(test.get(NAME), ""));
                     assert_eq!(v.payment_method_id().to_string(), as_string(test.get(PAYMENT_METHOD_ID), ""));
                     assert_eq!(v.amount().to_string(), as_string(test.get(AMOUNT), ""));
+                    assert_eq!(v.currency().to_string(), as_string(test.get(CURRENCY), ""));
                     assert_eq!(v.payment_cycle().to_string(), as_string(test.get(PAYMENT_CYCLE), ""));
                     assert_eq!(v.category_id().to_string(), as_string(test.get(CATEGORY_ID), ""));
                     assert_eq!(v.icon_local_path().to_string(), as_string(test.get(ICON_LOCAL_PATH), ""));
@@ -392,12 +1059,188 @@ mod tests {
                     assert_eq!(v.auto_renewal(), *test.get(AUTO_RENEWAL).unwrap().as_bool().unwrap());
                     assert_eq!(v.status().to_string(), as_string(test.get(STATUS), ""));
                     assert_eq!(v.memo().as_ref(), Some(&as_string(test.get(MEMO), "")));
+                    let trial = v.trial().as_ref().unwrap();
+                    assert_eq!(trial.end_date().to_rfc3339(), as_string(test.get(TRIAL_END_DATE), ""));
+                    assert_eq!(
+                        trial.post_trial_amount().as_ref().map(|m| m.amount().to_string()),
+                        Some(as_string(test.get(POST_TRIAL_AMOUNT), ""))
+                    );
+                    let history: Vec<String> =
+                        v.price_history().iter().map(|p| p.amount().amount().to_string()).collect();
+                    assert_eq!(history, vec!["4500", "5000"]);
+                    let contract_term = v.contract_term();
+                    assert_eq!(
+                        contract_term.commitment_end_date().map(|d| d.to_rfc3339()),
+                        Some(as_string(test.get(COMMITMENT_END_DATE), ""))
+                    );
+                    assert_eq!(contract_term.notice_period_days(), Some(30));
+                    assert_eq!(v.cost_split().members().len(), 2);
+                    assert!(v.has_tag(&Tag::new("shared").unwrap()));
+                    assert_eq!(v.tags().len(), 2);
+                    assert_eq!(v.own_amount(), Decimal::from(2750));
+                    assert_eq!(v.created_at().to_rfc3339(), as_string(test.get(CREATED_AT), ""));
+                    assert!(v.updated_at().is_none());
                 }
                 Err(e) => {
                     println!("{:?}", e.to_string());
-                    assert!(false)
+                    panic!("{:?}", e)
                 }
-            })
-            .collect::<()>();
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_to_domain_model_currency() {
+        let test_case = vec![
+            (None, "1980", Some(Currency::JPY)),
+            (Some("USD"), "9.99", Some(Currency::USD)),
+            (Some("JPY"), "9.99", None),
+            (Some("XXX"), "100", None),
+        ];
+
+        for (currency, amount, expected) in test_case {
+            let mut item = HashMap::from([
+                (SUBSCRIBE_KEY.into(), AttributeValue::S(SubscribeId::new().to_string())),
+                (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+                (NAME.to_string(), AttributeValue::S("hoge".into())),
+                (PAYMENT_METHOD_ID.into(), AttributeValue::S(PaymentMethodId::new().to_string())),
+                (AMOUNT.into(), AttributeValue::S(amount.into())),
+                (PAYMENT_CYCLE.into(), AttributeValue::S("monthly".into())),
+                (CATEGORY_ID.into(), AttributeValue::S(category_id::CategoryId::new().to_string())),
+                (NOTIFICATION.into(), AttributeValue::Bool(true)),
+                (FIRST_PAYMENT_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+                (NEXT_PAYMENT_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+                (AUTO_RENEWAL.into(), AttributeValue::Bool(false)),
+                (STATUS.into(), AttributeValue::S("ACTIVE".into())),
+            ]);
+            if let Some(c) = currency {
+                item.insert(CURRENCY.into(), AttributeValue::S(c.into()));
+            }
+
+            let result = SubscribeRepositoryImpl::map_to_domain_model(item);
+            match expected {
+                Some(expected) => assert_eq!(result.unwrap().currency(), &expected),
+                None => assert!(result.is_err(), "currency: {:?}, amount: {}", currency, amount),
+            }
+        }
+    }
+
+    #[test]
+    fn test_price_history_round_trip_with_currency_change() {
+        let mut item = HashMap::from([
+            (SUBSCRIBE_KEY.into(), AttributeValue::S(SubscribeId::new().to_string())),
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (NAME.to_string(), AttributeValue::S("hoge".into())),
+            (PAYMENT_METHOD_ID.into(), AttributeValue::S(PaymentMethodId::new().to_string())),
+            (AMOUNT.into(), AttributeValue::S("9.99".into())),
+            (CURRENCY.into(), AttributeValue::S("USD".into())),
+            (PAYMENT_CYCLE.into(), AttributeValue::S("monthly".into())),
+            (CATEGORY_ID.into(), AttributeValue::S(category_id::CategoryId::new().to_string())),
+            (NOTIFICATION.into(), AttributeValue::Bool(true)),
+            (FIRST_PAYMENT_DATE.into(), AttributeValue::S("2024-01-01T00:00:00+00:00".into())),
+            (NEXT_PAYMENT_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (AUTO_RENEWAL.into(), AttributeValue::Bool(false)),
+            (STATUS.into(), AttributeValue::S("ACTIVE".into())),
+        ]);
+        let mut subscribe = SubscribeRepositoryImpl::map_to_domain_model(item.clone()).unwrap();
+        let jpy = Money::new(Amount::from_str("1500").unwrap(), Currency::JPY).unwrap();
+        subscribe.change_amount(jpy.clone(), Utc::now());
+
+        item.insert(AMOUNT.into(), AttributeValue::S("1500".into()));
+        item.insert(CURRENCY.into(), AttributeValue::S("JPY".into()));
+        item.insert(PRICE_HISTORY.into(), price_history_attribute(&subscribe));
+        let result = SubscribeRepositoryImpl::map_to_domain_model(item).unwrap();
+
+        let history: Vec<&Money> = result.price_history().iter().map(|p| p.amount()).collect();
+        assert_eq!(
+            history,
+            vec![
+                &Money::new(Amount::from_str("9.99").unwrap(), Currency::USD).unwrap(),
+                &jpy
+            ]
+        );
+    }
+
+    #[test]
+    fn test_map_to_domain_model_deleted_at() {
+        let mut item = HashMap::from([
+            (SUBSCRIBE_KEY.into(), AttributeValue::S(SubscribeId::new().to_string())),
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (NAME.to_string(), AttributeValue::S("hoge".into())),
+            (PAYMENT_METHOD_ID.into(), AttributeValue::S(PaymentMethodId::new().to_string())),
+            (AMOUNT.into(), AttributeValue::S("1980".into())),
+            (PAYMENT_CYCLE.into(), AttributeValue::S("monthly".into())),
+            (CATEGORY_ID.into(), AttributeValue::S(category_id::CategoryId::new().to_string())),
+            (NOTIFICATION.into(), AttributeValue::Bool(true)),
+            (FIRST_PAYMENT_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (NEXT_PAYMENT_DATE.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (AUTO_RENEWAL.into(), AttributeValue::Bool(false)),
+            (STATUS.into(), AttributeValue::S("ACTIVE".into())),
+        ]);
+        let result = SubscribeRepositoryImpl::map_to_domain_model(item.clone()).unwrap();
+        assert!(!result.is_deleted());
+        assert_eq!(result.created_at(), &DateTime::UNIX_EPOCH);
+
+        item.insert(DELETED_AT.into(), AttributeValue::S("2024-06-01T00:00:00Z".into()));
+        let result = SubscribeRepositoryImpl::map_to_domain_model(item).unwrap();
+        assert!(result.is_deleted());
+        assert_eq!(result.deleted_at().map(|d| d.to_rfc3339()), Some("2024-06-01T00:00:00+00:00".to_string()));
+    }
+
+    #[test]
+    fn test_restore_billed_amount() {
+        let test_case = vec![
+            ("yearly", "462", "5544"),
+            ("monthly", "1980", "1980"),
+            ("weekly", "500", "500"),
+        ];
+
+        for (cycle, stored, expected) in test_case {
+            let item = HashMap::from([
+                (AMOUNT.to_string(), AttributeValue::S(stored.into())),
+                (PAYMENT_CYCLE.to_string(), AttributeValue::S(cycle.into())),
+            ]);
+            let result = restore_billed_amount(&item).unwrap();
+            assert_eq!(result.to_string(), expected, "cycle: {}", cycle)
+        }
+    }
+
+    #[test]
+    fn test_restore_billed_amount_invalid_item() {
+        let item = HashMap::from([
+            (AMOUNT.to_string(), AttributeValue::S("0".into())),
+            (PAYMENT_CYCLE.to_string(), AttributeValue::S("yearly".into())),
+        ]);
+        assert!(restore_billed_amount(&item).is_err());
+    }
+
+    #[test]
+    fn test_restorable_items_skips_invalid_items() {
+        let item = |id: &str, cycle: &str, amount: &str| {
+            HashMap::from([
+                (SUBSCRIBE_KEY.to_string(), AttributeValue::S(id.into())),
+                (AMOUNT.to_string(), AttributeValue::S(amount.into())),
+                (PAYMENT_CYCLE.to_string(), AttributeValue::S(cycle.into())),
+            ])
+        };
+        let items = vec![
+            item("1", "yearly", "462"),
+            item("2", "fortnightly", "500"),
+            item("3", "monthly", "1980"),
+            item("4", "monthly", "abc"),
+        ];
+
+        let result: Vec<_> = restorable_items(items)
+            .into_iter()
+            .map(|(item, amount)| (as_string(item.get(SUBSCRIBE_KEY), ""), amount.to_string()))
+            .collect();
+
+        assert_eq!(
+            result,
+            vec![
+                ("1".to_string(), "5544".to_string()),
+                ("3".to_string(), "1980".to_string()),
+            ]
+        );
     }
 }
@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use aws_sdk_dynamodb::error::ProvideErrorMetadata;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use domain::{
+    repository::user_repository::UserRepository,
+    user::{user_error::UserError, user_id::UserId, user_profile::UserProfile, user_settings::UserSettings, User},
+    value_object::currency::Currency,
+    AggregateId,
+};
+use tracing::{error, info};
+
+use crate::mapper::{as_datetime, as_string, Mapper};
+
+const USER_ID: &str = "user_id";
+
+const NAME: &str = "name";
+const EMAIL: &str = "email";
+const PROFILE_ICON_PATH: &str = "profile_icon_path";
+const COUNTRY_ID: &str = "country_id";
+const NOTIFICATION: &str = "notification";
+const CURRENCY: &str = "currency";
+const CREATED_AT: &str = "created_at";
+const UPDATED_AT: &str = "updated_at";
+
+const USER_ID_ATTR: &str = "#user_id";
+
+const NOT_EXISTS_CONDITION: &str = "attribute_not_exists(#user_id)";
+const EXISTS_CONDITION: &str = "attribute_exists(#user_id)";
+
+const UPDATE_EXPRESSION: &str = "SET #name = :name, \
+                                   #email = :email, \
+                                   #profile_icon_path = :profile_icon_path, \
+                                   #country_id = :country_id, \
+                                   #notification = :notification, \
+                                   #currency = :currency, \
+                                   #updated_at = :updated_at";
+
+const NAME_ATTR: &str = "#name";
+const EMAIL_ATTR: &str = "#email";
+const PROFILE_ICON_PATH_ATTR: &str = "#profile_icon_path";
+const COUNTRY_ID_ATTR: &str = "#country_id";
+const NOTIFICATION_ATTR: &str = "#notification";
+const CURRENCY_ATTR: &str = "#currency";
+const UPDATED_AT_ATTR: &str = "#updated_at";
+
+const NAME_VALUE: &str = ":name";
+const EMAIL_VALUE: &str = ":email";
+const PROFILE_ICON_PATH_VALUE: &str = ":profile_icon_path";
+const COUNTRY_ID_VALUE: &str = ":country_id";
+const NOTIFICATION_VALUE: &str = ":notification";
+const CURRENCY_VALUE: &str = ":currency";
+const UPDATED_AT_VALUE: &str = ":updated_at";
+
+pub struct UserRepositoryImpl {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl UserRepositoryImpl {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
+        Self { client, table: table.to_string() }
+    }
+}
+
+fn optional_attribute<T: ToString>(value: &Option<T>) -> AttributeValue {
+    match value {
+        Some(v) => AttributeValue::S(v.to_string()),
+        None => AttributeValue::Null(true),
+    }
+}
+
+#[async_trait::async_trait]
+impl UserRepository for UserRepositoryImpl {
+    async fn create(&self, user: &User) -> Result<(), UserError> {
+        let request = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .item(USER_ID, AttributeValue::S(user.user_id().value().to_owned()))
+            .item(NAME, AttributeValue::S(user.profile().name().to_owned()))
+            .item(EMAIL, AttributeValue::S(user.profile().email().to_owned()))
+            .item(PROFILE_ICON_PATH, optional_attribute(user.profile().profile_icon_path()))
+            .item(COUNTRY_ID, AttributeValue::N(user.country_id().to_string()))
+            .item(NOTIFICATION, AttributeValue::Bool(user.settings().notification()))
+            .item(CURRENCY, optional_attribute(user.settings().currency()))
+            .item(CREATED_AT, AttributeValue::S(user.created_at().to_rfc3339()))
+            .item(UPDATED_AT, AttributeValue::S(user.updated_at().to_rfc3339()))
+            .condition_expression(NOT_EXISTS_CONDITION)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID);
+
+        match request.send().await {
+            Ok(p) => {
+                info!("{:?}", p);
+                Ok(())
+            }
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = UserError::AlreadyExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(UserError::CreateUserFailed(msg))
+            }
+        }
+    }
+
+    async fn find_by_id(&self, user_id: &UserId) -> Result<User, UserError> {
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                UserError::FindByIdError(msg)
+            })?;
+
+        match result.item {
+            Some(item) => {
+                info!("{:?}", item);
+                UserRepositoryImpl::map_to_domain_model(item)
+            }
+            None => {
+                error!("{:?}, {:?}", UserError::NotExists.to_string(), &user_id);
+                Err(UserError::NotExists)
+            }
+        }
+    }
+
+    async fn update(&self, user: &User) -> Result<(), UserError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key(USER_ID, AttributeValue::S(user.user_id().value().to_owned()))
+            .update_expression(UPDATE_EXPRESSION)
+            .condition_expression(EXISTS_CONDITION)
+            .expression_attribute_names(USER_ID_ATTR, USER_ID)
+            .expression_attribute_names(NAME_ATTR, NAME)
+            .expression_attribute_names(EMAIL_ATTR, EMAIL)
+            .expression_attribute_names(PROFILE_ICON_PATH_ATTR, PROFILE_ICON_PATH)
+            .expression_attribute_names(COUNTRY_ID_ATTR, COUNTRY_ID)
+            .expression_attribute_names(NOTIFICATION_ATTR, NOTIFICATION)
+            .expression_attribute_names(CURRENCY_ATTR, CURRENCY)
+            .expression_attribute_names(UPDATED_AT_ATTR, UPDATED_AT)
+            .expression_attribute_values(NAME_VALUE, AttributeValue::S(user.profile().name().to_owned()))
+            .expression_attribute_values(EMAIL_VALUE, AttributeValue::S(user.profile().email().to_owned()))
+            .expression_attribute_values(
+                PROFILE_ICON_PATH_VALUE,
+                optional_attribute(user.profile().profile_icon_path()),
+            )
+            .expression_attribute_values(COUNTRY_ID_VALUE, AttributeValue::N(user.country_id().to_string()))
+            .expression_attribute_values(NOTIFICATION_VALUE, AttributeValue::Bool(user.settings().notification()))
+            .expression_attribute_values(CURRENCY_VALUE, optional_attribute(user.settings().currency()))
+            .expression_attribute_values(UPDATED_AT_VALUE, AttributeValue::S(user.updated_at().to_rfc3339()))
+            .send()
+            .await;
+
+        match result {
+            Ok(u) => {
+                info!("{:?}", u);
+                Ok(())
+            }
+            Err(e) if e.as_service_error().is_some_and(|e| e.is_conditional_check_failed_exception()) => {
+                let err = UserError::NotExists;
+                error!("{:?}", err);
+                Err(err)
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                Err(UserError::UpdateUserFailed(msg))
+            }
+        }
+    }
+
+    async fn delete(&self, user_id: &UserId) -> Result<(), UserError> {
+        let result = self
+            .client
+            .delete_item()
+            .table_name(&self.table)
+            .key(USER_ID, AttributeValue::S(user_id.value().to_owned()))
+            .return_values(ReturnValue::AllOld)
+            .send()
+            .await
+            .map_err(|e| {
+                let msg = match e.message() {
+                    Some(s) => s.to_string(),
+                    None => e.to_string(),
+                };
+                UserError::DeleteUserFailed(msg)
+            });
+
+        match result {
+            Ok(u) => {
+                if u.attributes.is_none() {
+                    let err = UserError::NotExists;
+                    error!("{:?}", err);
+                    return Err(err);
+                }
+                info!("{:?}", u);
+                Ok(())
+            }
+            Err(e) => {
+                error!("{:?}", e);
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Mapper<User, UserError> for UserRepositoryImpl {
+    fn map_to_domain_model(v: HashMap<String, AttributeValue>) -> Result<User, UserError> {
+        let user_id = UserId::from_str(&as_string(v.get(USER_ID), ""))?;
+        let profile_icon_path = v.get(PROFILE_ICON_PATH).and_then(|p| p.as_s().ok()).cloned();
+        let profile = UserProfile::new(&as_string(v.get(NAME), ""), &as_string(v.get(EMAIL), ""), profile_icon_path)?;
+        let country_id = v
+            .get(COUNTRY_ID)
+            .and_then(|n| n.as_n().ok())
+            .ok_or(UserError::MissingField(COUNTRY_ID.into()))?
+            .parse::<i32>()
+            .map_err(|_| UserError::MissingField(COUNTRY_ID.into()))?;
+        // 設定の記録前に登録されたユーザーは既定の設定として扱う
+        let notification = v.get(NOTIFICATION).and_then(|b| b.as_bool().ok()).copied().unwrap_or(true);
+        let currency = match v.get(CURRENCY).and_then(|c| c.as_s().ok()) {
+            Some(c) => Some(Currency::from_str(c)?),
+            None => None,
+        };
+        let created_at = as_datetime(v.get(CREATED_AT)).ok_or(UserError::MissingField(CREATED_AT.to_string()))?;
+        let updated_at = as_datetime(v.get(UPDATED_AT)).unwrap_or(created_at);
+
+        Ok(User::from(user_id, profile, country_id, UserSettings::new(notification, currency), created_at, updated_at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    #[test]
+    fn test_map_to_domain_model_success() {
+        let test = HashMap::from([
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (NAME.into(), AttributeValue::S("山田 太郎".into())),
+            (EMAIL.into(), AttributeValue::S("taro@example.com".into())),
+            (PROFILE_ICON_PATH.into(), AttributeValue::S("profile_usr123".into())),
+            (COUNTRY_ID.into(), AttributeValue::N("2".into())),
+            (NOTIFICATION.into(), AttributeValue::Bool(false)),
+            (CURRENCY.into(), AttributeValue::S("EUR".into())),
+            (CREATED_AT.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+            (UPDATED_AT.into(), AttributeValue::S(Utc::now().to_rfc3339())),
+        ]);
+
+        match UserRepositoryImpl::map_to_domain_model(test.clone()) {
+            Ok(v) => {
+                assert_eq!(v.user_id().to_string(), as_string(test.get(USER_ID), ""));
+                assert_eq!(v.profile().name(), "山田 太郎");
+                assert_eq!(v.profile().profile_icon_path(), &Some("profile_usr123".to_string()));
+                assert_eq!(v.country_id(), 2);
+                assert!(!v.settings().notification());
+                assert_eq!(v.default_currency(), Currency::EUR);
+            }
+            Err(e) => {
+                println!("{:?}", e.to_string());
+                panic!("{:?}", e)
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_to_domain_model_without_settings() {
+        let created_at = Utc::now();
+        let test = HashMap::from([
+            (USER_ID.into(), AttributeValue::S(UserId::new().to_string())),
+            (NAME.into(), AttributeValue::S("taro".into())),
+            (EMAIL.into(), AttributeValue::S("taro@example.com".into())),
+            (PROFILE_ICON_PATH.into(), AttributeValue::Null(true)),
+            (COUNTRY_ID.into(), AttributeValue::N("1".into())),
+            (CREATED_AT.into(), AttributeValue::S(created_at.to_rfc3339())),
+        ]);
+
+        let result = UserRepositoryImpl::map_to_domain_model(test).unwrap();
+
+        assert_eq!(result.settings(), &UserSettings::default());
+        assert_eq!(result.profile().profile_icon_path(), &None);
+        assert_eq!(result.default_currency(), Currency::JPY);
+    }
+}
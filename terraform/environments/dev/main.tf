@@ -29,6 +29,9 @@ module "lambda" {
     PAYMENT_TABLE   = module.dynamodb.table_names["payment"]
     SUBSCRIBE_TABLE = module.dynamodb.table_names["subscribe"]
     CATEGORY_TABLE  = module.dynamodb.table_names["category"]
+    LEDGER_TABLE    = module.dynamodb.table_names["ledger"]
+    BUDGET_TABLE    = module.dynamodb.table_names["budget"]
+    USER_TABLE      = module.dynamodb.table_names["user"]
     RUST_BACKTRACE  = "1"
     RUST_LOG        = "info"
     HOST            = "0.0.0.0"